@@ -0,0 +1,96 @@
+use aoc::Solution;
+
+fn corrupt_score(line: &[char]) -> usize {
+    let mut levels = vec![];
+    for &c in line {
+        match c {
+            '(' | '[' | '{' | '<' => levels.push(c),
+            ')' => {
+                if levels.pop() != Some('(') {
+                    return 3;
+                }
+            }
+            ']' => {
+                if levels.pop() != Some('[') {
+                    return 57;
+                }
+            }
+            '}' => {
+                if levels.pop() != Some('{') {
+                    return 1197;
+                }
+            }
+            '>' => {
+                if levels.pop() != Some('<') {
+                    return 25137;
+                }
+            }
+            _ => {}
+        }
+    }
+    0
+}
+
+fn incomplete_score(line: &[char]) -> usize {
+    let mut levels = vec![];
+    for &c in line {
+        match c {
+            '(' | '[' | '{' | '<' => levels.push(c),
+            ')' => assert!(levels.pop() == Some('(')),
+            ']' => assert!(levels.pop() == Some('[')),
+            '}' => assert!(levels.pop() == Some('{')),
+            '>' => assert!(levels.pop() == Some('<')),
+            _ => {}
+        }
+    }
+    levels.reverse();
+    let mut score = 0;
+    for c in levels {
+        score *= 5;
+        score += match c {
+            '(' => 1,
+            '[' => 2,
+            '{' => 3,
+            '<' => 4,
+            _ => 0,
+        };
+    }
+    score
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Vec<char>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, |line| {
+            let mut chunks = vec![];
+            while !line.is_empty() {
+                let before = *line;
+                let c = line.char()?;
+                if !"()[]{}<>".contains(c) {
+                    return Err(before.error(format!("expected a bracket, found `{c}`")));
+                }
+                chunks.push(c);
+            }
+            Ok(chunks)
+        })
+    }
+
+    fn part_a(lines: &Self::Input) -> Option<String> {
+        let total_corrupt: usize = lines.iter().map(|line| corrupt_score(line)).sum();
+        Some(total_corrupt.to_string())
+    }
+
+    fn part_b(lines: &Self::Input) -> Option<String> {
+        let mut incomplete_scores = vec![];
+        for line in lines {
+            if corrupt_score(line) == 0 {
+                incomplete_scores.push(incomplete_score(line));
+            }
+        }
+        incomplete_scores.sort();
+        Some(incomplete_scores[incomplete_scores.len() / 2].to_string())
+    }
+}
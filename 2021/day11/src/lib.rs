@@ -0,0 +1,83 @@
+use aoc::Solution;
+
+type Grid = Vec<Vec<(i8, bool)>>;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_rows(input, |cell| Ok((cell.digit()? as i8, false)))
+    }
+
+    fn part_a(grid: &Self::Input) -> Option<String> {
+        let (flashes_after_100, _) = simulate(grid.clone());
+        Some(flashes_after_100.to_string())
+    }
+
+    fn part_b(grid: &Self::Input) -> Option<String> {
+        let (_, sync_step) = simulate(grid.clone());
+        Some(sync_step.to_string())
+    }
+}
+
+/// Steps until every octopus flashes at once. Returns the number of flashes
+/// in the first 100 steps, and the step on which they synchronized.
+fn simulate(mut grid: Grid) -> (usize, usize) {
+    let mut num_flashes = 0;
+    let mut flashes_after_100 = 0;
+    let mut step = 0;
+    loop {
+        step += 1;
+        grid.iter_mut()
+            .for_each(|row| row.iter_mut().for_each(|(energy, _)| *energy += 1));
+
+        let mut progress = true;
+        while progress {
+            progress = false;
+            for r in 0..10 as i8 {
+                for c in 0..10 as i8 {
+                    let (energy, highlighted) = &mut grid[r as usize][c as usize];
+                    if *energy > 9 && !*highlighted {
+                        progress = true;
+                        num_flashes += 1;
+                        *highlighted = true;
+                        *energy = 0;
+
+                        for dr in -1..=1 {
+                            for dc in -1..=1 {
+                                if (dr != 0 || dc != 0)
+                                    && r + dr >= 0
+                                    && r + dr < 10
+                                    && c + dc >= 0
+                                    && c + dc < 10
+                                {
+                                    grid[(r + dr) as usize][(c + dc) as usize].0 += 1;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        let mut sync = true;
+        grid.iter_mut().for_each(|row| {
+            row.iter_mut().for_each(|(energy, highlighted)| {
+                if *highlighted {
+                    progress = true;
+                    *energy = 0;
+                    *highlighted = false;
+                } else {
+                    sync = false;
+                }
+            })
+        });
+        if step == 100 {
+            flashes_after_100 = num_flashes;
+        }
+        if sync {
+            return (flashes_after_100, step);
+        }
+    }
+}
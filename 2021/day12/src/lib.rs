@@ -0,0 +1,94 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc::Solution;
+use aoc_viz::{Graph, Shape};
+
+fn is_big(cave: &str) -> bool {
+    cave.chars().all(|c| c.is_uppercase())
+}
+
+fn num_paths(
+    edges: &HashMap<String, Vec<String>>,
+    from: &str,
+    can_revisit: bool,
+    mut seen: HashSet<String>,
+) -> usize {
+    if from == "end" {
+        1
+    } else {
+        if !is_big(from) {
+            seen.insert(from.to_owned());
+        }
+        let mut total = 0;
+        if let Some(from_edges) = edges.get(from) {
+            for dest in from_edges.iter() {
+                if !seen.contains(dest) {
+                    total += num_paths(edges, dest, can_revisit, seen.clone());
+                } else if can_revisit && dest != "start" {
+                    total += num_paths(edges, dest, false, seen.clone());
+                }
+            }
+        }
+        total
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = HashMap<String, Vec<String>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let mut edges: HashMap<String, Vec<String>> = HashMap::new();
+        for mut line in aoc_parse::lines(input) {
+            let left = line.word()?.to_owned();
+            line.literal("-")?;
+            let right = line.word()?.to_owned();
+            line.end()?;
+            edges.entry(left.clone()).or_default().push(right.clone());
+            edges.entry(right).or_default().push(left);
+        }
+        Ok(edges)
+    }
+
+    fn part_a(edges: &Self::Input) -> Option<String> {
+        Some(num_paths(edges, "start", false, HashSet::new()).to_string())
+    }
+
+    fn part_b(edges: &Self::Input) -> Option<String> {
+        Some(num_paths(edges, "start", true, HashSet::new()).to_string())
+    }
+}
+
+/// The caves as an undirected graph, with the big caves drawn as boxes and
+/// one of the shortest routes from `start` to `end` highlighted.
+pub fn graph(input: &str) -> Result<Graph, aoc::ParseError> {
+    let edges = Day12::try_parse(input)?;
+    let mut caves: Vec<&String> = edges.keys().collect();
+    caves.sort();
+
+    let mut graph = Graph::undirected();
+    for &cave in &caves {
+        if is_big(cave) {
+            graph.node(cave).shape(Shape::Box);
+        } else {
+            graph.node(cave);
+        }
+    }
+    for &cave in &caves {
+        for dest in &edges[cave] {
+            if cave < dest {
+                graph.edge(cave, dest);
+            }
+        }
+    }
+    let path = aoc_search::bfs(
+        ["start".to_owned()],
+        |cave| edges.get(cave).cloned().unwrap_or_default(),
+        |cave| cave == "end",
+    );
+    if let Some(path) = path {
+        graph.highlight_path(&path);
+    }
+    Ok(graph)
+}
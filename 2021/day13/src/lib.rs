@@ -0,0 +1,119 @@
+use std::collections::HashSet;
+
+use aoc::Solution;
+
+pub type Points = HashSet<(i32, i32)>;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Axis {
+    X,
+    Y,
+}
+
+fn render_grid(points: &Points) -> String {
+    let min_x = *points.iter().map(|(x, _)| x).min().unwrap();
+    let max_x = *points.iter().map(|(x, _)| x).max().unwrap();
+
+    let min_y = *points.iter().map(|(_, y)| y).min().unwrap();
+    let max_y = *points.iter().map(|(_, y)| y).max().unwrap();
+    let mut rows = vec![];
+    for y in min_y..=max_y {
+        let mut row = String::with_capacity((max_x - min_x) as usize);
+        for x in min_x..=max_x {
+            row.push(if points.contains(&(x, y)) { '#' } else { '.' });
+        }
+        rows.push(row);
+    }
+    rows.join("\n")
+}
+
+fn run_fold(points: &mut Points, (axis, line): (Axis, i32)) {
+    let min_x = *points.iter().map(|(x, _)| x).min().unwrap();
+    let max_x = *points.iter().map(|(x, _)| x).max().unwrap();
+    let min_y = *points.iter().map(|(_, y)| y).min().unwrap();
+    let max_y = *points.iter().map(|(_, y)| y).max().unwrap();
+
+    let mut new_points = Points::new();
+    match axis {
+        Axis::X => {
+            for x in min_x..line {
+                for y in min_y..=max_y {
+                    if points.contains(&(x, y)) {
+                        new_points.insert((x, y));
+                    }
+                }
+            }
+            for x in line..=max_x {
+                for y in min_y..=max_y {
+                    if points.contains(&(x, y)) {
+                        let folded_x = 2 * line - x;
+                        new_points.insert((folded_x, y));
+                    }
+                }
+            }
+        }
+        Axis::Y => {
+            for x in min_x..=max_x {
+                for y in min_y..line {
+                    if points.contains(&(x, y)) {
+                        new_points.insert((x, y));
+                    }
+                }
+            }
+            for x in min_x..=max_x {
+                for y in line..=max_y {
+                    if points.contains(&(x, y)) {
+                        let folded_y = 2 * line - y;
+                        new_points.insert((x, folded_y));
+                    }
+                }
+            }
+        }
+    }
+    std::mem::swap(points, &mut new_points);
+}
+
+fn run_folds(points: &mut Points, folds: &[(Axis, i32)]) {
+    for fold in folds {
+        run_fold(points, *fold);
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (Points, Vec<(Axis, i32)>);
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let [dots, instructions] = aoc_parse::split_sections(input)?;
+        let points = dots
+            .parse_lines(|line| {
+                let x = line.int()?;
+                line.literal(",")?;
+                Ok((x, line.int()?))
+            })?
+            .into_iter()
+            .collect();
+        let folds = instructions.parse_lines(|line| {
+            line.literal("fold along ")?;
+            let axis = line.one_of(&[("x=", Axis::X), ("y=", Axis::Y)])?;
+            Ok((axis, line.int()?))
+        })?;
+        if folds.is_empty() {
+            return Err(instructions.error("expected a fold"));
+        }
+        Ok((points, folds))
+    }
+
+    fn part_a((points, folds): &Self::Input) -> Option<String> {
+        let mut points = points.clone();
+        run_folds(&mut points, &folds[..1]);
+        Some(points.len().to_string())
+    }
+
+    fn part_b((points, folds): &Self::Input) -> Option<String> {
+        let mut points = points.clone();
+        run_folds(&mut points, folds);
+        Some(render_grid(&points))
+    }
+}
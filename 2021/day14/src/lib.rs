@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use aoc::Solution;
+
+pub type Rules = HashMap<[u8; 2], u8>;
+type Counts = HashMap<u8, usize>;
+type PairCounts = HashMap<[u8; 2], usize>;
+
+fn run_rules(
+    mut counts: Counts,
+    old_paircounts: PairCounts,
+    rules: &Rules,
+) -> (Counts, PairCounts) {
+    let mut paircounts = PairCounts::new();
+    for (pair, pair_count) in old_paircounts {
+        if let Some(&insertion) = rules.get(&pair) {
+            *counts.entry(insertion).or_default() += pair_count;
+            *paircounts.entry([pair[0], insertion]).or_default() += pair_count;
+            *paircounts.entry([insertion, pair[1]]).or_default() += pair_count;
+        } else {
+            *paircounts.entry(pair).or_default() += pair_count;
+        }
+    }
+    (counts, paircounts)
+}
+
+fn make_paircounts(s: Vec<u8>) -> (Counts, PairCounts) {
+    let mut counts = Counts::new();
+    let mut pairs = PairCounts::new();
+    *counts.entry(s[0]).or_default() += 1;
+    for i in 1..s.len() {
+        *counts.entry(s[i]).or_default() += 1;
+        *pairs.entry([s[i - 1], s[i]]).or_default() += 1;
+    }
+    (counts, pairs)
+}
+
+fn polymerize(initial: Vec<u8>, rules: &Rules, steps: usize) -> usize {
+    let (mut counts, mut paircounts) = make_paircounts(initial);
+    for _ in 1..=steps {
+        let new = run_rules(counts, paircounts, rules);
+        counts = new.0;
+        paircounts = new.1;
+    }
+    let most_common = counts.iter().max_by_key(|(_, v)| *v).unwrap();
+    let least_common = counts.iter().min_by_key(|(_, v)| *v).unwrap();
+    most_common.1 - least_common.1
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = (Vec<u8>, Rules);
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let [template, insertions] = aoc_parse::split_sections(input)?;
+        let [mut template] = template.split_lines()?;
+        let initial = template.word()?.as_bytes().to_vec();
+        template.end()?;
+
+        let mut rules = Rules::new();
+        for mut line in insertions.lines() {
+            let start = line;
+            let pair = line.char()?;
+            let pair = [pair, line.char()?];
+            line.literal(" -> ")?;
+            let insertion = line.char()?;
+            line.end()?;
+            if !pair
+                .iter()
+                .chain([&insertion])
+                .all(char::is_ascii_uppercase)
+            {
+                return Err(start.error("expected a rule between uppercase elements"));
+            }
+            rules.insert(pair.map(|c| c as u8), insertion as u8);
+        }
+        Ok((initial, rules))
+    }
+
+    fn part_a((initial, rules): &Self::Input) -> Option<String> {
+        Some(polymerize(initial.clone(), rules, 10).to_string())
+    }
+
+    fn part_b((initial, rules): &Self::Input) -> Option<String> {
+        Some(polymerize(initial.clone(), rules, 40).to_string())
+    }
+}
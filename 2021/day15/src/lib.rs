@@ -0,0 +1,61 @@
+use aoc::Solution;
+
+pub type Grid = Vec<Vec<usize>>;
+
+fn lowest_risk(grid: &Grid) -> usize {
+    let (width, height) = (grid[0].len(), grid.len());
+    let successors = |&(x, y): &(usize, usize)| {
+        let mut next = vec![];
+        if x > 0 {
+            next.push(((x - 1, y), grid[y][x - 1]));
+        }
+        if x + 1 < width {
+            next.push(((x + 1, y), grid[y][x + 1]));
+        }
+        if y > 0 {
+            next.push(((x, y - 1), grid[y - 1][x]));
+        }
+        if y + 1 < height {
+            next.push(((x, y + 1), grid[y + 1][x]));
+        }
+        next
+    };
+    let (_, risk) =
+        aoc_search::dijkstra([(0, 0)], successors, |&pos| pos == (width - 1, height - 1))
+            .expect("Couldn't reach the end...");
+    risk
+}
+
+fn make_big(grid: &Grid) -> Grid {
+    let mut big_grid = Vec::with_capacity(grid.len() * 5);
+    for y_tile in 0..5 {
+        for y in 0..grid.len() {
+            let mut row = Vec::with_capacity(grid[0].len() * 5);
+            for x_tile in 0..5 {
+                for x in 0..grid[0].len() {
+                    row.push(((y_tile + x_tile + grid[y][x] - 1) % 9) + 1);
+                }
+            }
+            big_grid.push(row);
+        }
+    }
+    big_grid
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Grid;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_rows(input, |cell| cell.digit().map(usize::from))
+    }
+
+    fn part_a(grid: &Self::Input) -> Option<String> {
+        Some(lowest_risk(grid).to_string())
+    }
+
+    fn part_b(grid: &Self::Input) -> Option<String> {
+        Some(lowest_risk(&make_big(grid)).to_string())
+    }
+}
@@ -0,0 +1,341 @@
+use aoc::Solution;
+use aoc_parse::{Cursor, Error};
+use bit_vec::BitVec;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum PacketType {
+    Sum,
+    Product,
+    Min,
+    Max,
+    Literal,
+    Gt,
+    Lt,
+    Eq,
+}
+
+impl From<u8> for PacketType {
+    fn from(type_id: u8) -> Self {
+        match type_id {
+            0 => Self::Sum,
+            1 => Self::Product,
+            2 => Self::Min,
+            3 => Self::Max,
+            4 => Self::Literal,
+            5 => Self::Gt,
+            6 => Self::Lt,
+            7 => Self::Eq,
+            _ => panic!("Invalid type ID: {}", type_id),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Payload {
+    Literal(usize),
+    Operands(Vec<Packet>),
+}
+
+#[derive(Clone, Debug)]
+pub struct Packet {
+    version: u8,
+    packet_type: PacketType,
+    payload: Payload,
+}
+
+impl Packet {
+    pub fn version_sum(&self) -> usize {
+        self.version as usize
+            + match &self.payload {
+                Payload::Literal(_) => 0,
+                Payload::Operands(packets) => packets.iter().map(|pkt| pkt.version_sum()).sum(),
+            }
+    }
+
+    pub fn eval(&self) -> usize {
+        match &self.payload {
+            Payload::Literal(num) => *num,
+            Payload::Operands(operands) => match self.packet_type {
+                PacketType::Sum => operands.iter().map(|packet| packet.eval()).sum(),
+                PacketType::Product => operands.iter().map(|packet| packet.eval()).product(),
+                PacketType::Min => operands.iter().map(|packet| packet.eval()).min().unwrap(),
+                PacketType::Max => operands.iter().map(|packet| packet.eval()).max().unwrap(),
+                PacketType::Literal => panic!("Nonliteral payload on a literal packet?"),
+                PacketType::Gt => {
+                    assert_eq!(operands.len(), 2);
+                    if operands[0].eval() > operands[1].eval() {
+                        1
+                    } else {
+                        0
+                    }
+                }
+                PacketType::Lt => {
+                    assert_eq!(operands.len(), 2);
+                    if operands[0].eval() < operands[1].eval() {
+                        1
+                    } else {
+                        0
+                    }
+                }
+                PacketType::Eq => {
+                    assert_eq!(operands.len(), 2);
+                    if operands[0].eval() == operands[1].eval() {
+                        1
+                    } else {
+                        0
+                    }
+                }
+            },
+        }
+    }
+}
+
+/// Reads a line of hex digits two at a time, one byte per pair.
+fn decode_hex(line: &mut Cursor) -> aoc_parse::Result<Vec<u8>> {
+    let nibble = |line: &mut Cursor| {
+        let before = *line;
+        match line.char()?.to_digit(16) {
+            Some(digit) => Ok(digit as u8),
+            None => Err(before.error("expected a hex digit")),
+        }
+    };
+    if line.rest().len() % 2 != 0 {
+        return Err(line.error("expected an even number of hex digits"));
+    }
+    let mut bytes = vec![];
+    while !line.is_empty() {
+        bytes.push(nibble(line)? << 4 | nibble(line)?);
+    }
+    Ok(bytes)
+}
+
+struct Parser {
+    bits: BitVec<u32>,
+    offset: usize,
+    line_no: usize,
+}
+
+impl Parser {
+    pub fn new(bits: BitVec<u32>, line_no: usize) -> Self {
+        Self {
+            bits,
+            offset: 0,
+            line_no,
+        }
+    }
+
+    /// An error pointing at the hex digit holding the current bit.
+    fn error(&self, msg: impl Into<String>) -> Error {
+        Error::new(self.line_no, self.offset / 4 + 1, msg)
+    }
+
+    pub fn parse_packet(&mut self) -> aoc_parse::Result<Packet> {
+        let (version, packet_type) = self.parse_header()?;
+        let payload = match packet_type {
+            PacketType::Literal => Payload::Literal(self.parse_literal()?),
+            _ => {
+                let subpackets = if self.get()? {
+                    let num_subpackets = self.parse_number(11)?;
+
+                    let mut subpackets = vec![];
+                    for _ in 0..num_subpackets {
+                        subpackets.push(self.parse_packet()?);
+                    }
+                    subpackets
+                } else {
+                    let subpackets_size = self.parse_number(15)?;
+
+                    let subpacket_start = self.offset;
+                    let mut subpackets = vec![];
+                    while self.offset < subpacket_start + subpackets_size {
+                        subpackets.push(self.parse_packet()?);
+                    }
+                    if self.offset != subpacket_start + subpackets_size {
+                        return Err(self.error(format!(
+                            "subpackets overran their length of {subpackets_size} bits"
+                        )));
+                    }
+                    subpackets
+                };
+                let arity_ok = match packet_type {
+                    PacketType::Gt | PacketType::Lt | PacketType::Eq => subpackets.len() == 2,
+                    _ => !subpackets.is_empty(),
+                };
+                if !arity_ok {
+                    return Err(self.error(format!(
+                        "{packet_type:?} packet can't have {} subpackets",
+                        subpackets.len()
+                    )));
+                }
+                Payload::Operands(subpackets)
+            }
+        };
+        Ok(Packet {
+            version,
+            packet_type,
+            payload,
+        })
+    }
+
+    fn get(&mut self) -> aoc_parse::Result<bool> {
+        let bit = self
+            .bits
+            .get(self.offset)
+            .ok_or_else(|| self.error("expected more bits, found the end of the line"))?;
+        self.offset += 1;
+        Ok(bit)
+    }
+
+    fn parse_number(&mut self, size: usize) -> aoc_parse::Result<usize> {
+        let mut num = 0;
+        for _ in 0..size {
+            num *= 2;
+            num += if self.get()? { 1 } else { 0 };
+        }
+        Ok(num)
+    }
+
+    fn parse_header(&mut self) -> aoc_parse::Result<(u8, PacketType)> {
+        let version = self.parse_number(3)? as u8;
+        let type_id = PacketType::from(self.parse_number(3)? as u8);
+        Ok((version, type_id))
+    }
+
+    fn parse_literal(&mut self) -> aoc_parse::Result<usize> {
+        let mut literal: usize = 0;
+        let mut keep_going = true;
+        while keep_going {
+            keep_going = self.get()?;
+            literal = literal
+                .checked_mul(16)
+                .ok_or_else(|| self.error("literal is too big"))?;
+            literal += self.parse_number(4)?;
+        }
+        Ok(literal)
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Packet;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let mut line = aoc_parse::single_line(input)?;
+        let line_no = line.line_no();
+        let bits = BitVec::from_bytes(&decode_hex(&mut line)?);
+        Parser::new(bits, line_no).parse_packet()
+    }
+
+    fn part_a(packet: &Self::Input) -> Option<String> {
+        Some(packet.version_sum().to_string())
+    }
+
+    fn part_b(packet: &Self::Input) -> Option<String> {
+        Some(packet.eval().to_string())
+    }
+}
+
+#[cfg(test)]
+fn hex_to_packet(hex: &str) -> Packet {
+    Day16::try_parse(hex).unwrap()
+}
+
+#[test]
+fn test_sample1() {
+    let packet = hex_to_packet("38006F45291200");
+    assert_eq!(packet.version, 1);
+    assert_eq!(packet.packet_type, PacketType::Lt);
+    match packet.payload {
+        Payload::Operands(subpackets) => {
+            assert_eq!(subpackets.len(), 2);
+            assert_eq!(subpackets[0].packet_type, PacketType::Literal);
+            match subpackets[0].payload {
+                Payload::Literal(num) => assert_eq!(num, 10),
+                _ => panic!("Incorrect payload type"),
+            }
+            assert_eq!(subpackets[1].packet_type, PacketType::Literal);
+            match subpackets[1].payload {
+                Payload::Literal(num) => assert_eq!(num, 20),
+                _ => panic!("Incorrect payload type"),
+            }
+        }
+        _ => panic!("Incorrect payload type"),
+    }
+}
+
+#[test]
+fn test_sample2() {
+    let packet = hex_to_packet("EE00D40C823060");
+    assert_eq!(packet.version, 7);
+    assert_eq!(packet.packet_type, PacketType::Max);
+    match packet.payload {
+        Payload::Operands(subpackets) => {
+            assert_eq!(subpackets.len(), 3);
+            assert_eq!(subpackets[0].packet_type, PacketType::Literal);
+            match subpackets[0].payload {
+                Payload::Literal(num) => assert_eq!(num, 1),
+                _ => panic!("Incorrect payload type"),
+            }
+            assert_eq!(subpackets[1].packet_type, PacketType::Literal);
+            match subpackets[1].payload {
+                Payload::Literal(num) => assert_eq!(num, 2),
+                _ => panic!("Incorrect payload type"),
+            }
+            assert_eq!(subpackets[2].packet_type, PacketType::Literal);
+            match subpackets[2].payload {
+                Payload::Literal(num) => assert_eq!(num, 3),
+                _ => panic!("Incorrect payload type"),
+            }
+        }
+        _ => panic!("Incorrect payload type"),
+    }
+}
+
+#[test]
+fn test_sample3() {
+    let packet = hex_to_packet("8A004A801A8002F478");
+    assert_eq!(packet.version, 4);
+
+    let subpacket = match packet.payload {
+        Payload::Operands(subpackets) => {
+            assert_eq!(subpackets.len(), 1);
+            subpackets.into_iter().next().unwrap()
+        }
+        _ => panic!("Incorrect payload type"),
+    };
+    assert_eq!(subpacket.version, 1);
+
+    let subpacket = match subpacket.payload {
+        Payload::Operands(subpackets) => {
+            assert_eq!(subpackets.len(), 1);
+            subpackets.into_iter().next().unwrap()
+        }
+        _ => panic!("Incorrect payload type"),
+    };
+    assert_eq!(subpacket.version, 5);
+    let subpacket = match subpacket.payload {
+        Payload::Operands(subpackets) => {
+            assert_eq!(subpackets.len(), 1);
+            subpackets.into_iter().next().unwrap()
+        }
+        _ => panic!("Incorrect payload type"),
+    };
+    assert_eq!(subpacket.version, 6);
+    assert_eq!(subpacket.packet_type, PacketType::Literal);
+}
+
+#[test]
+fn test_sample4() {
+    hex_to_packet("620080001611562C8802118E34");
+}
+
+#[test]
+fn test_sample5() {
+    hex_to_packet("C0015000016115A2E0802F182340");
+}
+
+#[test]
+fn test_sample6() {
+    hex_to_packet("A0016C880162017C3686B18A3D4780");
+}
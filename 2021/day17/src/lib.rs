@@ -0,0 +1,114 @@
+use aoc::Solution;
+
+#[derive(Copy, Clone, Debug)]
+pub struct Target {
+    pub min_x: i32,
+    pub max_x: i32,
+    pub min_y: i32,
+    pub max_y: i32,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Probe {
+    position: (i32, i32),
+    velocity: (i32, i32),
+}
+
+impl Probe {
+    pub fn new(velocity: (i32, i32)) -> Self {
+        let position = (0, 0);
+        Probe { position, velocity }
+    }
+}
+
+fn step(probe: &Probe) -> Probe {
+    let (mut x, mut y) = probe.position;
+    let (mut dx, mut dy) = probe.velocity;
+
+    x += dx;
+    y += dy;
+
+    if dx < 0 {
+        dx += 1;
+    } else if dx > 0 {
+        dx -= 1;
+    }
+
+    dy -= 1;
+
+    Probe {
+        position: (x, y),
+        velocity: (dx, dy),
+    }
+}
+
+fn within_target(probe: &Probe, target: &Target) -> bool {
+    let (x, y) = probe.position;
+    target.min_x <= x && x <= target.max_x && target.min_y <= y && y <= target.max_y
+}
+
+fn parse_range(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<(i32, i32)> {
+    let before = *line;
+    let min = line.int()?;
+    line.literal("..")?;
+    let max = line.int()?;
+    if max < min {
+        return Err(before.error(format!("range {min}..{max} runs backwards")));
+    }
+    Ok((min, max))
+}
+
+fn get_highest_point(target: &Target, dx: i32, dy: i32) -> Option<i32> {
+    let mut probe = Probe::new((dx, dy));
+    let mut highest_point = 0;
+    while target.min_y <= probe.position.1 || probe.velocity.1 > 0 {
+        highest_point = highest_point.max(probe.position.1);
+        if within_target(&probe, target) {
+            return Some(highest_point);
+        }
+        probe = step(&probe);
+    }
+    None
+}
+
+fn highest_points(target: &Target) -> Vec<i32> {
+    let mut highest_points = vec![];
+    for dx in -500..500 {
+        for dy in -500..500 {
+            if let Some(high_point) = get_highest_point(target, dx, dy) {
+                highest_points.push(high_point);
+            }
+        }
+    }
+    highest_points
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Target;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let mut line = aoc_parse::single_line(input)?;
+        line.literal("target area: x=")?;
+        let (min_x, max_x) = parse_range(&mut line)?;
+        line.literal(", y=")?;
+        let (min_y, max_y) = parse_range(&mut line)?;
+        line.end()?;
+        Ok(Target {
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+        })
+    }
+
+    fn part_a(target: &Self::Input) -> Option<String> {
+        let highest = highest_points(target).into_iter().fold(0, i32::max);
+        Some(highest.to_string())
+    }
+
+    fn part_b(target: &Self::Input) -> Option<String> {
+        Some(highest_points(target).len().to_string())
+    }
+}
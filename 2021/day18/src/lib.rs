@@ -0,0 +1,283 @@
+use aoc::Solution;
+use aoc_parse::Cursor;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SnailNum {
+    Literal(u64),
+    Nested(Box<SnailNum>, Box<SnailNum>),
+}
+
+impl SnailNum {
+    fn add(left: SnailNum, right: SnailNum) -> Self {
+        Self::Nested(Box::new(left), Box::new(right))
+    }
+
+    fn magnitude(&self) -> u64 {
+        match self {
+            Self::Literal(n) => *n,
+            Self::Nested(left, right) => 3 * left.magnitude() + 2 * right.magnitude(),
+        }
+    }
+}
+
+#[test]
+fn test_magnitude() {
+    assert_eq!(snail("[[1,2],[[3,4],5]]").magnitude(), 143);
+    assert_eq!(snail("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]").magnitude(), 1384);
+    assert_eq!(snail("[[[[1,1],[2,2]],[3,3]],[4,4]]").magnitude(), 445);
+}
+
+/// Reads a number or a bracketed pair of snailfish numbers.
+fn parse_snail(cursor: &mut Cursor) -> aoc_parse::Result<SnailNum> {
+    if cursor.eat("[") {
+        let left = parse_snail(cursor)?;
+        cursor.literal(",")?;
+        let right = parse_snail(cursor)?;
+        cursor.literal("]")?;
+        Ok(SnailNum::add(left, right))
+    } else {
+        Ok(SnailNum::Literal(cursor.int()?))
+    }
+}
+
+#[cfg(test)]
+fn snail(line: &str) -> SnailNum {
+    let mut cursor = Cursor::new(line, 1);
+    let num = parse_snail(&mut cursor).unwrap();
+    cursor.end().unwrap();
+    num
+}
+
+fn add_to_the_left(num: SnailNum, add: u64) -> (SnailNum, u64) {
+    if add == 0 {
+        return (num, add);
+    }
+    match num {
+        SnailNum::Nested(left, right) => {
+            let (left, add) = add_to_the_left(*left, add);
+            let (right, add) = add_to_the_left(*right, add);
+            (SnailNum::add(left, right), add)
+        }
+        SnailNum::Literal(num) => (SnailNum::Literal(num + add), 0),
+    }
+}
+
+#[test]
+fn test_add_to_the_left() {
+    assert_eq!(
+        add_to_the_left(snail("[[[1,2],3],4]"), 5),
+        (snail("[[[6,2],3],4]"), 0)
+    );
+}
+
+#[test]
+fn test_add_to_the_right() {
+    assert_eq!(
+        add_to_the_right(snail("[[[1,2],3],4]"), 5),
+        (snail("[[[1,2],3],9]"), 0)
+    );
+}
+
+fn add_to_the_right(num: SnailNum, add: u64) -> (SnailNum, u64) {
+    if add == 0 {
+        return (num, add);
+    }
+    match num {
+        SnailNum::Nested(left, right) => {
+            let (right, add) = add_to_the_right(*right, add);
+            let (left, add) = add_to_the_right(*left, add);
+            (SnailNum::add(left, right), add)
+        }
+        SnailNum::Literal(num) => (SnailNum::Literal(num + add), 0),
+    }
+}
+
+fn should_immediately_explode(left: &SnailNum, right: &SnailNum, depth: i64) -> Option<(u64, u64)> {
+    if depth < 4 {
+        None
+    } else {
+        match (left, right) {
+            (SnailNum::Literal(left_num), SnailNum::Literal(right_num)) => {
+                Some((*left_num, *right_num))
+            }
+            _ => None,
+        }
+    }
+}
+
+/**
+ * Returns the new value of `num`, and an option
+ *
+ * If no explosion happens in our subtree, the option will be None.
+ *
+ * If an explosion happens in our subtree, the option will contain
+ * two numbers which need to be added higher up in the tree.
+ */
+fn explode_inner(num: &SnailNum, depth: i64) -> Option<(SnailNum, u64, u64)> {
+    match num {
+        SnailNum::Literal(_) => None,
+        SnailNum::Nested(left, right) => {
+            if let Some((left_num, right_num)) = should_immediately_explode(left, right, depth) {
+                Some((SnailNum::Literal(0), left_num.clone(), right_num.clone()))
+            } else {
+                if let Some((new_left, left_add, right_add)) = explode_inner(&*left, depth + 1) {
+                    let (new_right, right_add) = add_to_the_left(*right.clone(), right_add);
+                    Some((SnailNum::add(new_left, new_right), left_add, right_add))
+                } else if let Some((new_right, left_add, right_add)) =
+                    explode_inner(&*right, depth + 1)
+                {
+                    let (new_left, left_add) = add_to_the_right(*left.clone(), left_add);
+                    Some((SnailNum::add(new_left, new_right), left_add, right_add))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+fn explode(num: &SnailNum) -> Option<SnailNum> {
+    match num {
+        SnailNum::Literal(_) => Some(num.clone()),
+        SnailNum::Nested(_, _) => {
+            if let Some((new, _, _)) = explode_inner(num, 0) {
+                Some(new)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+#[test]
+fn test_explode_1() {
+    assert_eq!(
+        explode(&snail("[[[[[9,8],1],2],3],4]")),
+        Some(snail("[[[[0,9],2],3],4]"))
+    );
+}
+
+#[test]
+fn test_explode_2() {
+    assert_eq!(
+        explode(&snail("[7,[6,[5,[4,[3,2]]]]]")),
+        Some(snail("[7,[6,[5,[7,0]]]]"))
+    );
+}
+
+#[test]
+fn test_explode_3() {
+    assert_eq!(
+        explode(&snail("[[6,[5,[4,[3,2]]]],1]")),
+        Some(snail("[[6,[5,[7,0]]],3]"))
+    );
+}
+
+#[test]
+fn test_explode_4() {
+    assert_eq!(
+        explode(&snail("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]")),
+        Some(snail("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]"))
+    );
+}
+
+#[test]
+fn test_explode_5() {
+    assert_eq!(
+        explode(&snail("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]")),
+        Some(snail("[[3,[2,[8,0]]],[9,[5,[7,0]]]]"))
+    );
+}
+
+fn split(num: &SnailNum) -> Option<SnailNum> {
+    match num {
+        SnailNum::Literal(n) => {
+            if *n >= 10 {
+                Some(SnailNum::add(
+                    SnailNum::Literal(n / 2),
+                    SnailNum::Literal((n + 1) / 2),
+                ))
+            } else {
+                None
+            }
+        }
+        SnailNum::Nested(left, right) => {
+            if let Some(splitted_left) = split(&left) {
+                Some(SnailNum::add(splitted_left, *right.clone()))
+            } else {
+                if let Some(splitted_right) = split(&right) {
+                    Some(SnailNum::add(*left.clone(), splitted_right))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_split_1() {
+    assert_eq!(
+        split(&snail("[[[[0,7],4],[15,[0,13]]],[1,1]]")),
+        Some(snail("[[[[0,7],4],[[7,8],[0,13]]],[1,1]]"))
+    );
+}
+
+#[test]
+fn test_split_2() {
+    assert_eq!(
+        split(&snail("[[[[0,7],4],[[7,8],[0,13]]],[1,1]]")),
+        Some(snail("[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]"))
+    );
+}
+
+fn reduce(mut num: SnailNum) -> SnailNum {
+    loop {
+        if let Some(exploded) = explode(&num) {
+            num = exploded;
+        } else if let Some(splitted) = split(&num) {
+            num = splitted;
+        } else {
+            break;
+        }
+    }
+    num
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<SnailNum>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let nums = aoc_parse::parse_lines(input, parse_snail)?;
+        if nums.is_empty() {
+            return Err(aoc::ParseError::new(1, 1, "expected a snailfish number"));
+        }
+        Ok(nums)
+    }
+
+    fn part_a(nums: &Self::Input) -> Option<String> {
+        let mut nums_iter = nums.iter().cloned();
+        let mut sum = nums_iter.next()?;
+        for n in nums_iter {
+            sum = SnailNum::add(sum, n);
+            sum = reduce(sum);
+        }
+        sum = reduce(sum);
+        Some(sum.magnitude().to_string())
+    }
+
+    fn part_b(nums: &Self::Input) -> Option<String> {
+        let mut big = 0;
+        for i in 0..nums.len() {
+            for j in 0..nums.len() {
+                if i == j {
+                    continue;
+                }
+                big = big.max(reduce(SnailNum::add(nums[i].clone(), nums[j].clone())).magnitude());
+            }
+        }
+        Some(big.to_string())
+    }
+}
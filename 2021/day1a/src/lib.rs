@@ -0,0 +1,23 @@
+use aoc::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<usize>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, |line| line.int())
+    }
+
+    fn part_a(input: &Self::Input) -> Option<String> {
+        let mut count = 0;
+        let mut last = 0;
+        for &num in input {
+            if num > last {
+                count += 1;
+            }
+            last = num;
+        }
+        Some((count - 1).to_string())
+    }
+}
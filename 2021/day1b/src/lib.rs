@@ -0,0 +1,26 @@
+use aoc::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<usize>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, |line| line.int())
+    }
+
+    fn part_b(input: &Self::Input) -> Option<String> {
+        let mut window = std::collections::VecDeque::<usize>::with_capacity(4);
+        let mut count = 0;
+        for &num in input {
+            window.push_back(num);
+            if window.len() == 4 {
+                if window[0] < window[3] {
+                    count += 1;
+                }
+                window.pop_front();
+            }
+        }
+        Some(count.to_string())
+    }
+}
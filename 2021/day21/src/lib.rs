@@ -0,0 +1,141 @@
+use aoc::Solution;
+
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+struct Player {
+    pos: usize,
+    score: usize,
+}
+
+impl Player {
+    fn new(pos: usize) -> Self {
+        Player {
+            pos: pos - 1,
+            score: 0,
+        }
+    }
+
+    fn advance(&mut self, roll: usize) {
+        self.pos += roll;
+        self.pos %= 10;
+        self.score += self.position();
+    }
+
+    fn position(&self) -> usize {
+        self.pos + 1
+    }
+}
+
+fn parse_start(line: &mut aoc_parse::Cursor, player: &str) -> aoc_parse::Result<usize> {
+    line.literal(player)?;
+    line.literal(" starting position: ")?;
+    let before = *line;
+    let pos = line.int()?;
+    line.end()?;
+    if !(1..=10).contains(&pos) {
+        return Err(before.error(format!("position {pos} is off the board")));
+    }
+    Ok(pos)
+}
+
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+struct Dirac {
+    players: [Player; 2],
+}
+
+impl Dirac {
+    fn new(pos: [usize; 2]) -> Self {
+        Dirac {
+            players: [Player::new(pos[0]), Player::new(pos[1])],
+        }
+    }
+
+    fn advance(&self, p: usize, roll: usize) -> Dirac {
+        let mut players = self.players;
+        players[p].advance(roll);
+        Dirac { players }
+    }
+}
+
+struct DeterministicDie {
+    rolls: usize,
+}
+
+impl DeterministicDie {
+    fn new() -> Self {
+        Self { rolls: 0 }
+    }
+
+    fn roll(&mut self) -> usize {
+        let roll = 3 * self.rolls + 6;
+        self.rolls += 3;
+        roll
+    }
+
+    fn nrolls(&self) -> usize {
+        self.rolls
+    }
+}
+
+fn practice(p1_pos: usize, p2_pos: usize) -> usize {
+    let mut dirac = Dirac::new([p1_pos, p2_pos]);
+    let mut die = DeterministicDie::new();
+    for p in (0..=1).cycle() {
+        dirac = dirac.advance(p, die.roll());
+        if dirac.players[p].score >= 1000 {
+            break;
+        }
+    }
+    dirac.players.iter().map(|p| p.score).min().unwrap() * die.nrolls()
+}
+
+type DiracMemo = std::collections::HashMap<Dirac, usize>;
+
+fn real(p1_pos: usize, p2_pos: usize) -> [usize; 2] {
+    let mut games = DiracMemo::from([(Dirac::new([p1_pos, p2_pos]), 1)]);
+    let rolls: Vec<usize> = (1..=3)
+        .flat_map(|a| (1..=3).flat_map(move |b| (1..=3).map(move |c| a + b + c)))
+        .collect();
+    let mut wins = [0, 0];
+    for p in (0..=1).cycle() {
+        let mut next = DiracMemo::new();
+        for &roll in rolls.iter() {
+            for (game, ngames) in games.iter() {
+                let advanced = game.advance(p, roll);
+                if advanced.players[p].score >= 21 {
+                    wins[p] += ngames;
+                } else {
+                    *next.entry(advanced).or_default() += ngames;
+                }
+            }
+        }
+        games = next;
+        if games.is_empty() {
+            break;
+        }
+    }
+    wins
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = (usize, usize);
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let [section] = aoc_parse::split_sections(input)?;
+        let [mut p1, mut p2] = section.split_lines()?;
+        Ok((
+            parse_start(&mut p1, "Player 1")?,
+            parse_start(&mut p2, "Player 2")?,
+        ))
+    }
+
+    fn part_a(&(p1_start, p2_start): &Self::Input) -> Option<String> {
+        Some(practice(p1_start, p2_start).to_string())
+    }
+
+    fn part_b(&(p1_start, p2_start): &Self::Input) -> Option<String> {
+        let wins = real(p1_start, p2_start);
+        Some(wins.iter().max().unwrap().to_string())
+    }
+}
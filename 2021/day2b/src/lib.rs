@@ -0,0 +1,42 @@
+use aoc::Solution;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Dir {
+    Forward,
+    Up,
+    Down,
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<(Dir, i32)>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, |line| {
+            let dir = line.one_of(&[
+                ("forward ", Dir::Forward),
+                ("up ", Dir::Up),
+                ("down ", Dir::Down),
+            ])?;
+            Ok((dir, line.int()?))
+        })
+    }
+
+    fn part_b(input: &Self::Input) -> Option<String> {
+        let mut x = 0;
+        let mut d = 0;
+        let mut aim = 0;
+        for &(dir, mag) in input {
+            match dir {
+                Dir::Forward => {
+                    x += mag;
+                    d += aim * mag;
+                }
+                Dir::Up => aim -= mag,
+                Dir::Down => aim += mag,
+            };
+        }
+        Some((x * d).to_string())
+    }
+}
@@ -0,0 +1,41 @@
+use aoc::Solution;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<u8>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_rows(input, |bit| bit.one_of(&[("0", b'0'), ("1", b'1')]))
+    }
+
+    fn part_a(nums: &Self::Input) -> Option<String> {
+        Some(power_consumption(nums).to_string())
+    }
+}
+
+fn power_consumption(nums: &[Vec<u8>]) -> isize {
+    let bitlen = nums[0].len();
+    let mut gamma = String::new();
+    let mut epsilon = String::new();
+    for i in 0..bitlen {
+        let mut count = 0;
+        for num in nums {
+            match num[i] as char {
+                '0' => count -= 1,
+                '1' => count += 1,
+                _ => panic!("Unexpected string {:?}", num),
+            }
+        }
+        if count > 0 {
+            gamma.push('1');
+            epsilon.push('0');
+        } else {
+            gamma.push('0');
+            epsilon.push('1');
+        }
+    }
+    let gamma_dec = isize::from_str_radix(&gamma, 2).unwrap();
+    let epsilon_dec = isize::from_str_radix(&epsilon, 2).unwrap();
+    gamma_dec * epsilon_dec
+}
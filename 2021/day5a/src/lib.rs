@@ -0,0 +1,94 @@
+use aoc::Solution;
+
+type Line = ((usize, usize), (usize, usize));
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<Line>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, |line| {
+            let left = parse_pair(line)?;
+            line.literal(" -> ")?;
+            Ok((left, parse_pair(line)?))
+        })
+    }
+
+    fn part_b(lines: &Self::Input) -> Option<String> {
+        Some(intersections(lines).to_string())
+    }
+}
+
+fn parse_pair(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<(usize, usize)> {
+    let x = line.int()?;
+    line.literal(",")?;
+    Ok((x, line.int()?))
+}
+
+fn simplify(mut x: usize, mut y: usize) -> (usize, usize) {
+    for factor in 2..=x.min(y) {
+        while x % factor == 0 && y % factor == 0 {
+            x /= factor;
+            y /= factor;
+        }
+    }
+    (x, y)
+}
+
+fn intersections(lines: &[Line]) -> usize {
+    let xmax = *lines
+        .iter()
+        .map(|((x1, _), (x2, _))| x1.max(x2))
+        .max()
+        .unwrap();
+    let ymax = *lines
+        .iter()
+        .map(|((_, y1), (_, y2))| y1.max(y2))
+        .max()
+        .unwrap();
+    let mut grid = vec![vec![0; ymax + 1]; xmax + 1];
+    for &((mut x1, mut y1), (mut x2, mut y2)) in lines {
+        if x2 < x1 {
+            std::mem::swap(&mut x1, &mut x2);
+            std::mem::swap(&mut y1, &mut y2);
+        }
+        let (dx, dy) = simplify(x2.max(x1) - x2.min(x1), y2.max(y1) - y2.min(y1));
+        if dx == 0 {
+            for y in y1.min(y2)..=y1.max(y2) {
+                grid[x1][y] += 1;
+            }
+        } else if dy == 0 {
+            for x in x1.min(x2)..=x1.max(x2) {
+                grid[x][y1] += 1;
+            }
+        } else if y1 < y2 {
+            let mut x = x1.min(x2);
+            let mut y = y1.min(y2);
+            while x <= x1.max(x2) && y <= y1.max(y2) {
+                grid[x][y] += 1;
+                x += dx;
+                y += dy;
+            }
+        } else {
+            let (mut x, mut y) = (x1, y1);
+            while x <= x2 && y >= y2 {
+                grid[x][y] += 1;
+                x += dx;
+                if y < dy {
+                    break;
+                }
+                y -= dy;
+            }
+        }
+    }
+    let mut intersections = 0;
+    for x in 0..=xmax {
+        for y in 0..=ymax {
+            if grid[x][y] > 1 {
+                intersections += 1;
+            }
+        }
+    }
+    intersections
+}
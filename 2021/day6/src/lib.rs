@@ -0,0 +1,45 @@
+use aoc::Solution;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = [usize; 9];
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let mut fish = [0; 9];
+        let mut line = aoc_parse::single_line(input)?;
+        line.list(",", |timer| {
+            let before = *timer;
+            match timer.int::<usize>()? {
+                days @ 0..=8 => {
+                    fish[days] += 1;
+                    Ok(())
+                }
+                days => Err(before.error(format!("timer {days} is past 8"))),
+            }
+        })?;
+        line.end()?;
+        Ok(fish)
+    }
+
+    fn part_a(fish: &Self::Input) -> Option<String> {
+        Some(simulate(*fish, 80).to_string())
+    }
+
+    fn part_b(fish: &Self::Input) -> Option<String> {
+        Some(simulate(*fish, 256).to_string())
+    }
+}
+
+fn simulate(mut fish: [usize; 9], days: usize) -> usize {
+    for _ in 1..=days {
+        let spawning = fish[0];
+        for f in 1..=8 {
+            fish[f - 1] = fish[f];
+        }
+        fish[6] += spawning;
+        fish[8] = spawning;
+    }
+
+    fish.iter().sum::<usize>()
+}
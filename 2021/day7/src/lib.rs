@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use aoc::Solution;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = HashMap<i64, usize>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let mut crabs: HashMap<i64, usize> = HashMap::new();
+        let mut line = aoc_parse::single_line(input)?;
+        for pos in line.ints(",")? {
+            *crabs.entry(pos).or_insert(0) += 1;
+        }
+        line.end()?;
+        Ok(crabs)
+    }
+
+    fn part_b(crabs: &Self::Input) -> Option<String> {
+        let (_, fuel) = best_position(crabs);
+        Some(fuel.to_string())
+    }
+}
+
+fn triangle(x: i64) -> i64 {
+    return (x * (x + 1)) / 2;
+}
+
+fn best_position(crabs: &HashMap<i64, usize>) -> (i64, i64) {
+    let min_pos = *crabs.keys().min().unwrap();
+    let max_pos = *crabs.keys().max().unwrap();
+
+    let mut best = (0, i64::MAX);
+    for pos in min_pos..=max_pos {
+        let mut dist = 0;
+        for (&crab_pos, &count) in crabs.iter() {
+            dist += triangle((crab_pos - pos).abs()) * count as i64;
+        }
+        if dist < best.1 {
+            best = (pos, dist);
+        }
+    }
+    best
+}
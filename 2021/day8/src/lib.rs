@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use aoc::Solution;
+
+const A_SCORE: i64 = 8;
+const B_SCORE: i64 = 6;
+const C_SCORE: i64 = 8;
+const D_SCORE: i64 = 7;
+const E_SCORE: i64 = 4;
+const F_SCORE: i64 = 9;
+const G_SCORE: i64 = 7;
+
+const ZERO_SCORE: i64 = A_SCORE + B_SCORE + C_SCORE + E_SCORE + F_SCORE + G_SCORE;
+const ONE_SCORE: i64 = C_SCORE + F_SCORE;
+const TWO_SCORE: i64 = A_SCORE + C_SCORE + D_SCORE + E_SCORE + G_SCORE;
+const THREE_SCORE: i64 = A_SCORE + C_SCORE + D_SCORE + F_SCORE + G_SCORE;
+const FOUR_SCORE: i64 = B_SCORE + C_SCORE + D_SCORE + F_SCORE;
+const FIVE_SCORE: i64 = A_SCORE + B_SCORE + D_SCORE + F_SCORE + G_SCORE;
+const SIX_SCORE: i64 = A_SCORE + B_SCORE + D_SCORE + E_SCORE + F_SCORE + G_SCORE;
+const SEVEN_SCORE: i64 = A_SCORE + C_SCORE + F_SCORE;
+const EIGHT_SCORE: i64 = A_SCORE + B_SCORE + C_SCORE + D_SCORE + E_SCORE + F_SCORE + G_SCORE;
+const NINE_SCORE: i64 = A_SCORE + B_SCORE + C_SCORE + D_SCORE + F_SCORE + G_SCORE;
+
+/// The ten unique signal patterns seen on one display, and the four digits
+/// of its output value.
+pub struct Note {
+    patterns: Vec<String>,
+    outputs: Vec<String>,
+}
+
+/// Reads a note like `acedgfb cdfbe ... ab | cdfeb fcadb cdfeb cdbaf`.
+fn parse_note(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Note> {
+    let mut patterns = vec![];
+    loop {
+        patterns.push(line.word()?.to_owned());
+        if line.eat(" | ") {
+            break;
+        }
+        line.literal(" ")?;
+    }
+    let outputs = line.list(" ", |line| Ok(line.word()?.to_owned()))?;
+    Ok(Note { patterns, outputs })
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Note>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, parse_note)
+    }
+
+    fn part_b(notes: &Self::Input) -> Option<String> {
+        Some(total_output(notes).to_string())
+    }
+}
+
+fn total_output(notes: &[Note]) -> i64 {
+    let mut total_output = 0;
+    for note in notes {
+        let mut letter_counts: HashMap<char, i64> = HashMap::new();
+        for tok in &note.patterns {
+            for c in tok.chars() {
+                *letter_counts.entry(c).or_insert(0) += 1;
+            }
+        }
+        let mut output = 0;
+        for tok in &note.outputs {
+            let digit_score = tok.chars().map(|c| letter_counts.get(&c).unwrap()).sum();
+            output *= 10;
+            output += match digit_score {
+                ZERO_SCORE => 0,
+                ONE_SCORE => 1,
+                TWO_SCORE => 2,
+                THREE_SCORE => 3,
+                FOUR_SCORE => 4,
+                FIVE_SCORE => 5,
+                SIX_SCORE => 6,
+                SEVEN_SCORE => 7,
+                EIGHT_SCORE => 8,
+                NINE_SCORE => 9,
+                _ => panic!(
+                    "Never saw a digit score of {} before for {}",
+                    digit_score, tok,
+                ),
+            };
+        }
+        total_output += output;
+    }
+    total_output
+}
@@ -0,0 +1,74 @@
+use aoc::Solution;
+
+fn is_low(heightmap: &Vec<Vec<i64>>, row: usize, col: usize) -> bool {
+    let point = heightmap[row][col];
+    !((row > 0 && heightmap[row - 1][col] <= point)
+        || (row + 1 < heightmap.len() && heightmap[row + 1][col] <= point)
+        || (col > 0 && heightmap[row][col - 1] <= point)
+        || (col + 1 < heightmap[row].len() && heightmap[row][col + 1] <= point))
+}
+
+fn basin_size(heightmap: &mut Vec<Vec<i64>>, row: usize, col: usize) -> usize {
+    let mut size = 0;
+    let mut stack = vec![(row, col)];
+    while let Some((r, c)) = stack.pop() {
+        if heightmap[r][c] == 9 {
+            continue;
+        }
+        size += 1;
+        heightmap[r][c] = 9;
+        if r > 0 {
+            stack.push((r - 1, c));
+        }
+        if r + 1 < heightmap.len() {
+            stack.push((r + 1, c));
+        }
+        if c > 0 {
+            stack.push((r, c - 1));
+        }
+        if c + 1 < heightmap[r].len() {
+            stack.push((r, c + 1));
+        }
+    }
+    size
+}
+
+fn lowpoints(heightmap: &Vec<Vec<i64>>) -> Vec<(usize, usize)> {
+    let mut lowpoints = vec![];
+    for row in 0..heightmap.len() {
+        for col in 0..heightmap[row].len() {
+            if is_low(&heightmap, row, col) {
+                lowpoints.push((row, col));
+            }
+        }
+    }
+    lowpoints
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<i64>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_rows(input, |cell| cell.digit().map(i64::from))
+    }
+
+    fn part_a(heightmap: &Self::Input) -> Option<String> {
+        let mut risk = 0;
+        for (row, col) in lowpoints(heightmap) {
+            risk += 1 + heightmap[row][col];
+        }
+        Some(risk.to_string())
+    }
+
+    fn part_b(heightmap: &Self::Input) -> Option<String> {
+        let mut heightmap = heightmap.clone();
+        let mut basins = vec![];
+        for (row, col) in lowpoints(&heightmap) {
+            basins.push(basin_size(&mut heightmap, row, col));
+        }
+        basins.sort_by(|a, b| b.cmp(a));
+        Some(basins.iter().take(3).product::<usize>().to_string())
+    }
+}
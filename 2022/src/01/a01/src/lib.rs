@@ -0,0 +1,19 @@
+use aoc::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<Vec<i32>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::sections(input)
+            .iter()
+            .map(|elf| elf.parse_lines(|line| line.int()))
+            .collect()
+    }
+
+    fn part_a(elves: &Self::Input) -> Option<String> {
+        let best = elves.iter().map(|elf| elf.iter().sum::<i32>()).max()?;
+        Some(best.to_string())
+    }
+}
@@ -0,0 +1,33 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use aoc::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<Vec<i32>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::sections(input)
+            .iter()
+            .map(|elf| elf.parse_lines(|line| line.int()))
+            .collect()
+    }
+
+    fn part_b(elves: &Self::Input) -> Option<String> {
+        let mut best = BinaryHeap::new();
+        for elf in elves {
+            let cur: i32 = elf.iter().sum();
+            if best.len() < 3 {
+                best.push(Reverse(cur));
+            } else {
+                let mut x = best.peek_mut().unwrap();
+                if *x > Reverse(cur) {
+                    *x = Reverse(cur);
+                }
+            }
+        }
+        Some(best.iter().map(|Reverse(x)| x).sum::<i32>().to_string())
+    }
+}
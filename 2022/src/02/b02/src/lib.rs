@@ -0,0 +1,35 @@
+use aoc::Solution;
+
+/// Reads a round like `A Y`, as the opponent's shape and the letter in the
+/// second column, each from 0 to 2.
+fn parse_round(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<(u8, u8)> {
+    let opp = line.one_of(&[("A", 0), ("B", 1), ("C", 2)])?;
+    line.literal(" ")?;
+    let second = line.one_of(&[("X", 0), ("Y", 1), ("Z", 2)])?;
+    Ok((opp, second))
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<(u8, u8)>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, parse_round)
+    }
+
+    fn part_b(rounds: &Self::Input) -> Option<String> {
+        let mut my_score = 0;
+        for &(opp, strat) in rounds {
+            my_score += match strat {
+                // lose
+                0 => (3 + opp - 1) % 3 + 1,
+                // draw
+                1 => 3 + opp + 1,
+                // win
+                _ => 6 + (opp + 1) % 3 + 1,
+            } as usize;
+        }
+        Some(my_score.to_string())
+    }
+}
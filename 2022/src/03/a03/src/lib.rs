@@ -0,0 +1,48 @@
+use std::collections::HashSet;
+
+use aoc::Solution;
+
+/// Reads a rucksack's items as their priorities: `a` through `z` are 1
+/// through 26, and `A` through `Z` are 27 through 52.
+fn parse_rucksack(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Vec<usize>> {
+    let mut items = vec![];
+    while !line.is_empty() {
+        let before = *line;
+        let priority = match line.char()? {
+            item @ 'a'..='z' => item as u8 - b'a' + 1,
+            item @ 'A'..='Z' => item as u8 - b'A' + 27,
+            item => return Err(before.error(format!("expected an item, found `{item}`"))),
+        };
+        items.push(priority as usize);
+    }
+    Ok(items)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<usize>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, |line| {
+            let start = *line;
+            let items = parse_rucksack(line)?;
+            if items.len() % 2 != 0 {
+                return Err(start.error("expected an even number of items"));
+            }
+            Ok(items)
+        })
+    }
+
+    fn part_a(rucksacks: &Self::Input) -> Option<String> {
+        let mut sum = 0;
+        for rucksack in rucksacks {
+            let (first, second) = rucksack.split_at(rucksack.len() / 2);
+            let first: HashSet<usize> = first.iter().copied().collect();
+            if let Some(&x) = second.iter().find(|x| first.contains(x)) {
+                sum += x;
+            }
+        }
+        Some(sum.to_string())
+    }
+}
@@ -0,0 +1,51 @@
+use std::collections::HashSet;
+
+use aoc::Solution;
+
+/// Reads a rucksack's items as their priorities: `a` through `z` are 1
+/// through 26, and `A` through `Z` are 27 through 52.
+fn parse_rucksack(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Vec<usize>> {
+    let mut items = vec![];
+    while !line.is_empty() {
+        let before = *line;
+        let priority = match line.char()? {
+            item @ 'a'..='z' => item as u8 - b'a' + 1,
+            item @ 'A'..='Z' => item as u8 - b'A' + 27,
+            item => return Err(before.error(format!("expected an item, found `{item}`"))),
+        };
+        items.push(priority as usize);
+    }
+    Ok(items)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<usize>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let rucksacks = aoc_parse::parse_lines(input, parse_rucksack)?;
+        if rucksacks.len() % 3 != 0 {
+            return Err(aoc::ParseError::new(
+                rucksacks.len() + 1,
+                1,
+                "expected a whole number of groups of three",
+            ));
+        }
+        Ok(rucksacks)
+    }
+
+    fn part_b(rucksacks: &Self::Input) -> Option<String> {
+        let mut sum = 0;
+        for group in rucksacks.chunks(3) {
+            let mut set: HashSet<usize> = group[0].iter().copied().collect();
+            for rucksack in &group[1..] {
+                let rucksack: HashSet<usize> = rucksack.iter().copied().collect();
+                set.retain(|c| rucksack.contains(c));
+            }
+            assert_eq!(set.len(), 1);
+            sum += *set.iter().next().unwrap();
+        }
+        Some(sum.to_string())
+    }
+}
@@ -0,0 +1,39 @@
+use aoc::Solution;
+use aoc_interval::IntervalSet;
+
+/// Reads a range of sections like `2-4`.
+fn parse_range(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<IntervalSet<u32>> {
+    let before = *line;
+    let start: u32 = line.int()?;
+    line.literal("-")?;
+    let finish: u32 = line.int()?;
+    if finish < start {
+        return Err(before.error("range ends before it starts"));
+    }
+    Ok(IntervalSet::from(start..finish + 1))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<(IntervalSet<u32>, IntervalSet<u32>)>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, |line| {
+            let range1 = parse_range(line)?;
+            line.literal(",")?;
+            let range2 = parse_range(line)?;
+            Ok((range1, range2))
+        })
+    }
+
+    fn part_b(pairs: &Self::Input) -> Option<String> {
+        let mut cnt = 0;
+        for (range1, range2) in pairs {
+            if !range1.is_disjoint(range2) {
+                cnt += 1;
+            }
+        }
+        Some(cnt.to_string())
+    }
+}
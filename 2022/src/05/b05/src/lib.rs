@@ -0,0 +1,89 @@
+use aoc::Solution;
+
+struct Move {
+    n: usize,
+    src: usize,
+    dst: usize,
+}
+
+pub struct Crates {
+    stacks: Vec<Vec<char>>,
+    moves: Vec<Move>,
+}
+
+/// Reads the drawing of the stacks, whose last line numbers them.
+fn parse_stacks(drawing: aoc_parse::Section) -> aoc_parse::Result<Vec<Vec<char>>> {
+    let mut stacks: Vec<Vec<char>> = vec![];
+    let mut lines: Vec<aoc_parse::Cursor> = drawing.lines().collect();
+    let mut labels = lines.pop().unwrap();
+    labels.skip_spaces();
+    let num_stacks = labels.ints::<usize>(" ")?.len();
+    labels.end()?;
+
+    for line in lines {
+        for (i, c) in line.rest().chars().skip(1).step_by(4).enumerate() {
+            while stacks.len() <= i {
+                stacks.push(vec![]);
+            }
+            if c.is_ascii_uppercase() {
+                stacks[i].push(c);
+            }
+        }
+    }
+    if stacks.len() > num_stacks {
+        return Err(drawing.error(format!("found crates beyond the {num_stacks} stacks")));
+    }
+    stacks.resize(num_stacks, vec![]);
+    for s in &mut stacks {
+        s.reverse();
+    }
+    Ok(stacks)
+}
+
+/// Reads a line like `move 1 from 2 to 1`.
+fn parse_move(line: &mut aoc_parse::Cursor, num_stacks: usize) -> aoc_parse::Result<Move> {
+    let stack = |line: &mut aoc_parse::Cursor| {
+        let start = *line;
+        match line.int::<usize>()? {
+            i @ 1.. if i <= num_stacks => Ok(i - 1),
+            i => Err(start.error(format!("there's no stack {i}"))),
+        }
+    };
+    line.literal("move ")?;
+    let n = line.int()?;
+    line.literal(" from ")?;
+    let src = stack(line)?;
+    line.literal(" to ")?;
+    let dst = stack(line)?;
+    Ok(Move { n, src, dst })
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Crates;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let [drawing, moves] = aoc_parse::split_sections(input)?;
+        let stacks = parse_stacks(drawing)?;
+        let moves = moves.parse_lines(|line| parse_move(line, stacks.len()))?;
+        Ok(Crates { stacks, moves })
+    }
+
+    fn part_b(crates: &Self::Input) -> Option<String> {
+        let mut stacks = crates.stacks.clone();
+        for &Move { n, src, dst } in &crates.moves {
+            let mut src_stack = std::mem::take(&mut stacks[src]);
+            let mut dst_stack = std::mem::take(&mut stacks[dst]);
+            dst_stack.extend(src_stack.drain((src_stack.len() - n)..));
+            stacks[src] = src_stack;
+            stacks[dst] = dst_stack;
+        }
+        Some(
+            stacks
+                .iter()
+                .map(|stack| stack.last().unwrap())
+                .collect::<String>(),
+        )
+    }
+}
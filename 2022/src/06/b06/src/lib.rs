@@ -0,0 +1,23 @@
+use aoc::Solution;
+
+const LEN: usize = 14;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Vec<u8>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, |line| Ok(line.word()?.as_bytes().to_vec()))
+    }
+
+    fn part_b(buffers: &Self::Input) -> Option<String> {
+        let mut answers = vec![];
+        for buf in buffers {
+            let ans = (LEN - 1..buf.len())
+                .find(|&i| (i + 1 - LEN..i).all(|a| (a + 1..=i).all(|b| buf[a] != buf[b])))?;
+            answers.push((ans + 1).to_string());
+        }
+        Some(answers.join("\n"))
+    }
+}
@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use aoc::Solution;
+
+const DISK_SPACE: usize = 70_000_000;
+const NEEDED_FREE_SPACE: usize = 30_000_000;
+
+/// A line of the terminal session, either a command or a line of `ls`
+/// output.
+pub enum Line {
+    Cd(String),
+    Ls,
+    Dir,
+    File(usize),
+}
+
+fn parse_line(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Line> {
+    if line.eat("$ ") {
+        if line.eat("cd ") {
+            Ok(Line::Cd(line.take_rest().to_owned()))
+        } else {
+            line.literal("ls")?;
+            Ok(Line::Ls)
+        }
+    } else if line.eat("dir ") {
+        line.take_rest();
+        Ok(Line::Dir)
+    } else {
+        let size = line.int()?;
+        line.literal(" ")?;
+        line.take_rest();
+        Ok(Line::File(size))
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Line>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, parse_line)
+    }
+
+    fn part_b(lines: &Self::Input) -> Option<String> {
+        let mut cwd: Vec<String> = vec![];
+        let mut total_sizes: HashMap<String, usize> = HashMap::new();
+        for line in lines {
+            match line {
+                Line::Cd(dir) if dir == "/" => {
+                    cwd = vec!["".to_string()];
+                }
+                Line::Cd(dir) if dir == ".." => {
+                    cwd.pop()?;
+                }
+                Line::Cd(dir) => {
+                    cwd.push(dir.clone());
+                }
+                Line::Ls | Line::Dir => {}
+                &Line::File(sz) => {
+                    for i in 0..cwd.len() {
+                        *total_sizes.entry(cwd[0..=i].join("/")).or_default() += sz;
+                    }
+                }
+            }
+        }
+        let free_space = DISK_SPACE - total_sizes.get("")?;
+        let need_to_free = NEEDED_FREE_SPACE - free_space;
+        let size_to_free = total_sizes
+            .values()
+            .filter(|&&sz| sz >= need_to_free)
+            .min()?;
+        Some(size_to_free.to_string())
+    }
+}
@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+
+use aoc::Solution;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Vec<u8>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_rows(input, |tree| tree.digit())
+    }
+
+    fn part_a(grid: &Self::Input) -> Option<String> {
+        let height = grid.len();
+        let width = grid[0].len();
+        let perimeter = 2 * height + 2 * width - 4;
+
+        let mut visible = HashSet::new();
+        // Looking down
+        for c in 1..width - 1 {
+            let mut highest = grid[0][c];
+            for r in 1..height - 1 {
+                if highest < grid[r][c] {
+                    visible.insert((r, c));
+                    highest = grid[r][c];
+                }
+            }
+        }
+
+        // Looking up
+        for c in 1..width - 1 {
+            let mut highest = grid[height - 1][c];
+            for r in (1..height - 1).rev() {
+                if highest < grid[r][c] {
+                    visible.insert((r, c));
+                    highest = grid[r][c];
+                }
+            }
+        }
+
+        // Looking right
+        for r in 1..height - 1 {
+            let mut highest = grid[r][0];
+            for c in 1..width - 1 {
+                if highest < grid[r][c] {
+                    visible.insert((r, c));
+                    highest = grid[r][c];
+                }
+            }
+        }
+
+        // Looking left
+        for r in 1..height - 1 {
+            let mut highest = grid[r][width - 1];
+            for c in (1..width - 1).rev() {
+                if highest < grid[r][c] {
+                    visible.insert((r, c));
+                    highest = grid[r][c];
+                }
+            }
+        }
+
+        Some((perimeter + visible.len()).to_string())
+    }
+}
@@ -0,0 +1,60 @@
+use aoc::Solution;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Vec<u8>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_rows(input, |tree| tree.digit())
+    }
+
+    fn part_b(grid: &Self::Input) -> Option<String> {
+        let height = grid.len();
+        let width = grid[0].len();
+
+        let mut best_score = 0;
+        for r in 0..height {
+            for c in 0..width {
+                let mut left = 0;
+                for c2 in (0..c).rev() {
+                    left += 1;
+                    if grid[r][c2] >= grid[r][c] {
+                        break;
+                    }
+                }
+
+                let mut right = 0;
+                for c2 in c + 1..width {
+                    right += 1;
+                    if grid[r][c2] >= grid[r][c] {
+                        break;
+                    }
+                }
+
+                let mut up = 0;
+                for r2 in (0..r).rev() {
+                    up += 1;
+                    if grid[r2][c] >= grid[r][c] {
+                        break;
+                    }
+                }
+
+                let mut down = 0;
+                for r2 in r + 1..height {
+                    down += 1;
+                    if grid[r2][c] >= grid[r][c] {
+                        break;
+                    }
+                }
+
+                let score = left * right * up * down;
+                if score > best_score {
+                    best_score = score;
+                }
+            }
+        }
+
+        Some(best_score.to_string())
+    }
+}
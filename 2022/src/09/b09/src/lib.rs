@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+
+use aoc::Solution;
+
+/// Reads a motion like `R 4`, as the step the head takes and how many
+/// times it takes it.
+fn parse_motion(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<((i32, i32), i32)> {
+    let step = line.one_of(&[("R", (1, 0)), ("L", (-1, 0)), ("U", (0, 1)), ("D", (0, -1))])?;
+    line.literal(" ")?;
+    Ok((step, line.int()?))
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<((i32, i32), i32)>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, parse_motion)
+    }
+
+    fn part_b(motions: &Self::Input) -> Option<String> {
+        let mut pos: [(i32, i32); 10] = [(0, 0); 10];
+        let mut seen = HashSet::new();
+
+        for &((dx, dy), n) in motions {
+            for _ in 0..n {
+                pos[0].0 += dx;
+                pos[0].1 += dy;
+
+                for i in 1..pos.len() {
+                    let head = pos[i - 1];
+                    let tail = &mut pos[i];
+                    if head.0 == tail.0 {
+                        // Vertical
+                        if tail.1 + 1 < head.1 {
+                            // Up
+                            tail.1 += 1;
+                        } else if head.1 + 1 < tail.1 {
+                            // Down
+                            tail.1 -= 1;
+                        }
+                    } else if head.1 == tail.1 {
+                        // Horizontal
+                        if tail.0 + 1 < head.0 {
+                            // Right
+                            tail.0 += 1;
+                        } else if head.0 + 1 < tail.0 {
+                            // Left
+                            tail.0 -= 1;
+                        }
+                    } else if (head.0 - tail.0).abs() + (head.1 - tail.1).abs() > 2 {
+                        // Diagonal
+                        assert_ne!(head.0, tail.0);
+                        assert_ne!(head.1, tail.1);
+
+                        if tail.0 < head.0 {
+                            tail.0 += 1;
+                        } else {
+                            tail.0 -= 1;
+                        }
+
+                        if tail.1 < head.1 {
+                            tail.1 += 1;
+                        } else {
+                            tail.1 -= 1
+                        }
+                    }
+                }
+                seen.insert(pos[9]);
+            }
+        }
+        Some(seen.len().to_string())
+    }
+}
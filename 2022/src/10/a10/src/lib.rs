@@ -0,0 +1,51 @@
+use aoc::Solution;
+
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+fn parse_instruction(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Instruction> {
+    if line.eat("addx ") {
+        Ok(Instruction::Addx(line.int()?))
+    } else {
+        line.literal("noop")?;
+        Ok(Instruction::Noop)
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, parse_instruction)
+    }
+
+    fn part_a(program: &Self::Input) -> Option<String> {
+        let mut x = 1;
+        let mut t = 0;
+        let mut signal_sum = 0;
+        for instruction in program {
+            let (dt, dx) = match *instruction {
+                Instruction::Noop => (1, 0),
+                Instruction::Addx(dx) => (2, dx),
+            };
+            for _ in 0..dt {
+                t += 1;
+                if t % 20 == 0 {
+                    let signal = t * x;
+                    match t {
+                        20 | 60 | 100 | 140 | 180 | 220 => {
+                            signal_sum += signal;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            x += dx;
+        }
+        Some(signal_sum.to_string())
+    }
+}
@@ -0,0 +1,91 @@
+use aoc::Solution;
+
+const W: usize = 40;
+
+struct CRT {
+    rows: Vec<String>,
+    t: usize,
+    x: i32,
+}
+
+impl CRT {
+    fn new() -> Self {
+        CRT {
+            rows: vec![String::new()],
+            t: 1,
+            x: 1,
+        }
+    }
+
+    fn noop(&mut self) {
+        self.cycle();
+    }
+
+    fn addx(&mut self, dx: i32) {
+        self.cycle();
+        self.cycle();
+        self.x += dx;
+    }
+
+    fn cycle(&mut self) {
+        let cur_row = self.rows.last_mut().unwrap();
+
+        let pos = cur_row.len();
+        cur_row.push(if self.x - 1 <= pos as i32 && pos as i32 <= self.x + 1 {
+            '#'
+        } else {
+            '.'
+        });
+        if cur_row.len() == W {
+            self.rows.push(String::new());
+        }
+
+        self.t += 1;
+    }
+
+    fn display(&self) -> String {
+        self.rows
+            .iter()
+            .filter(|row| !row.is_empty())
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+fn parse_instruction(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Instruction> {
+    if line.eat("addx ") {
+        Ok(Instruction::Addx(line.int()?))
+    } else {
+        line.literal("noop")?;
+        Ok(Instruction::Noop)
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, parse_instruction)
+    }
+
+    fn part_b(program: &Self::Input) -> Option<String> {
+        let mut crt = CRT::new();
+
+        for instruction in program {
+            match *instruction {
+                Instruction::Noop => crt.noop(),
+                Instruction::Addx(dx) => crt.addx(dx),
+            }
+        }
+
+        Some(crt.display())
+    }
+}
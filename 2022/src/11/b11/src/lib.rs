@@ -0,0 +1,137 @@
+use aoc::Solution;
+use aoc_num::lcm;
+
+#[derive(Clone, Debug)]
+enum Op {
+    Plus(usize),
+    Times(usize),
+    Square,
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    items: Vec<usize>,
+    operator: Op,
+    test_div: usize,
+    throw_true: usize,
+    throw_false: usize,
+}
+
+impl Monkey {
+    fn inspect(&mut self, modulo: usize) -> (Vec<usize>, Vec<usize>, usize) {
+        let mut true_items = vec![];
+        let mut false_items = vec![];
+        let num_inspected = self.items.len();
+        for &old in &self.items {
+            let new = self.worry(modulo, old);
+            if new % self.test_div == 0 {
+                true_items.push(new);
+            } else {
+                false_items.push(new);
+            }
+        }
+        self.items.clear();
+        (true_items, false_items, num_inspected)
+    }
+
+    fn worry(&self, modulo: usize, old: usize) -> usize {
+        let new = match self.operator {
+            Op::Plus(rhs) => old + rhs,
+            Op::Times(rhs) => old * rhs,
+            Op::Square => old * old,
+        };
+        new % modulo
+    }
+}
+
+fn parse_monkey(section: aoc_parse::Section, id: usize) -> aoc_parse::Result<Monkey> {
+    let [mut header, mut items, mut operation, mut test, mut if_true, mut if_false] =
+        section.split_lines()?;
+
+    header.literal("Monkey ")?;
+    let number = header;
+    if header.int::<usize>()? != id {
+        return Err(number.error(format!(
+            "expected monkey {id}, as monkeys should be listed in order"
+        )));
+    }
+    header.literal(":")?;
+    header.end()?;
+
+    items.field("Starting items")?;
+    let starting_items = items.ints(",")?;
+    items.end()?;
+
+    operation.field("Operation")?;
+    operation.literal("new = old ")?;
+    let op = operation.one_of(&[("*", '*'), ("+", '+')])?;
+    operation.literal(" ")?;
+    let operator = match op {
+        '*' if operation.eat("old") => Op::Square,
+        '*' => Op::Times(operation.int()?),
+        _ => Op::Plus(operation.int()?),
+    };
+    operation.end()?;
+
+    test.field("Test")?;
+    test.literal("divisible by ")?;
+    let test_div = test.int()?;
+    test.end()?;
+
+    if_true.field("If true")?;
+    if_true.literal("throw to monkey ")?;
+    let throw_true = if_true.int()?;
+    if_true.end()?;
+
+    if_false.field("If false")?;
+    if_false.literal("throw to monkey ")?;
+    let throw_false = if_false.int()?;
+    if_false.end()?;
+
+    Ok(Monkey {
+        items: starting_items,
+        operator,
+        test_div,
+        throw_true,
+        throw_false,
+    })
+}
+
+fn monkey_business(mut monkeys: Vec<Monkey>) -> usize {
+    let modulo = monkeys
+        .iter()
+        .fold(1, |modulo, m| lcm(modulo, m.test_div as i128)) as usize;
+
+    let mut inspected_totals = vec![0; monkeys.len()];
+    for _ in 1..=10_000 {
+        for i in 0..monkeys.len() {
+            let (true_items, false_items, num_inspected) = monkeys[i].inspect(modulo);
+            let throw_true = monkeys[i].throw_true;
+            let throw_false = monkeys[i].throw_false;
+            monkeys[throw_true].items.extend(true_items.into_iter());
+            monkeys[throw_false].items.extend(false_items.into_iter());
+            inspected_totals[i] += num_inspected;
+        }
+    }
+
+    inspected_totals.sort();
+    inspected_totals[inspected_totals.len() - 1] * inspected_totals[inspected_totals.len() - 2]
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::sections(input)
+            .into_iter()
+            .enumerate()
+            .map(|(id, section)| parse_monkey(section, id))
+            .collect()
+    }
+
+    fn part_b(monkeys: &Self::Input) -> Option<String> {
+        Some(monkey_business(monkeys.clone()).to_string())
+    }
+}
@@ -0,0 +1,89 @@
+use aoc::Solution;
+
+/// The heights, from `a` to `z`, with the best signal marked. The start
+/// is just another square of height `a`.
+pub struct Heightmap {
+    grid: Vec<Vec<u8>>,
+    fin: (usize, usize),
+}
+
+fn parse_heightmap(input: &str) -> aoc_parse::Result<Heightmap> {
+    let mut grid = aoc_parse::parse_lines(input, |line| Ok(line.take_rest().as_bytes().to_vec()))?;
+    let width = grid.first().map_or(0, Vec::len);
+    let (mut start, mut fin) = (None, None);
+    for (r, row) in grid.iter_mut().enumerate() {
+        if row.len() != width {
+            return Err(aoc_parse::Error::new(
+                r + 1,
+                1,
+                format!("expected a row of {width} squares, found {}", row.len()),
+            ));
+        }
+        for (c, h) in row.iter_mut().enumerate() {
+            match *h {
+                b'S' => {
+                    start = Some((r, c));
+                    *h = b'a';
+                }
+                b'E' => {
+                    fin = Some((r, c));
+                    *h = b'z';
+                }
+                b'a'..=b'z' => {}
+                _ => {
+                    return Err(aoc_parse::Error::new(
+                        r + 1,
+                        c + 1,
+                        format!("expected a height, found `{}`", *h as char),
+                    ))
+                }
+            }
+        }
+    }
+    let (Some(_), Some(fin)) = (start, fin) else {
+        return Err(aoc_parse::Error::new(1, 1, "expected an `S` and an `E`"));
+    };
+    Ok(Heightmap { grid, fin })
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Heightmap;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        parse_heightmap(input)
+    }
+
+    fn part_b(heightmap: &Self::Input) -> Option<String> {
+        let Heightmap { grid, fin } = heightmap;
+        let mut starts = vec![];
+        for r in 0..grid.len() {
+            for c in 0..grid[r].len() {
+                if grid[r][c] == b'a' {
+                    starts.push((r, c));
+                }
+            }
+        }
+
+        let successors = |&(r, c): &(usize, usize)| {
+            let h = grid[r][c];
+            let mut next = vec![];
+            if c >= 1 && grid[r][c - 1] <= h + 1 {
+                next.push((r, c - 1));
+            }
+            if c + 1 < grid[r].len() && grid[r][c + 1] <= h + 1 {
+                next.push((r, c + 1));
+            }
+            if r >= 1 && grid[r - 1][c] <= h + 1 {
+                next.push((r - 1, c));
+            }
+            if r + 1 < grid.len() && grid[r + 1][c] <= h + 1 {
+                next.push((r + 1, c));
+            }
+            next
+        };
+        let path = aoc_search::bfs(starts, successors, |pos| pos == fin)?;
+        Some((path.len() - 1).to_string())
+    }
+}
@@ -0,0 +1,95 @@
+use std::cmp::Ordering;
+
+use aoc::Solution;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum V {
+    Num(i32),
+    List(Vec<V>),
+}
+
+impl Ord for V {
+    fn cmp(&self, other: &V) -> Ordering {
+        match self {
+            V::Num(n1) => match other {
+                V::Num(n2) => n1.cmp(n2),
+                V::List(_) => V::List(vec![V::Num(*n1)]).cmp(other),
+            },
+            V::List(v1) => match other {
+                V::Num(n2) => self.cmp(&V::List(vec![V::Num(*n2)])),
+                V::List(v2) => {
+                    for i in 0..v1.len().min(v2.len()) {
+                        match v1[i].cmp(&v2[i]) {
+                            Ordering::Greater => {
+                                return Ordering::Greater;
+                            }
+                            Ordering::Less => {
+                                return Ordering::Less;
+                            }
+                            Ordering::Equal => {}
+                        }
+                    }
+                    v1.len().cmp(&v2.len())
+                }
+            },
+        }
+    }
+}
+
+impl PartialOrd for V {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Reads a packet like `[1,[2,3],[]]`.
+fn parse_value(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<V> {
+    if !line.eat("[") {
+        return Ok(V::Num(line.int()?));
+    }
+    let mut list = vec![];
+    if line.eat("]") {
+        return Ok(V::List(list));
+    }
+    loop {
+        list.push(parse_value(line)?);
+        if line.eat("]") {
+            return Ok(V::List(list));
+        }
+        line.literal(",")?;
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<(V, V)>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::sections(input)
+            .iter()
+            .map(|pair| {
+                let [mut left, mut right] = pair.split_lines()?;
+                let left_value = parse_value(&mut left)?;
+                left.end()?;
+                let right_value = parse_value(&mut right)?;
+                right.end()?;
+                Ok((left_value, right_value))
+            })
+            .collect()
+    }
+
+    fn part_a(pairs: &Self::Input) -> Option<String> {
+        let mut index_total = 0;
+        for (i, (v1, v2)) in pairs.iter().enumerate() {
+            match v1.cmp(&v2) {
+                Ordering::Equal => {}
+                Ordering::Greater => {}
+                Ordering::Less => {
+                    index_total += i + 1;
+                }
+            }
+        }
+        Some(index_total.to_string())
+    }
+}
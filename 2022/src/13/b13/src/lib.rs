@@ -0,0 +1,86 @@
+use std::cmp::Ordering;
+
+use aoc::Solution;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum V {
+    Num(i32),
+    List(Vec<V>),
+}
+
+impl Ord for V {
+    fn cmp(&self, other: &V) -> Ordering {
+        match self {
+            V::Num(n1) => match other {
+                V::Num(n2) => n1.cmp(n2),
+                V::List(_) => V::List(vec![V::Num(*n1)]).cmp(other),
+            },
+            V::List(v1) => match other {
+                V::Num(n2) => self.cmp(&V::List(vec![V::Num(*n2)])),
+                V::List(v2) => {
+                    for i in 0..v1.len().min(v2.len()) {
+                        match v1[i].cmp(&v2[i]) {
+                            Ordering::Greater => {
+                                return Ordering::Greater;
+                            }
+                            Ordering::Less => {
+                                return Ordering::Less;
+                            }
+                            Ordering::Equal => {}
+                        }
+                    }
+                    v1.len().cmp(&v2.len())
+                }
+            },
+        }
+    }
+}
+
+impl PartialOrd for V {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Reads a packet like `[1,[2,3],[]]`.
+fn parse_value(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<V> {
+    if !line.eat("[") {
+        return Ok(V::Num(line.int()?));
+    }
+    let mut list = vec![];
+    if line.eat("]") {
+        return Ok(V::List(list));
+    }
+    loop {
+        list.push(parse_value(line)?);
+        if line.eat("]") {
+            return Ok(V::List(list));
+        }
+        line.literal(",")?;
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<V>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let mut packets = vec![];
+        for section in aoc_parse::sections(input) {
+            packets.extend(section.parse_lines(parse_value)?);
+        }
+        Ok(packets)
+    }
+
+    fn part_b(packets: &Self::Input) -> Option<String> {
+        let div_2 = V::List(vec![V::List(vec![V::Num(2)])]);
+        let div_6 = V::List(vec![V::List(vec![V::Num(6)])]);
+        let mut vs = vec![div_2.clone(), div_6.clone()];
+        vs.extend(packets.iter().cloned());
+        vs.sort();
+        let loc_2 = vs.iter().position(|v| *v == div_2).unwrap() + 1;
+        let loc_6 = vs.iter().position(|v| *v == div_6).unwrap() + 1;
+        Some((loc_2 * loc_6).to_string())
+    }
+}
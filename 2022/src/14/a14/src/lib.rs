@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use aoc::Solution;
+
+#[derive(Default, Eq, PartialEq)]
+enum Cell {
+    #[default]
+    Air,
+    Rock,
+    Sand,
+}
+
+/// Reads a path of rock like `498,4 -> 498,6 -> 496,6`, where each point
+/// shares a row or column with the one before it.
+fn parse_path(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Vec<(i32, i32)>> {
+    let mut path: Vec<(i32, i32)> = vec![];
+    loop {
+        let before = *line;
+        let x = line.int()?;
+        line.literal(",")?;
+        let y = line.int()?;
+        if let Some(&(last_x, last_y)) = path.last() {
+            if x != last_x && y != last_y {
+                return Err(before.error(format!(
+                    "({x}, {y}) doesn't share a row or column with ({last_x}, {last_y})"
+                )));
+            }
+        }
+        path.push((x, y));
+        if !line.eat(" -> ") {
+            return Ok(path);
+        }
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Vec<(i32, i32)>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, parse_path)
+    }
+
+    fn part_a(paths: &Self::Input) -> Option<String> {
+        let mut grid = HashMap::new();
+        for path in paths {
+            for segment in path.windows(2) {
+                let [(last_x, last_y), (new_x, new_y)] = [segment[0], segment[1]];
+                for x in last_x.min(new_x)..=last_x.max(new_x) {
+                    for y in last_y.min(new_y)..=last_y.max(new_y) {
+                        grid.insert((x, y), Cell::Rock);
+                    }
+                }
+            }
+        }
+
+        let max_y = *grid.keys().map(|(_x, y)| y).max()?;
+
+        let mut num_sand = 0;
+        'outer: loop {
+            let mut x = 500;
+            let mut y = 0;
+            'inner: loop {
+                if y > max_y {
+                    break 'outer;
+                }
+                if *grid.get(&(x, y + 1)).unwrap_or(&Cell::Air) == Cell::Air {
+                    y += 1;
+                } else if *grid.get(&(x - 1, y + 1)).unwrap_or(&Cell::Air) == Cell::Air {
+                    x -= 1;
+                    y += 1;
+                } else if *grid.get(&(x + 1, y + 1)).unwrap_or(&Cell::Air) == Cell::Air {
+                    x += 1;
+                    y += 1;
+                } else {
+                    grid.insert((x, y), Cell::Sand);
+                    break 'inner;
+                }
+            }
+            num_sand += 1;
+        }
+        Some(num_sand.to_string())
+    }
+}
@@ -0,0 +1,84 @@
+use aoc::Solution;
+use aoc_interval::IntervalSet;
+
+fn manhattan(a: (i64, i64), b: (i64, i64)) -> i64 {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Sensor {
+    pub pos: (i64, i64),
+    pub beacon: (i64, i64),
+    /// How far the sensor can see: no beacon is closer than this one.
+    pub dist: i64,
+}
+
+/// The columns of row `y` that some sensor can see, and so that can't hold
+/// an undetected beacon.
+pub fn coverage(sensors: &[Sensor], y: i64) -> IntervalSet<i64> {
+    sensors
+        .iter()
+        .filter_map(|sensor| {
+            let reach = sensor.dist - (sensor.pos.1 - y).abs();
+            (reach >= 0).then(|| sensor.pos.0 - reach..sensor.pos.0 + reach + 1)
+        })
+        .collect()
+}
+
+/// Whether the input is the sample, which asks about smaller coordinates.
+pub fn is_sample(sensors: &[Sensor]) -> bool {
+    let max_coord = sensors
+        .iter()
+        .map(|sensor| sensor.pos.0.max(sensor.pos.1))
+        .max()
+        .unwrap();
+    max_coord <= 100
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Sensor>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, |line| {
+            line.literal("Sensor at x=")?;
+            let sensor_x = line.int()?;
+            line.literal(", y=")?;
+            let sensor_y = line.int()?;
+            line.literal(": closest beacon is at x=")?;
+            let nearest_x = line.int()?;
+            line.literal(", y=")?;
+            let nearest_y = line.int()?;
+            Ok(Sensor {
+                pos: (sensor_x, sensor_y),
+                beacon: (nearest_x, nearest_y),
+                dist: manhattan((sensor_x, sensor_y), (nearest_x, nearest_y)),
+            })
+        })
+    }
+
+    fn part_a(sensors: &Self::Input) -> Option<String> {
+        // The sample asks about row 10, the real input about row 2000000.
+        let impossible_y = if is_sample(sensors) { 10 } else { 2_000_000 };
+        let beacons: IntervalSet<i64> = sensors
+            .iter()
+            .filter(|sensor| sensor.beacon.1 == impossible_y)
+            .map(|sensor| sensor.beacon.0..sensor.beacon.0 + 1)
+            .collect();
+        let impossible = coverage(sensors, impossible_y).difference(&beacons);
+        Some(impossible.len().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coverage() {
+        let sensors = Day15::parse(include_str!("../../sample.txt"));
+        assert_eq!(coverage(&sensors, 10), IntervalSet::from(-2..25));
+        assert_eq!(coverage(&sensors, 11).ranges(), [-3..14, 15..26]);
+    }
+}
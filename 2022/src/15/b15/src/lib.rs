@@ -0,0 +1,26 @@
+use aoc::Solution;
+use aoc2022_a15::{coverage, is_sample, Sensor};
+use aoc_interval::IntervalSet;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Sensor>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc2022_a15::Day15::try_parse(input)
+    }
+
+    fn part_b(sensors: &Self::Input) -> Option<String> {
+        // The sample searches up to 20, the real input up to 4000000.
+        let max_dim = if is_sample(sensors) { 20 } else { 4_000_000 };
+        let search = IntervalSet::from(0..max_dim + 1);
+        let (x, y) = (0..=max_dim)
+            .find_map(|y| {
+                let hidden = search.difference(&coverage(sensors, y));
+                hidden.min().map(|x| (x, y))
+            })
+            .unwrap();
+        Some((x * 4_000_000 + y).to_string())
+    }
+}
@@ -0,0 +1,228 @@
+use aoc::Solution;
+use aoc_bnb::{search, Best, Problem, Strategy};
+use std::collections::BTreeMap;
+
+const MAX_TIME: i64 = 30;
+const START: &str = "AA";
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RawNode {
+    flow_rate: i64,
+    tunnels: Vec<String>,
+}
+
+/// The valves worth opening, and how many minutes it takes to get from one
+/// to another. The start comes after them.
+#[derive(Clone, Debug)]
+pub struct Valves {
+    flow_rates: Vec<i64>,
+    dist: Vec<Vec<i64>>,
+}
+
+impl Valves {
+    pub fn new(raw_nodes: &BTreeMap<String, RawNode>) -> Self {
+        let keys: Vec<&String> = raw_nodes.keys().collect();
+        let index = |key: &String| keys.binary_search(&key).unwrap();
+        let n = keys.len();
+
+        // Floyd-Warshall
+        let mut dist = vec![vec![i64::MAX / 2; n]; n];
+        for (i, raw_node) in raw_nodes.values().enumerate() {
+            dist[i][i] = 0;
+            for tunnel in &raw_node.tunnels {
+                dist[i][index(tunnel)] = 1;
+            }
+        }
+        for via in 0..n {
+            for src in 0..n {
+                for dst in 0..n {
+                    dist[src][dst] = dist[src][dst].min(dist[src][via] + dist[via][dst]);
+                }
+            }
+        }
+
+        let mut useful: Vec<usize> = (0..n)
+            .filter(|&i| raw_nodes[keys[i]].flow_rate > 0)
+            .collect();
+        let flow_rates = useful
+            .iter()
+            .map(|&i| raw_nodes[keys[i]].flow_rate)
+            .collect();
+        useful.push(index(&START.to_string()));
+        let dist = useful
+            .iter()
+            .map(|&src| useful.iter().map(|&dst| dist[src][dst]).collect())
+            .collect();
+        Self { flow_rates, dist }
+    }
+
+    fn start(&self) -> usize {
+        self.flow_rates.len()
+    }
+}
+
+/// Opening valves with a team taking turns: each has the same few minutes,
+/// starting from `AA`, to open whichever valves the ones before them left
+/// shut. That's as good as working side by side, since no two of them ever
+/// need to open the same valve.
+pub struct Plan<'a> {
+    pub valves: &'a Valves,
+    pub minutes: i64,
+    pub team: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct State {
+    pos: usize,
+    /// One bit per valve.
+    opened: u64,
+    time: i64,
+    /// Whose turn it is.
+    turn: usize,
+    /// The pressure the opened valves will release by the end.
+    released: i64,
+}
+
+impl State {
+    pub fn new(valves: &Valves) -> Self {
+        Self {
+            pos: valves.start(),
+            opened: 0,
+            time: 0,
+            turn: 0,
+            released: 0,
+        }
+    }
+}
+
+impl Plan<'_> {
+    /// The earliest minute `valve` could be open, if it's worth opening.
+    fn earliest_open(&self, state: &State, valve: usize) -> i64 {
+        let mut time = state.time + self.valves.dist[state.pos][valve] + 1;
+        if state.turn + 1 < self.team {
+            time = time.min(self.valves.dist[self.valves.start()][valve] + 1);
+        }
+        time
+    }
+}
+
+impl Problem for Plan<'_> {
+    type Node = State;
+    type Key = (usize, u64, usize);
+
+    /// Walking to each shut valve and opening it, or handing over to the
+    /// next in the team.
+    fn successors(&self, state: &State) -> Vec<State> {
+        let mut next = vec![];
+        for (valve, &flow_rate) in self.valves.flow_rates.iter().enumerate() {
+            let time = state.time + self.valves.dist[state.pos][valve] + 1;
+            if state.opened & (1 << valve) == 0 && time < self.minutes {
+                next.push(State {
+                    pos: valve,
+                    opened: state.opened | (1 << valve),
+                    time,
+                    released: state.released + flow_rate * (self.minutes - time),
+                    ..*state
+                });
+            }
+        }
+        if state.turn + 1 < self.team {
+            next.push(State {
+                pos: self.valves.start(),
+                time: 0,
+                turn: state.turn + 1,
+                ..*state
+            });
+        }
+        next
+    }
+
+    fn score(&self, state: &State) -> i64 {
+        state.released
+    }
+
+    /// Every shut valve opened as soon as anyone could get to it.
+    fn bound(&self, state: &State) -> i64 {
+        let mut bound = state.released;
+        for (valve, &flow_rate) in self.valves.flow_rates.iter().enumerate() {
+            if state.opened & (1 << valve) == 0 {
+                bound += flow_rate * (self.minutes - self.earliest_open(state, valve)).max(0);
+            }
+        }
+        bound
+    }
+
+    fn key(&self, state: &State) -> Option<Self::Key> {
+        Some((state.pos, state.opened, state.turn))
+    }
+
+    fn dominates(&self, a: &State, b: &State) -> bool {
+        a.time <= b.time && a.released >= b.released
+    }
+}
+
+/// The most pressure a team can release.
+pub fn most_pressure(valves: &Valves, minutes: i64, team: usize) -> Best<State> {
+    let plan = Plan {
+        valves,
+        minutes,
+        team,
+    };
+    search(&plan, State::new(valves), Strategy::DepthFirst)
+}
+
+/// Reads a line like `Valve AA has flow rate=0; tunnels lead to valves DD, II`.
+fn parse_valve(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<(String, RawNode)> {
+    line.literal("Valve ")?;
+    let valve = line.word()?.to_string();
+    line.literal(" has flow rate=")?;
+    let flow_rate = line.int()?;
+    line.one_of(&[
+        ("; tunnels lead to valves ", ()),
+        ("; tunnel leads to valve ", ()),
+    ])?;
+    let tunnels = line.list(",", |line| line.word().map(|t| t.to_string()))?;
+    Ok((valve, RawNode { flow_rate, tunnels }))
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = BTreeMap<String, RawNode>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        Ok(aoc_parse::parse_lines(input, parse_valve)?
+            .into_iter()
+            .collect())
+    }
+
+    fn part_a(network: &Self::Input) -> Option<String> {
+        let best = most_pressure(&Valves::new(network), MAX_TIME, 1);
+        Some(best.score.to_string())
+    }
+}
+
+/// The tunnels as an undirected graph. Valves with a nonzero flow rate, the
+/// only ones worth opening, are drawn as boxes and labelled with their rate.
+pub fn graph(input: &str) -> Result<aoc_viz::Graph, aoc::ParseError> {
+    let valves = Day16::try_parse(input)?;
+    let mut graph = aoc_viz::Graph::undirected();
+    for (key, valve) in &valves {
+        if valve.flow_rate > 0 {
+            graph
+                .node(key)
+                .label(format!("{key}\n{}", valve.flow_rate))
+                .shape(aoc_viz::Shape::Box);
+        } else {
+            graph.node(key);
+        }
+    }
+    for (key, valve) in &valves {
+        for tunnel in &valve.tunnels {
+            if key < tunnel {
+                graph.edge(key, tunnel);
+            }
+        }
+    }
+    Ok(graph)
+}
@@ -0,0 +1,20 @@
+use aoc::Solution;
+use aoc2022_a16::{most_pressure, Valves};
+
+const MAX_TIME: i64 = 26;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Valves;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        Ok(Valves::new(&aoc2022_a16::Day16::try_parse(input)?))
+    }
+
+    /// You and the elephant take turns, each opening valves for 26 minutes.
+    fn part_b(valves: &Self::Input) -> Option<String> {
+        let best = most_pressure(valves, MAX_TIME, 2);
+        Some(best.score.to_string())
+    }
+}
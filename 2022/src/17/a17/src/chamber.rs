@@ -0,0 +1,194 @@
+use std::fmt;
+
+use aoc_cycle::Simulation;
+
+const WIDTH: usize = 7;
+
+/// A row with every column blocked.
+const FULL: u8 = (1 << WIDTH) - 1;
+
+/// The rocks in the order they fall, each as one mask per row from the
+/// bottom up. Bit `x` is column `x` from the left, and each rock starts two
+/// columns in from the left wall.
+const ROCKS: [&[u8]; 5] = [
+    &[0b0111100],
+    &[0b0001000, 0b0011100, 0b0001000],
+    &[0b0011100, 0b0010000, 0b0010000],
+    &[0b0000100, 0b0000100, 0b0000100, 0b0000100],
+    &[0b0001100, 0b0001100],
+];
+
+/// How far above the tower each rock appears.
+const GAP: usize = 3;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Jet {
+    Left,
+    Right,
+}
+
+/// The rows of one rock, at most four of them.
+type Rock = [u8; 4];
+
+/// The rock pushed one column over, or `None` if a wall is in the way.
+fn push(rock: Rock, jet: Jet) -> Option<Rock> {
+    let blocked = match jet {
+        Jet::Left => rock.iter().any(|row| row & 1 != 0),
+        Jet::Right => rock.iter().any(|row| row & (1 << (WIDTH - 1)) != 0),
+    };
+    if blocked {
+        return None;
+    }
+    Some(rock.map(|row| match jet {
+        Jet::Left => row >> 1,
+        Jet::Right => row << 1,
+    }))
+}
+
+/// The chamber after some number of rocks have come to rest, one bitmask
+/// per row. Nothing can fall past a row that's blocked all the way across,
+/// so everything below the highest such row is cut off.
+#[derive(Clone)]
+pub struct Chamber<'a> {
+    jets: &'a [Jet],
+    /// The rows still kept, from the bottom up.
+    rows: Vec<u8>,
+    /// How many rows have been cut off below `rows`.
+    floor: usize,
+    num_rocks: usize,
+    num_jets: usize,
+}
+
+impl<'a> Chamber<'a> {
+    pub fn new(jets: &'a [Jet]) -> Self {
+        Self {
+            jets,
+            rows: vec![],
+            floor: 0,
+            num_rocks: 0,
+            num_jets: 0,
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.floor + self.rows.len()
+    }
+
+    /// Whether `rock` fits with its bottom row at `y`, counting from the
+    /// lowest row kept.
+    fn fits(&self, rock: &Rock, y: usize) -> bool {
+        rock.iter()
+            .enumerate()
+            .all(|(dy, &row)| self.rows.get(y + dy).is_none_or(|&kept| kept & row == 0))
+    }
+
+    /// Drops the next rock and lets it come to rest.
+    pub fn drop_rock(&mut self) {
+        let shape = ROCKS[self.num_rocks % ROCKS.len()];
+        let mut rock = [0; 4];
+        rock[..shape.len()].copy_from_slice(shape);
+        let mut y = self.rows.len() + GAP;
+        loop {
+            let jet = self.jets[self.num_jets % self.jets.len()];
+            self.num_jets += 1;
+            if let Some(pushed) = push(rock, jet) {
+                if self.fits(&pushed, y) {
+                    rock = pushed;
+                }
+            }
+            if y == 0 || !self.fits(&rock, y - 1) {
+                break;
+            }
+            y -= 1;
+        }
+
+        let top = y + shape.len();
+        if self.rows.len() < top {
+            self.rows.resize(top, 0);
+        }
+        for (dy, row) in rock[..shape.len()].iter().enumerate() {
+            self.rows[y + dy] |= row;
+        }
+        if let Some(full) = (y..top).rev().find(|&i| self.rows[i] == FULL) {
+            self.rows.drain(..=full);
+            self.floor += full + 1;
+        }
+        self.num_rocks += 1;
+    }
+
+    /// How far below the top of the tower each column is first blocked,
+    /// up to 255. Along with the next rock and jet, this is what decides
+    /// where the rocks to come will land.
+    pub fn profile(&self) -> [u8; WIDTH] {
+        std::array::from_fn(|x| {
+            let depth = self
+                .rows
+                .iter()
+                .rev()
+                .position(|row| row & (1 << x) != 0)
+                .unwrap_or(self.rows.len());
+            depth.min(u8::MAX as usize) as u8
+        })
+    }
+}
+
+impl fmt::Display for Chamber<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows.iter().rev() {
+            let cells: String = (0..WIDTH)
+                .map(|x| if row & (1 << x) != 0 { '#' } else { '.' })
+                .collect();
+            writeln!(f, "|{cells}|")?;
+        }
+        if self.floor == 0 {
+            write!(f, "+-------+")
+        } else {
+            write!(f, "~{} more~", self.floor)
+        }
+    }
+}
+
+impl Simulation for Chamber<'_> {
+    /// The next rock, the next jet, and the top of the tower.
+    type Key = (usize, usize, [u8; WIDTH]);
+
+    fn key(&self) -> Self::Key {
+        (
+            self.num_rocks % ROCKS.len(),
+            self.num_jets % self.jets.len(),
+            self.profile(),
+        )
+    }
+
+    fn metric(&self) -> i64 {
+        self.height() as i64
+    }
+
+    fn step(&mut self) {
+        self.drop_rock();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_jets;
+
+    #[test]
+    fn test_chamber() {
+        let jets = parse_jets(include_str!("../../sample.txt")).unwrap();
+        let mut chamber = Chamber::new(&jets);
+        for _ in 0..3 {
+            chamber.drop_rock();
+        }
+        assert_eq!(
+            chamber.to_string(),
+            "|..#....|\n|..#....|\n|####...|\n|..###..|\n|...#...|\n|..####.|\n+-------+"
+        );
+        assert_eq!(chamber.profile(), [2, 2, 0, 2, 3, 5, 6]);
+        for _ in 3..2022 {
+            chamber.drop_rock();
+        }
+        assert_eq!(chamber.height(), 3068);
+    }
+}
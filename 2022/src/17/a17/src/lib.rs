@@ -0,0 +1,32 @@
+use aoc::Solution;
+
+mod chamber;
+
+pub use chamber::{Chamber, Jet};
+
+pub fn parse_jets(input: &str) -> aoc_parse::Result<Vec<Jet>> {
+    let mut line = aoc_parse::single_line(input)?;
+    let mut jets = vec![];
+    while !line.is_empty() {
+        jets.push(line.one_of(&[("<", Jet::Left), (">", Jet::Right)])?);
+    }
+    Ok(jets)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<Jet>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        parse_jets(input)
+    }
+
+    fn part_a(jets: &Self::Input) -> Option<String> {
+        let mut chamber = Chamber::new(jets);
+        for _ in 0..2022 {
+            chamber.drop_rock();
+        }
+        Some(chamber.height().to_string())
+    }
+}
@@ -0,0 +1,19 @@
+use aoc::Solution;
+use aoc2022_a17::{parse_jets, Chamber, Jet};
+use aoc_cycle::extrapolate;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<Jet>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        parse_jets(input)
+    }
+
+    fn part_b(jets: &Self::Input) -> Option<String> {
+        let target_rocks = 1000000000000;
+        let tower = extrapolate(Chamber::new(jets), target_rocks, 2);
+        Some(tower.value.to_string())
+    }
+}
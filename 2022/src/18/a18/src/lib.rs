@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use aoc::Solution;
+
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+enum Orientation {
+    XY,
+    YZ,
+    XZ,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+struct Face {
+    x: i64,
+    y: i64,
+    z: i64,
+    o: Orientation,
+}
+
+impl Face {
+    fn pair(coords: (i64, i64, i64), o: Orientation) -> (Self, Self) {
+        let (x, y, z) = coords;
+        let s1 = Self { x, y, z, o };
+        let s2 = match o {
+            Orientation::YZ => Self { x: x + 1, y, z, o },
+            Orientation::XZ => Self { x, y: y + 1, z, o },
+            Orientation::XY => Self { x, y, z: z + 1, o },
+        };
+        (s1, s2)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct FaceCounter {
+    map: HashMap<Face, usize>,
+}
+
+impl FaceCounter {
+    fn add(&mut self, f: Face) {
+        let count = self.map.entry(f).or_default();
+        *count += 1;
+    }
+
+    fn add_pair(&mut self, fs: (Face, Face)) {
+        self.add(fs.0);
+        self.add(fs.1);
+    }
+}
+
+/// Reads a cube's coordinates, like `2,2,2`.
+fn parse_cube(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<(i64, i64, i64)> {
+    let x = line.int()?;
+    line.literal(",")?;
+    let y = line.int()?;
+    line.literal(",")?;
+    Ok((x, y, line.int()?))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<(i64, i64, i64)>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, parse_cube)
+    }
+
+    fn part_a(cubes: &Self::Input) -> Option<String> {
+        let mut faces = FaceCounter::default();
+        for &coords in cubes {
+            faces.add_pair(Face::pair(coords, Orientation::XY));
+            faces.add_pair(Face::pair(coords, Orientation::YZ));
+            faces.add_pair(Face::pair(coords, Orientation::XZ));
+        }
+        Some(
+            faces
+                .map
+                .values()
+                .filter(|&&count| count == 1)
+                .count()
+                .to_string(),
+        )
+    }
+}
@@ -0,0 +1,374 @@
+use aoc::Solution;
+use aoc_bnb::{search, Best, Problem, Strategy};
+
+const MAX_TIME: usize = 24;
+
+#[derive(Copy, Clone, Default, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Goods {
+    ore: usize,
+    clay: usize,
+    obsidian: usize,
+    geode: usize,
+}
+
+impl Goods {
+    fn contains(&self, other: &Goods) -> bool {
+        self.ore >= other.ore
+            && self.clay >= other.clay
+            && self.obsidian >= other.obsidian
+            && self.geode >= other.geode
+    }
+
+    fn add(&self, other: &Goods) -> Self {
+        let ore = self.ore + other.ore;
+        let clay = self.clay + other.clay;
+        let obsidian = self.obsidian + other.obsidian;
+        let geode = self.geode + other.geode;
+        Self {
+            ore,
+            clay,
+            obsidian,
+            geode,
+        }
+    }
+
+    fn subtract(&self, other: &Goods) -> Self {
+        let ore = self.ore - other.ore;
+        let clay = self.clay - other.clay;
+        let obsidian = self.obsidian - other.obsidian;
+        let geode = self.geode - other.geode;
+        Self {
+            ore,
+            clay,
+            obsidian,
+            geode,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Default, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Blueprint {
+    id: usize,
+    ore: Goods,
+    clay: Goods,
+    obsidian: Goods,
+    geode: Goods,
+}
+
+#[derive(Copy, Clone, Default, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct State {
+    time: usize,
+    resources: Goods,
+    bots: Goods,
+}
+
+impl State {
+    pub fn init() -> Self {
+        State {
+            time: 0,
+            resources: Default::default(),
+            bots: Goods {
+                ore: 1,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Waits until the factory can afford `cost`, then spends a minute
+    /// building `bot` with it. `None` if that can't happen before `minutes`
+    /// are up, so the bot would never get to collect anything.
+    fn build(&self, cost: &Goods, bot: &Goods, minutes: usize) -> Option<Self> {
+        let wait_for = |have: usize, need: usize, rate: usize| {
+            if have >= need {
+                Some(0)
+            } else if rate == 0 {
+                None
+            } else {
+                Some((need - have).div_ceil(rate))
+            }
+        };
+        let wait = wait_for(self.resources.ore, cost.ore, self.bots.ore)?
+            .max(wait_for(self.resources.clay, cost.clay, self.bots.clay)?)
+            .max(wait_for(
+                self.resources.obsidian,
+                cost.obsidian,
+                self.bots.obsidian,
+            )?);
+        let time = self.time + wait + 1;
+        if time >= minutes {
+            return None;
+        }
+        let mut resources = self.resources;
+        for _ in 0..=wait {
+            resources = resources.add(&self.bots);
+        }
+        Some(Self {
+            time,
+            resources: resources.subtract(cost),
+            bots: self.bots.add(bot),
+        })
+    }
+
+    /// The states where the factory has just built each kind of bot it can
+    /// build next, skipping the minutes spent saving up for it. Bots of a
+    /// kind are never built past the most of their resource any one recipe
+    /// needs, since only one bot can be built a minute and more couldn't be
+    /// spent.
+    fn next(&self, bp: &Blueprint, minutes: usize) -> Vec<Self> {
+        let mut next_states = vec![];
+        macro_rules! buy_bot {
+            ($resource_name:ident, $cap:expr) => {
+                if self.bots.$resource_name < $cap {
+                    let bot = Goods {
+                        $resource_name: 1,
+                        ..Default::default()
+                    };
+                    next_states.extend(self.build(&bp.$resource_name, &bot, minutes));
+                }
+            };
+        }
+        buy_bot!(ore, bp.max_ore());
+        buy_bot!(clay, bp.obsidian.clay);
+        buy_bot!(obsidian, bp.geode.obsidian);
+        buy_bot!(geode, usize::MAX);
+        next_states
+    }
+}
+
+impl Blueprint {
+    /// The most ore any one bot costs.
+    fn max_ore(&self) -> usize {
+        [self.ore, self.clay, self.obsidian, self.geode]
+            .iter()
+            .map(|cost| cost.ore)
+            .max()
+            .unwrap()
+    }
+}
+
+/// Running a factory to the blueprint for a number of minutes.
+pub struct Factory<'a> {
+    pub blueprint: &'a Blueprint,
+    pub minutes: usize,
+}
+
+impl Problem for Factory<'_> {
+    type Node = State;
+    type Key = (usize, Goods);
+
+    fn successors(&self, state: &State) -> Vec<State> {
+        state.next(self.blueprint, self.minutes)
+    }
+
+    /// The geodes there will be if the factory builds nothing more.
+    fn score(&self, state: &State) -> i64 {
+        (state.resources.geode + state.bots.geode * (self.minutes - state.time)) as i64
+    }
+
+    /// The geodes there would be if ore were free and the factory could
+    /// build one bot of each kind every minute. Then a clay bot gets built
+    /// every minute, and obsidian and geode bots whenever there's the clay
+    /// and obsidian for them, so obsidian still limits the geodes.
+    fn bound(&self, state: &State) -> i64 {
+        let bp = self.blueprint;
+        let mut resources = state.resources;
+        let mut bots = state.bots;
+        for _ in state.time..self.minutes {
+            let build_obsidian = resources.clay >= bp.obsidian.clay;
+            let build_geode = resources.obsidian >= bp.geode.obsidian;
+            if build_obsidian {
+                resources.clay -= bp.obsidian.clay;
+            }
+            if build_geode {
+                resources.obsidian -= bp.geode.obsidian;
+            }
+            resources = resources.add(&bots);
+            bots.clay += 1;
+            bots.obsidian += build_obsidian as usize;
+            bots.geode += build_geode as usize;
+        }
+        resources.geode as i64
+    }
+
+    fn key(&self, state: &State) -> Option<Self::Key> {
+        Some((state.time, state.bots))
+    }
+
+    /// At the same minute with the same bots, more of everything is better.
+    fn dominates(&self, a: &State, b: &State) -> bool {
+        a.resources.contains(&b.resources)
+    }
+}
+
+/// The most geodes the blueprint can crack in the time.
+pub fn max_geodes(blueprint: &Blueprint, minutes: usize) -> Best<State> {
+    let factory = Factory { blueprint, minutes };
+    search(&factory, State::init(), Strategy::BestFirst)
+}
+
+/// The most geodes each blueprint can crack in the time. With
+/// [`aoc::verbose`] on, also reports how much searching each one took.
+pub fn max_geodes_each(blueprints: &[Blueprint], minutes: usize) -> Vec<usize> {
+    let bests = aoc::par_map(blueprints, |bp| max_geodes(bp, minutes));
+    if aoc::verbose() {
+        for (bp, best) in blueprints.iter().zip(&bests) {
+            eprintln!("Blueprint {}: {} geodes, {}", bp.id, best.score, best.stats);
+        }
+    }
+    bests.into_iter().map(|best| best.score as usize).collect()
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, |line| {
+            line.literal("Blueprint ")?;
+            let id = line.int()?;
+            line.literal(": Each ore robot costs ")?;
+            let ore = line.int()?;
+            line.literal(" ore. Each clay robot costs ")?;
+            let clay = line.int()?;
+            line.literal(" ore. Each obsidian robot costs ")?;
+            let obsidian_ore = line.int()?;
+            line.literal(" ore and ")?;
+            let obsidian_clay = line.int()?;
+            line.literal(" clay. Each geode robot costs ")?;
+            let geode_ore = line.int()?;
+            line.literal(" ore and ")?;
+            let geode_obsidian = line.int()?;
+            line.literal(" obsidian.")?;
+            Ok(Blueprint {
+                id,
+                ore: Goods {
+                    ore,
+                    ..Default::default()
+                },
+                clay: Goods {
+                    ore: clay,
+                    ..Default::default()
+                },
+                obsidian: Goods {
+                    ore: obsidian_ore,
+                    clay: obsidian_clay,
+                    ..Default::default()
+                },
+                geode: Goods {
+                    ore: geode_ore,
+                    obsidian: geode_obsidian,
+                    ..Default::default()
+                },
+            })
+        })
+    }
+
+    fn part_a(blueprints: &Self::Input) -> Option<String> {
+        let geodes = max_geodes_each(blueprints, MAX_TIME);
+        let total_quality: usize = blueprints
+            .iter()
+            .zip(geodes)
+            .map(|(bp, geodes)| bp.id * geodes)
+            .sum();
+        Some(total_quality.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The old search: a minute at a time, buying at most one bot a minute
+    /// with no caps, bounded by a free geode bot every minute.
+    struct MinuteByMinute<'a>(Factory<'a>);
+
+    impl Problem for MinuteByMinute<'_> {
+        type Node = State;
+        type Key = (usize, Goods);
+
+        fn successors(&self, state: &State) -> Vec<State> {
+            if state.time == self.0.minutes {
+                return vec![];
+            }
+            let bp = self.0.blueprint;
+            let wait = State {
+                time: state.time + 1,
+                resources: state.resources.add(&state.bots),
+                bots: state.bots,
+            };
+            let mut next_states = vec![wait];
+            let one = |ore, clay, obsidian, geode| Goods {
+                ore,
+                clay,
+                obsidian,
+                geode,
+            };
+            for (cost, bot) in [
+                (bp.ore, one(1, 0, 0, 0)),
+                (bp.clay, one(0, 1, 0, 0)),
+                (bp.obsidian, one(0, 0, 1, 0)),
+                (bp.geode, one(0, 0, 0, 1)),
+            ] {
+                if state.resources.contains(&cost) {
+                    next_states.push(State {
+                        resources: wait.resources.subtract(&cost),
+                        bots: state.bots.add(&bot),
+                        ..wait
+                    });
+                }
+            }
+            next_states
+        }
+
+        fn score(&self, state: &State) -> i64 {
+            self.0.score(state)
+        }
+
+        fn bound(&self, state: &State) -> i64 {
+            let remaining = self.0.minutes - state.time;
+            self.0.score(state) + (remaining * remaining.saturating_sub(1) / 2) as i64
+        }
+
+        fn key(&self, state: &State) -> Option<Self::Key> {
+            self.0.key(state)
+        }
+
+        fn dominates(&self, a: &State, b: &State) -> bool {
+            self.0.dominates(a, b)
+        }
+    }
+
+    #[test]
+    fn test_sample() {
+        let blueprints = Day19::parse(include_str!("../../sample.txt"));
+        let geodes: Vec<i64> = blueprints
+            .iter()
+            .map(|bp| max_geodes(bp, MAX_TIME).score)
+            .collect();
+        assert_eq!(geodes, [9, 12]);
+        let geodes: Vec<i64> = blueprints
+            .iter()
+            .map(|bp| max_geodes(bp, 32).score)
+            .collect();
+        assert_eq!(geodes, [56, 62]);
+    }
+
+    /// Run with `--nocapture` to see how many nodes each search takes.
+    #[test]
+    fn test_node_counts() {
+        let blueprints = Day19::parse(include_str!("../../sample.txt"));
+        for bp in &blueprints {
+            let factory = Factory {
+                blueprint: bp,
+                minutes: MAX_TIME,
+            };
+            let skipping = search(&factory, State::init(), Strategy::BestFirst);
+            let stepping = search(&MinuteByMinute(factory), State::init(), Strategy::BestFirst);
+            eprintln!("Blueprint {}, time-skipping: {}", bp.id, skipping.stats);
+            eprintln!("Blueprint {}, minute by minute: {}", bp.id, stepping.stats);
+            assert_eq!(skipping.score, stepping.score);
+            assert!(skipping.stats.expanded * 10 < stepping.stats.expanded);
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use aoc::Solution;
+use aoc2022_a19::{max_geodes_each, Blueprint};
+
+const MAX_TIME: usize = 32;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc2022_a19::Day19::try_parse(input)
+    }
+
+    fn part_b(blueprints: &Self::Input) -> Option<String> {
+        let first = &blueprints[..blueprints.len().min(3)];
+        let geodes = max_geodes_each(first, MAX_TIME);
+        Some(geodes.into_iter().product::<usize>().to_string())
+    }
+}
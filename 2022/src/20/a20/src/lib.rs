@@ -0,0 +1,136 @@
+use aoc::Solution;
+use aoc_ring::Ring;
+
+/// Mixes the file `rounds` times. Each number, in the order they were
+/// first given, moves as many places along as its value.
+pub fn mix(nums: &[i64], rounds: usize) -> Ring<i64> {
+    let mut file: Ring<i64> = nums.iter().copied().collect();
+    for _ in 0..rounds {
+        for (handle, &val) in nums.iter().enumerate() {
+            file.move_by(handle, val);
+        }
+    }
+    file
+}
+
+/// The sum of the numbers 1000, 2000 and 3000 places after the 0.
+pub fn grove_coordinates(file: &Ring<i64>) -> i64 {
+    let zero = file.handles().into_iter().find(|&h| *file.get(h) == 0);
+    let zero = file.position(zero.expect("The file has no 0"));
+    [1000, 2000, 3000]
+        .into_iter()
+        .map(|shift| file.at(zero + shift))
+        .sum()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<i64>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, |line| line.int())
+    }
+
+    fn part_a(nums: &Self::Input) -> Option<String> {
+        Some(grove_coordinates(&mix(nums, 1)).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The file as a doubly linked list, walking every link a number moves
+    /// past.
+    struct File {
+        nums: Vec<i64>,
+        prev: Vec<usize>,
+        next: Vec<usize>,
+    }
+
+    impl File {
+        fn new(nums: Vec<i64>) -> Self {
+            let prev = (0..nums.len())
+                .map(|n| (n + nums.len() - 1) % nums.len())
+                .collect();
+            let next = (0..nums.len()).map(|n| (n + 1) % nums.len()).collect();
+            Self { nums, prev, next }
+        }
+
+        fn mix(&mut self) {
+            self.integrity_check();
+            for (src, val) in self.nums.iter().cloned().enumerate() {
+                // Remove source node
+                self.next[self.prev[src]] = self.next[src];
+                self.prev[self.next[src]] = self.prev[src];
+
+                // Find destination node. We will add after this node, so
+                // start from the one before, where a move of 0 would put it.
+                let steps = val.rem_euclid(self.nums.len() as i64 - 1);
+                let mut dst = self.prev[src];
+                for _ in 0..steps {
+                    dst = self.next[dst];
+                }
+                // Add source node after destination node
+                self.next[src] = self.next[dst];
+                self.prev[self.next[dst]] = src;
+                self.next[dst] = src;
+                self.prev[src] = dst;
+            }
+            self.integrity_check();
+        }
+
+        fn integrity_check(&self) {
+            for i in 0..self.nums.len() {
+                assert_eq!(self.prev[self.next[i]], i);
+                assert_eq!(self.next[self.prev[i]], i);
+            }
+        }
+
+        /// The numbers in order, starting from the first one given.
+        fn out(&self) -> Vec<i64> {
+            let mut p = 0;
+            let mut ret = vec![];
+            for _ in 0..self.nums.len() {
+                ret.push(self.nums[p]);
+                p = self.next[p];
+            }
+            assert_eq!(p, 0);
+            ret
+        }
+    }
+
+    /// The ring in order, starting from the first number given.
+    fn out(file: &Ring<i64>) -> Vec<i64> {
+        let start = file.position(0);
+        (0..file.len()).map(|i| *file.at(start + i)).collect()
+    }
+
+    #[test]
+    fn test_sample() {
+        let nums = Day20::parse(include_str!("../../sample.txt"));
+        let file = mix(&nums, 1);
+        assert_eq!(out(&file), [1, 2, -3, 4, 0, 3, -2]);
+        assert_eq!(grove_coordinates(&file), 3);
+    }
+
+    #[test]
+    fn test_against_linked_list() {
+        let mut seed = 2022u64;
+        let mut random = || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+        for len in 2..40 {
+            let nums: Vec<i64> = (0..len).map(|_| (random() % 2001) as i64 - 1000).collect();
+            let mut list = File::new(nums.clone());
+            for rounds in 1..=3 {
+                list.mix();
+                assert_eq!(out(&mix(&nums, rounds)), list.out(), "{nums:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use aoc::Solution;
+use aoc2022_a20::{grove_coordinates, mix};
+
+const KEY: i64 = 811589153;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<i64>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, |line| line.int())
+    }
+
+    fn part_b(nums: &Self::Input) -> Option<String> {
+        let nums: Vec<i64> = nums.iter().map(|n| n * KEY).collect();
+        Some(grove_coordinates(&mix(&nums, 10)).to_string())
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use aoc::Solution;
+use aoc_dag::Evaluator;
+
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub enum Op {
+    Plus,
+    Minus,
+    Div,
+    Mul,
+}
+
+#[derive(Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub enum Yell {
+    Literal(i64),
+    Expr(Op, String, String),
+}
+
+/// Reads the monkeys, like `root: pppw + sjmn` or `dbpl: 5`, by name.
+fn parse_monkeys(input: &str) -> aoc_parse::Result<HashMap<String, Yell>> {
+    let mut monkeys = HashMap::new();
+    for mut line in aoc_parse::lines(input) {
+        let before = line;
+        let name = line.word()?;
+        line.literal(": ")?;
+        let yell = if line.rest().starts_with(|c: char| c.is_ascii_alphabetic()) {
+            let m1 = line.word()?;
+            let op = line.one_of(&[
+                (" + ", Op::Plus),
+                (" - ", Op::Minus),
+                (" / ", Op::Div),
+                (" * ", Op::Mul),
+            ])?;
+            let m2 = line.word()?;
+            Yell::Expr(op, m1.to_owned(), m2.to_owned())
+        } else {
+            Yell::Literal(line.int()?)
+        };
+        line.end()?;
+        if monkeys.insert(name.to_owned(), yell).is_some() {
+            return Err(before.error(format!("{name} yells twice")));
+        }
+    }
+    Ok(monkeys)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = HashMap<String, Yell>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        parse_monkeys(input)
+    }
+
+    fn part_a(monkeys: &Self::Input) -> Option<String> {
+        let mut gates = HashMap::new();
+        let mut values = HashMap::new();
+        for (name, yell) in monkeys {
+            match yell {
+                &Yell::Literal(n) => {
+                    values.insert(name.as_str(), n);
+                }
+                Yell::Expr(op, m1, m2) => {
+                    gates.insert(name.as_str(), (*op, vec![m1.as_str(), m2.as_str()]));
+                }
+            }
+        }
+        let root = Evaluator::new(&gates, values)
+            .eval(&"root", |op, args| {
+                let (v1, v2) = (args[0], args[1]);
+                match op {
+                    Op::Plus => v1 + v2,
+                    Op::Minus => v1 - v2,
+                    Op::Mul => v1 * v2,
+                    Op::Div => v1 / v2,
+                }
+            })
+            .unwrap_or_else(|err| panic!("Can't work out what root yells: {err}"));
+        Some(root.to_string())
+    }
+}
@@ -0,0 +1,248 @@
+use std::{collections::HashMap, fmt};
+
+use aoc::Solution;
+
+mod symbolic;
+
+use symbolic::{Linear, Ratio};
+
+const ROOT: &str = "root";
+const HUMN: &str = "humn";
+
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub enum Op {
+    Plus,
+    Minus,
+    Div,
+    Mul,
+}
+
+impl Op {
+    fn symbol(self) -> &'static str {
+        match self {
+            Op::Plus => "+",
+            Op::Minus => "-",
+            Op::Div => "/",
+            Op::Mul => "*",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub enum Yell {
+    Literal(i64),
+    Expr(Op, String, String),
+}
+
+/// Reads the monkeys, like `root: pppw + sjmn` or `dbpl: 5`, by name.
+fn parse_monkeys(input: &str) -> aoc_parse::Result<HashMap<String, Yell>> {
+    let mut monkeys = HashMap::new();
+    for mut line in aoc_parse::lines(input) {
+        let before = line;
+        let name = line.word()?;
+        line.literal(": ")?;
+        let yell = if line.rest().starts_with(|c: char| c.is_ascii_alphabetic()) {
+            let m1 = line.word()?;
+            let op = line.one_of(&[
+                (" + ", Op::Plus),
+                (" - ", Op::Minus),
+                (" / ", Op::Div),
+                (" * ", Op::Mul),
+            ])?;
+            let m2 = line.word()?;
+            Yell::Expr(op, m1.to_owned(), m2.to_owned())
+        } else {
+            Yell::Literal(line.int()?)
+        };
+        line.end()?;
+        if monkeys.insert(name.to_owned(), yell).is_some() {
+            return Err(before.error(format!("{name} yells twice")));
+        }
+    }
+    Ok(monkeys)
+}
+
+/// Why `root`'s two sides can't be made equal with a whole number.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Unsolvable {
+    Missing(String),
+    LiteralRoot,
+    /// This monkey multiplies or divides by something that depends on `humn`.
+    NonLinear(String),
+    DivisionByZero(String),
+    /// The exact value of this monkey's number doesn't fit in an `i128`.
+    Overflow(String),
+    NoSolution,
+    AnyValue,
+    Fraction(Ratio),
+}
+
+impl fmt::Display for Unsolvable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing(name) => write!(f, "nobody is called {name}"),
+            Self::LiteralRoot => write!(f, "{ROOT} yells a number, not an equation"),
+            Self::NonLinear(name) => {
+                write!(f, "{name} isn't linear in {HUMN}")
+            }
+            Self::DivisionByZero(name) => write!(f, "{name} divides by zero"),
+            Self::Overflow(name) => write!(f, "{name} overflows an i128"),
+            Self::NoSolution => write!(f, "no value of {HUMN} balances {ROOT}"),
+            Self::AnyValue => write!(f, "every value of {HUMN} balances {ROOT}"),
+            Self::Fraction(humn) => write!(f, "only {HUMN} = {humn} balances {ROOT}"),
+        }
+    }
+}
+
+/// What `name` yells, in terms of what `humn` yells.
+fn linear(monkeys: &HashMap<String, Yell>, name: &str) -> Result<Linear, Unsolvable> {
+    if name == HUMN {
+        return Ok(Linear::unknown());
+    }
+    let yell = monkeys
+        .get(name)
+        .ok_or_else(|| Unsolvable::Missing(name.to_owned()))?;
+    match yell {
+        &Yell::Literal(n) => Ok(Linear::constant(n as i128)),
+        Yell::Expr(op, m1, m2) => {
+            let (v1, v2) = (linear(monkeys, m1)?, linear(monkeys, m2)?);
+            let result = match *op {
+                Op::Plus => v1.add(v2),
+                Op::Minus => v1.sub(v2),
+                Op::Mul => v1.mul(v2),
+                Op::Div => v1.div(v2),
+            };
+            result.map_err(|err| unsolvable(err, name))
+        }
+    }
+}
+
+fn unsolvable(err: symbolic::Error, name: &str) -> Unsolvable {
+    let name = name.to_owned();
+    match err {
+        symbolic::Error::NonLinear => Unsolvable::NonLinear(name),
+        symbolic::Error::DivisionByZero => Unsolvable::DivisionByZero(name),
+        symbolic::Error::Overflow => Unsolvable::Overflow(name),
+    }
+}
+
+/// The number `humn` has to yell for both sides of `root` to be equal.
+fn solve(monkeys: &HashMap<String, Yell>) -> Result<i128, Unsolvable> {
+    let root = monkeys
+        .get(ROOT)
+        .ok_or_else(|| Unsolvable::Missing(ROOT.to_owned()))?;
+    let Yell::Expr(_, m1, m2) = root else {
+        return Err(Unsolvable::LiteralRoot);
+    };
+    // Both sides are equal where a·humn + b = 0.
+    let Linear { a, b } = linear(monkeys, m1)?
+        .sub(linear(monkeys, m2)?)
+        .map_err(|err| unsolvable(err, ROOT))?;
+    if a.is_zero() {
+        return Err(if b.is_zero() {
+            Unsolvable::AnyValue
+        } else {
+            Unsolvable::NoSolution
+        });
+    }
+    let humn = b
+        .checked_neg()
+        .and_then(|b| b.checked_div(a))
+        .ok_or_else(|| Unsolvable::Overflow(ROOT.to_owned()))?;
+    humn.to_integer().ok_or(Unsolvable::Fraction(humn))
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = HashMap<String, Yell>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        parse_monkeys(input)
+    }
+
+    fn part_b(monkeys: &Self::Input) -> Option<String> {
+        let humn = solve(monkeys).unwrap_or_else(|err| panic!("Can't solve for {HUMN}: {err}"));
+        Some(humn.to_string())
+    }
+}
+
+/// The monkeys that lead from `name` down to `humn`, if `humn` is below it.
+fn path_to_humn<'a>(monkeys: &'a HashMap<String, Yell>, name: &'a str) -> Option<Vec<&'a str>> {
+    if name == HUMN {
+        return Some(vec![name]);
+    }
+    let Yell::Expr(_, m1, m2) = monkeys.get(name)? else {
+        return None;
+    };
+    let mut path = path_to_humn(monkeys, m1).or_else(|| path_to_humn(monkeys, m2))?;
+    path.insert(0, name);
+    Some(path)
+}
+
+/// The monkeys' expression tree, each monkey pointing at the two it waits
+/// on. The chain from `root` down to `humn`, which part B has to invert, is
+/// highlighted.
+pub fn graph(input: &str) -> Result<aoc_viz::Graph, aoc::ParseError> {
+    let monkeys = parse_monkeys(input)?;
+    let mut names: Vec<&str> = monkeys.keys().map(String::as_str).collect();
+    names.sort();
+
+    let mut graph = aoc_viz::Graph::directed();
+    for &name in &names {
+        let label = match monkeys[name] {
+            _ if name == HUMN => name.to_owned(),
+            Yell::Literal(n) => format!("{name}\n{n}"),
+            Yell::Expr(op, _, _) => format!("{name}\n{}", op.symbol()),
+        };
+        graph.node(name).label(label);
+    }
+    graph.node(HUMN).shape(aoc_viz::Shape::Box);
+    for &name in &names {
+        if let Yell::Expr(_, m1, m2) = &monkeys[name] {
+            graph.edge(name, m1);
+            graph.edge(name, m2);
+        }
+    }
+    if let Some(path) = path_to_humn(&monkeys, ROOT) {
+        graph.highlight_path(&path);
+    }
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solve_lines(input: &str) -> Result<i128, Unsolvable> {
+        solve(&Day21::parse(input))
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(
+            solve_lines("root: aaaa + bbbb\naaaa: humn / cccc\ncccc: 4\nbbbb: 3\nhumn: 1"),
+            Ok(12)
+        );
+        assert_eq!(
+            solve_lines("root: aaaa + bbbb\naaaa: humn * cccc\ncccc: 2\nbbbb: 3\nhumn: 1")
+                .map_err(|err| err.to_string()),
+            Err("only humn = 3/2 balances root".to_owned())
+        );
+        assert_eq!(
+            solve_lines("root: humn + bbbb\nbbbb: humn * cccc\ncccc: 1\nhumn: 1"),
+            Err(Unsolvable::AnyValue)
+        );
+        assert_eq!(
+            solve_lines("root: humn + bbbb\nbbbb: humn * humn\nhumn: 1"),
+            Err(Unsolvable::NonLinear("bbbb".to_owned()))
+        );
+        assert_eq!(
+            solve_lines(
+                "root: humn + bbbb\nbbbb: cccc * cccc\ncccc: dddd * dddd\n\
+                 dddd: eeee * eeee\neeee: 1000000000000\nhumn: 1"
+            ),
+            Err(Unsolvable::Overflow("cccc".to_owned()))
+        );
+    }
+}
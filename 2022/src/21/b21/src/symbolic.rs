@@ -0,0 +1,226 @@
+use std::fmt;
+
+use aoc_num::gcd;
+
+/// Why an operation on [`Linear`]s has no result.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// Both sides depend on `x`, so the result would be `x²` or `1/x`.
+    NonLinear,
+    DivisionByZero,
+    /// A numerator or denominator doesn't fit in an `i128`.
+    Overflow,
+}
+
+/// An exact fraction, kept in lowest terms with a positive denominator.
+///
+/// Arithmetic cancels common factors before multiplying, and returns `None`
+/// if the result still doesn't fit rather than wrapping around.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Ratio {
+    num: i128,
+    den: i128,
+}
+
+impl Ratio {
+    /// Panics if `den` is zero, or if the fraction doesn't fit in lowest
+    /// terms with a positive denominator.
+    #[cfg(test)]
+    pub fn new(num: i128, den: i128) -> Self {
+        Self::checked_new(num, den).unwrap_or_else(|| panic!("{num}/{den} isn't a fraction"))
+    }
+
+    fn checked_new(num: i128, den: i128) -> Option<Self> {
+        if den == 0 {
+            return None;
+        }
+        let divisor = gcd(num, den).checked_mul(den.signum())?;
+        Some(Self {
+            num: num.checked_div(divisor)?,
+            den: den.checked_div(divisor)?,
+        })
+    }
+
+    pub fn integer(n: i128) -> Self {
+        Self { num: n, den: 1 }
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.num == 0
+    }
+
+    /// The fraction as a whole number, if it is one.
+    pub fn to_integer(self) -> Option<i128> {
+        (self.den == 1).then_some(self.num)
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+            num: self.num.checked_neg()?,
+            den: self.den,
+        })
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let g = gcd(self.den, rhs.den);
+        let num = self
+            .num
+            .checked_mul(rhs.den / g)?
+            .checked_add(rhs.num.checked_mul(self.den / g)?)?;
+        Self::checked_new(num, (self.den / g).checked_mul(rhs.den)?)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs.checked_neg()?)
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // Cancelling across first keeps the result in lowest terms.
+        let g1 = gcd(self.num, rhs.den).max(1);
+        let g2 = gcd(rhs.num, self.den).max(1);
+        Some(Self {
+            num: (self.num / g1).checked_mul(rhs.num / g2)?,
+            den: (self.den / g2).checked_mul(rhs.den / g1)?,
+        })
+    }
+
+    /// `None` if `rhs` is zero, as well as on overflow.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(Self::checked_new(rhs.den, rhs.num)?)
+    }
+}
+
+impl fmt::Display for Ratio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+/// `a·x + b`, for a single unknown `x`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Linear {
+    pub a: Ratio,
+    pub b: Ratio,
+}
+
+impl Linear {
+    pub fn constant(n: i128) -> Self {
+        Self {
+            a: Ratio::integer(0),
+            b: Ratio::integer(n),
+        }
+    }
+
+    /// `x` itself.
+    pub fn unknown() -> Self {
+        Self {
+            a: Ratio::integer(1),
+            b: Ratio::integer(0),
+        }
+    }
+
+    /// The value, if it doesn't depend on `x`.
+    pub fn as_constant(self) -> Option<Ratio> {
+        self.a.is_zero().then_some(self.b)
+    }
+
+    fn scale(self, k: Ratio) -> Result<Self, Error> {
+        Ok(Self {
+            a: self.a.checked_mul(k).ok_or(Error::Overflow)?,
+            b: self.b.checked_mul(k).ok_or(Error::Overflow)?,
+        })
+    }
+
+    pub fn add(self, rhs: Self) -> Result<Self, Error> {
+        Ok(Self {
+            a: self.a.checked_add(rhs.a).ok_or(Error::Overflow)?,
+            b: self.b.checked_add(rhs.b).ok_or(Error::Overflow)?,
+        })
+    }
+
+    pub fn sub(self, rhs: Self) -> Result<Self, Error> {
+        Ok(Self {
+            a: self.a.checked_sub(rhs.a).ok_or(Error::Overflow)?,
+            b: self.b.checked_sub(rhs.b).ok_or(Error::Overflow)?,
+        })
+    }
+
+    /// The product, unless both sides depend on `x`.
+    pub fn mul(self, rhs: Self) -> Result<Self, Error> {
+        match (self.as_constant(), rhs.as_constant()) {
+            (_, Some(k)) => self.scale(k),
+            (Some(k), _) => rhs.scale(k),
+            (None, None) => Err(Error::NonLinear),
+        }
+    }
+
+    /// The quotient, unless `rhs` depends on `x` or is zero.
+    pub fn div(self, rhs: Self) -> Result<Self, Error> {
+        let k = rhs.as_constant().ok_or(Error::NonLinear)?;
+        if k.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+        self.scale(Ratio::integer(1).checked_div(k).ok_or(Error::Overflow)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ratio() {
+        assert_eq!(Ratio::new(6, -4), Ratio::new(-3, 2));
+        assert_eq!(
+            Ratio::new(1, 3).checked_add(Ratio::new(1, 6)),
+            Some(Ratio::new(1, 2))
+        );
+        assert_eq!(
+            Ratio::new(3, 4).checked_div(Ratio::new(3, 8)),
+            Some(Ratio::integer(2))
+        );
+        assert_eq!(Ratio::new(3, 4).checked_div(Ratio::integer(0)), None);
+        assert_eq!(Ratio::new(4, 2).to_integer(), Some(2));
+        assert_eq!(Ratio::new(1, 2).to_integer(), None);
+        assert_eq!(Ratio::new(-1, 2).to_string(), "-1/2");
+
+        // Cancelling first keeps these in range, though the plain products
+        // wouldn't be.
+        let big = i128::MAX / 3;
+        assert_eq!(
+            Ratio::new(big, 7).checked_mul(Ratio::new(7, big)),
+            Some(Ratio::integer(1))
+        );
+        assert_eq!(
+            Ratio::new(1, big).checked_add(Ratio::new(1, big)),
+            Some(Ratio::new(2, big))
+        );
+        assert_eq!(Ratio::integer(big).checked_mul(Ratio::integer(4)), None);
+        assert_eq!(
+            Ratio::integer(i128::MAX).checked_add(Ratio::integer(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_linear() {
+        // (x - 3) / 2 * 4 + 1 = 2x - 5
+        let x = Linear::unknown().sub(Linear::constant(3)).unwrap();
+        let x = x.div(Linear::constant(2)).unwrap();
+        let x = x.mul(Linear::constant(4)).unwrap();
+        let x = x.add(Linear::constant(1)).unwrap();
+        let two_x = Linear::unknown().add(Linear::unknown()).unwrap();
+        assert_eq!(Ok(x), two_x.sub(Linear::constant(5)));
+        assert_eq!(x.mul(Linear::unknown()), Err(Error::NonLinear));
+        assert_eq!(
+            Linear::constant(1).div(Linear::unknown()),
+            Err(Error::NonLinear)
+        );
+        assert_eq!(x.div(Linear::constant(0)), Err(Error::DivisionByZero));
+        assert_eq!(x.mul(Linear::constant(i128::MAX)), Err(Error::Overflow));
+    }
+}
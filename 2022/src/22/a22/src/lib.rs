@@ -0,0 +1,190 @@
+use std::fmt::Display;
+
+use aoc::Solution;
+use aoc_grid::{Dir, Grid};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Direction {
+    Left,
+    Right,
+    Forward(i64),
+}
+
+fn facing_score(facing: Dir) -> usize {
+    match facing {
+        Dir::E => 0,
+        Dir::S => 1,
+        Dir::W => 2,
+        Dir::N => 3,
+    }
+}
+
+struct Board {
+    map: Grid,
+    directions: Vec<Direction>,
+    x: usize,
+    y: usize,
+    facing: Dir,
+    t: usize,
+}
+
+impl Board {
+    fn new(map: Grid, directions: Vec<Direction>) -> Self {
+        let x = map.find(&b'.').unwrap().0;
+        let y = 0;
+        let facing = Dir::E;
+        let t = 0;
+        let mut me = Self {
+            map,
+            directions,
+            x,
+            y,
+            facing,
+            t,
+        };
+        me.mark();
+        me
+    }
+
+    fn run(&mut self) {
+        while self.t < self.directions.len() {
+            self.step();
+        }
+    }
+
+    fn step(&mut self) {
+        match self.directions[self.t] {
+            Direction::Left => {
+                self.facing = self.facing.ccw();
+                self.mark();
+            }
+            Direction::Right => {
+                self.facing = self.facing.cw();
+                self.mark();
+            }
+            Direction::Forward(n) => {
+                for _ in 0..n {
+                    if !self.forward() {
+                        break;
+                    }
+                    self.mark();
+                }
+            }
+        }
+        self.t += 1;
+    }
+
+    fn forward(&mut self) -> bool {
+        let (new_x, new_y) = match self.facing {
+            Dir::N => {
+                let height = self.map.height();
+                let mut new_y = (self.y + height - 1) % height;
+                while self.get(self.x, new_y).is_none() {
+                    new_y = (new_y + height - 1) % height;
+                }
+                (self.x, new_y)
+            }
+            Dir::S => {
+                let height = self.map.height();
+                let mut new_y = (self.y + 1) % height;
+                while self.get(self.x, new_y).is_none() {
+                    new_y = (new_y + 1) % height;
+                }
+                (self.x, new_y)
+            }
+            Dir::E => {
+                let width = self.map.width();
+                let mut new_x = (self.x + 1) % width;
+                while self.get(new_x, self.y).is_none() {
+                    new_x = (new_x + 1) % width;
+                }
+                (new_x, self.y)
+            }
+            Dir::W => {
+                let width = self.map.width();
+                let mut new_x = (self.x + width - 1) % width;
+                while self.get(new_x, self.y).is_none() {
+                    new_x = (new_x + width - 1) % width;
+                }
+                (new_x, self.y)
+            }
+        };
+        if self.get(new_x, new_y).unwrap() != b'#' {
+            self.x = new_x;
+            self.y = new_y;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn get(&self, x: usize, y: usize) -> Option<u8> {
+        match self.map.get((x, y)) {
+            None | Some(b' ') => None,
+            Some(&c) => Some(c),
+        }
+    }
+
+    fn mark(&mut self) {
+        let pos = (self.x, self.y);
+        assert_ne!(self.map[pos], b' ');
+        assert_ne!(self.map[pos], b'#');
+        self.map[pos] = self.facing.arrow();
+    }
+}
+
+impl Display for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.map)?;
+        write!(f, "\n{:?}\n", self.directions)?;
+        Ok(())
+    }
+}
+
+/// The map of the board, and the path to follow across it.
+pub struct Notes {
+    map: Grid,
+    directions: Vec<Direction>,
+}
+
+fn parse_notes(input: &str) -> aoc_parse::Result<Notes> {
+    let [map, path] = aoc_parse::split_sections(input)?;
+    for mut line in map.lines() {
+        while !line.is_empty() {
+            line.one_of(&[(" ", ()), (".", ()), ("#", ())])?;
+        }
+    }
+    let map = Grid::parse_padded(map.text(), b' ')?;
+    if map.find(&b'.').is_none() {
+        return Err(aoc_parse::Error::new(1, 1, "expected an open tile"));
+    }
+
+    let [mut line] = path.split_lines()?;
+    let mut directions = vec![];
+    while !line.is_empty() {
+        directions.push(if line.eat("L") {
+            Direction::Left
+        } else if line.eat("R") {
+            Direction::Right
+        } else {
+            Direction::Forward(line.int()?)
+        });
+    }
+    Ok(Notes { map, directions })
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Notes;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        parse_notes(input)
+    }
+
+    fn part_a(notes: &Self::Input) -> Option<String> {
+        let mut board = Board::new(notes.map.clone(), notes.directions.clone());
+        board.run();
+        Some((1000 * (board.y + 1) + 4 * (board.x + 1) + facing_score(board.facing)).to_string())
+    }
+}
@@ -0,0 +1,274 @@
+use aoc::Solution;
+use aoc_cube::Cube;
+use aoc_grid::{Dir, Grid, Pos};
+
+/**
+ * Based on /u/Kentzo's solution: https://www.reddit.com/r/adventofcode/comments/zsct8w/2022_day_22_solutions/j25n56r/
+ * Python code: https://gist.githubusercontent.com/Kentzo/2319c054832f2ff2befc7cfacf914976/raw/24c62150f20a7940d121c0e9a3f78513f5c5a1e6/aoc22.py
+ *
+ * The folding itself now lives in `aoc_cube`, which handles any net.
+ */
+
+/**
+ * Coordinates to a location in the flattened input text.
+ */
+type InputCoord = (usize, usize);
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Direction {
+    Left,
+    Right,
+    Forward(i64),
+}
+
+fn score(facing: Dir) -> usize {
+    match facing {
+        Dir::E => 0,
+        Dir::S => 1,
+        Dir::W => 2,
+        Dir::N => 3,
+    }
+}
+
+#[derive(Clone)]
+pub struct Input {
+    rows: Vec<Vec<u8>>,
+    directions: Vec<Direction>,
+}
+
+impl Input {
+    fn parse(input: &str) -> aoc_parse::Result<Self> {
+        let [map, path] = aoc_parse::split_sections(input)?;
+        let mut rows = vec![];
+        for mut line in map.lines() {
+            let mut row = vec![];
+            while !line.is_empty() {
+                row.push(line.one_of(&[(" ", b' '), (".", b'.'), ("#", b'#')])?);
+            }
+            rows.push(row);
+        }
+
+        let [mut line] = path.split_lines()?;
+        let mut directions = vec![];
+        while !line.is_empty() {
+            directions.push(if line.eat("L") {
+                Direction::Left
+            } else if line.eat("R") {
+                Direction::Right
+            } else {
+                Direction::Forward(line.int()?)
+            });
+        }
+        Ok(Self { rows, directions })
+    }
+
+    /**
+     * Return a node with the given coordinates, if it exists.
+     */
+    fn get(&self, coords: InputCoord) -> Option<u8> {
+        let (x, y) = coords;
+        if y >= self.rows.len() {
+            return None;
+        }
+        if x >= self.rows[y].len() {
+            return None;
+        }
+        if self.rows[y][x] == b' ' {
+            return None;
+        }
+        Some(self.rows[y][x])
+    }
+
+    /**
+     * Top-left nonempty node. Where the player begins.
+     */
+    fn starting_point(&self) -> (usize, usize) {
+        let x = self.rows[0]
+            .iter()
+            .enumerate()
+            .find(|(_, c)| **c != b' ')
+            .unwrap()
+            .0;
+        let y = 0;
+        (x, y)
+    }
+
+    /**
+     * Example input given in the puzzle
+     */
+    #[cfg(test)]
+    fn example() -> Self {
+        let lines = [
+            "        ...#",
+            "        .#..",
+            "        #...",
+            "        ....",
+            "...#.......#",
+            "........#...",
+            "..#....#....",
+            "..........#.",
+            "        ...#....",
+            "        .....#..",
+            "        .#......",
+            "        ......#.",
+            "",
+            "10R5L5R10L4R5L5",
+        ];
+        Self::parse(&lines.join("\n")).unwrap()
+    }
+
+    /**
+     * The map as a rectangular grid, padded with spaces.
+     */
+    fn grid(&self) -> Grid {
+        let width = self.rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        Grid::from_fn(width, self.rows.len(), |pos| self.get(pos).unwrap_or(b' '))
+    }
+}
+
+struct Board<'a> {
+    input: &'a Input,
+    grid: Grid,
+    cube: Cube,
+    current_coords: Pos,
+    facing: Dir,
+    t: usize,
+}
+
+impl<'a> Board<'a> {
+    fn new(input: &'a Input) -> Self {
+        let grid = input.grid();
+        let cube = Cube::new(&grid).unwrap_or_else(|err| panic!("{err}"));
+        Self {
+            input,
+            grid,
+            cube,
+            current_coords: input.starting_point(),
+            facing: Dir::E,
+            t: 0,
+        }
+    }
+
+    fn run(&mut self) {
+        while self.t < self.input.directions.len() {
+            self.step();
+        }
+    }
+
+    fn step(&mut self) {
+        match self.input.directions[self.t] {
+            Direction::Left => self.facing = self.facing.ccw(),
+            Direction::Right => self.facing = self.facing.cw(),
+            Direction::Forward(n) => {
+                for _ in 0..n {
+                    let (coords, facing) = self.cube.wrap(self.current_coords, self.facing);
+                    if self.grid[coords] == b'#' {
+                        break;
+                    }
+                    self.current_coords = coords;
+                    self.facing = facing;
+                }
+            }
+        }
+        self.t += 1;
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Input;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        Input::parse(input)
+    }
+
+    fn part_b(input: &Self::Input) -> Option<String> {
+        let mut board = Board::new(input);
+        board.run();
+        let (x, y) = board.current_coords;
+        Some((1000 * (y + 1) + 4 * (x + 1) + score(board.facing)).to_string())
+    }
+}
+
+#[test]
+fn test_example_start() {
+    /* Structure of the example input:
+     *   A
+     * BCD
+     *   EF
+     */
+    let ex = Input::example();
+    let side_len = Board::new(&ex).cube.size();
+    assert_eq!(side_len, 4);
+    let (x, y) = ex.starting_point();
+    assert_eq!(x, side_len * 2);
+    assert_eq!(y, 0);
+}
+
+#[test]
+fn test_example() {
+    /* Structure of the example input:
+     *   A
+     * BCD
+     *   EF
+     */
+    let mut ex = Input::example();
+    ex.directions = vec![
+        Direction::Forward(2),
+        Direction::Right,
+        Direction::Forward(5),
+        Direction::Left,
+        Direction::Forward(3),
+    ];
+    let mut board = Board::new(&ex);
+    let (x, y) = board.current_coords;
+    let side_len = board.cube.size();
+    assert_eq!(x, side_len * 2);
+    assert_eq!(y, 0);
+    // Take two steps East
+    board.step();
+    assert_eq!(board.facing, Dir::E);
+    assert_eq!(board.current_coords, (side_len * 2 + 2, 0));
+    // Turn Right to go South
+    board.step();
+    assert_eq!(board.facing, Dir::S);
+    // Now take five steps south, off the south edge of A. This puts you on the north edge of D, facing south.
+    board.step();
+    assert_eq!(board.current_coords, (side_len * 2 + 2, 5));
+    assert_eq!(board.facing, Dir::S);
+    // Now make a left turn. You are now facing East.
+    board.step();
+    assert_eq!(board.facing, Dir::E);
+    // Now walk three steps off the east edge of D. You are now on F, facing South.
+    board.step();
+    assert_eq!(board.current_coords, (side_len * 3 + 2, side_len * 2 + 1));
+    assert_eq!(board.facing, Dir::S);
+}
+
+#[test]
+fn test_wrap_coordinate() {
+    /*   A
+     * BCD
+     *   EF
+     */
+    let mut ex = Input::example();
+    ex.directions = vec![
+        Direction::Forward(1),
+        Direction::Left,
+        Direction::Forward(1),
+    ];
+    let mut board = Board::new(&ex);
+    let side_len = board.cube.size();
+    // Take one step East
+    board.step();
+    assert_eq!(board.facing, Dir::E);
+    assert_eq!(board.current_coords, (side_len * 2 + 1, 0));
+    // Turn Left to go North
+    board.step();
+    assert_eq!(board.facing, Dir::N);
+    // Now take one step north off north edge of A. This puts you on the north edge of B, facing south.
+    board.step();
+    assert_eq!(board.current_coords, (side_len - 2, side_len));
+    assert_eq!(board.facing, Dir::S);
+}
@@ -0,0 +1,251 @@
+use std::fmt;
+
+use aoc::Solution;
+use aoc_grid::{Dir, Grid};
+
+type Point = (usize, usize);
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+struct Blizzard {
+    point: Point,
+    dir: Dir,
+}
+
+fn apply(dir: Dir, (x, y): Point) -> Point {
+    let (dx, dy) = dir.delta();
+    (x.wrapping_add_signed(dx), y.wrapping_add_signed(dy))
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+struct Board {
+    width: usize,
+    height: usize,
+    start_x: usize,
+    goal_x: usize,
+    blizzards: Vec<Vec<Blizzard>>,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
+struct Explorer {
+    minute: usize,
+    point: Point,
+}
+
+impl Board {
+    fn new(map: &Grid) -> Self {
+        assert!(map.height() > 2);
+        assert!(map.width() > 2);
+
+        let start_x = map
+            .rows()
+            .next()
+            .unwrap()
+            .iter()
+            .position(|&byte| byte == b'.')
+            .unwrap();
+        let goal_x = map
+            .rows()
+            .last()
+            .unwrap()
+            .iter()
+            .position(|&byte| byte == b'.')
+            .unwrap();
+
+        let mut initial_blizzards = vec![];
+        for row in map.rows() {
+            assert!(*row.first().unwrap() == b'#');
+            assert!(*row.last().unwrap() == b'#');
+        }
+        for (point, &byte) in map.iter() {
+            match byte {
+                b'#' | b'.' => {}
+                _ => initial_blizzards.push(Blizzard {
+                    point,
+                    dir: Dir::from_byte(byte).unwrap_or_else(|| panic!("Unexpected byte: {byte}")),
+                }),
+            }
+        }
+
+        Self {
+            width: map.width(),
+            height: map.height(),
+            start_x,
+            goal_x,
+            blizzards: vec![initial_blizzards],
+        }
+    }
+
+    fn initial_explorer(&self) -> Explorer {
+        Explorer {
+            point: self.start(),
+            minute: 0,
+        }
+    }
+
+    fn run_until(&mut self, minute: usize) {
+        while self.blizzards.len() <= minute {
+            self.blizzards.push(
+                self.blizzards
+                    .last()
+                    .unwrap()
+                    .iter()
+                    .map(|blizzard| self.move_blizzard(*blizzard))
+                    .collect(),
+            );
+        }
+    }
+
+    fn move_explorer(&mut self, explorer: Explorer, choice: Option<Dir>) -> Option<Explorer> {
+        self.run_until(explorer.minute + 1);
+
+        let (new_x, new_y) = choice
+            .map(|dir| apply(dir, explorer.point))
+            .unwrap_or(explorer.point);
+
+        // Bounds check
+        if new_x == 0
+            || new_x >= self.width - 1
+            || (new_y == 0 && new_x != self.start_x)
+            || (new_y == self.height - 1 && new_x != self.goal_x)
+            || new_y >= self.height
+        {
+            return None;
+        }
+
+        // Collision check
+        if self.blizzards[explorer.minute + 1]
+            .iter()
+            .any(|blizzard| blizzard.point == (new_x, new_y))
+        {
+            return None;
+        }
+
+        Some(Explorer {
+            minute: explorer.minute + 1,
+            point: (new_x, new_y),
+        })
+    }
+
+    fn move_blizzard(&self, blizzard: Blizzard) -> Blizzard {
+        let (mut new_x, mut new_y) = apply(blizzard.dir, blizzard.point);
+
+        if new_x == 0 {
+            new_x = self.width - 2;
+        } else if new_x >= self.width - 1 {
+            new_x = 1;
+        }
+
+        if new_y == 0 {
+            new_y = self.height - 2;
+        } else if new_y >= self.height - 1 {
+            new_y = 1;
+        }
+
+        Blizzard {
+            point: (new_x, new_y),
+            ..blizzard
+        }
+    }
+
+    fn start(&self) -> Point {
+        (self.start_x, 0)
+    }
+
+    fn goal(&self) -> Point {
+        (self.goal_x, self.height - 1)
+    }
+}
+
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", render_state(self, None))
+    }
+}
+
+fn render_state(board: &Board, explorer: Option<Explorer>) -> String {
+    let mut out = Grid::from_fn(board.width, board.height, |(x, y)| {
+        if (y == 0 && x == board.start_x) || (y == board.height - 1 && x == board.goal_x) {
+            b'.'
+        } else if x == 0 || y == 0 || x == board.width - 1 || y == board.height - 1 {
+            b'#'
+        } else {
+            b'.'
+        }
+    });
+
+    let minute = match explorer {
+        Some(Explorer { point: _, minute }) => minute,
+        _ => 0,
+    };
+
+    // Print blizzards, handling collisions by displaying the number of blizzards (if there are 2-9 blizzards)
+    for blizzard in &board.blizzards[minute] {
+        out[blizzard.point] = match out[blizzard.point] {
+            b'.' => blizzard.dir.arrow(),
+            b'^' | b'v' | b'<' | b'>' => b'2',
+            b'2'..=b'8' => out[blizzard.point] + 1,
+            _ => b'@',
+        }
+    }
+
+    // Print explorer
+    if let Some(explorer) = explorer {
+        out[explorer.point] = b'E';
+    }
+
+    out.to_string()
+}
+
+fn manhattan((x1, y1): Point, (x2, y2): Point) -> usize {
+    x1.abs_diff(x2) + y1.abs_diff(y2)
+}
+
+fn a_star(board: &mut Board, initial_explorer: Explorer, target: Point) -> Option<Vec<Explorer>> {
+    let successors = |&explorer: &Explorer| {
+        let mut choices = vec![None];
+        let (x, y) = explorer.point;
+        if x > 0 {
+            choices.push(Some(Dir::W));
+        }
+        if x < board.width - 1 {
+            choices.push(Some(Dir::E));
+        }
+        if y > 0 {
+            choices.push(Some(Dir::N));
+        }
+        if y < board.height - 1 {
+            choices.push(Some(Dir::S));
+        }
+
+        choices
+            .into_iter()
+            .filter_map(|choice| board.move_explorer(explorer, choice))
+            .map(|new_explorer| (new_explorer, 1))
+            .collect::<Vec<_>>()
+    };
+    let (path, _) = aoc_search::astar(
+        [initial_explorer],
+        successors,
+        |explorer| manhattan(explorer.point, target),
+        |explorer| explorer.point == target,
+    )?;
+    Some(path)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Grid;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part_a(map: &Self::Input) -> Option<String> {
+        let mut board = Board::new(map);
+
+        let (initial_explorer, goal) = (board.initial_explorer(), board.goal());
+        let path = a_star(&mut board, initial_explorer, goal).unwrap();
+        Some((path.len() - 1).to_string())
+    }
+}
@@ -0,0 +1,111 @@
+use aoc::Solution;
+
+#[derive(Debug, Copy, Clone)]
+pub enum Digit {
+    DoubleMinus,
+    Minus,
+    Zero,
+    One,
+    Two,
+}
+
+fn parse_snafu(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Vec<Digit>> {
+    let mut digits = vec![];
+    while !line.is_empty() {
+        digits.push(line.one_of(&[
+            ("=", Digit::DoubleMinus),
+            ("-", Digit::Minus),
+            ("0", Digit::Zero),
+            ("1", Digit::One),
+            ("2", Digit::Two),
+        ])?);
+    }
+    Ok(digits)
+}
+
+fn render_snafu(digits: &Vec<Digit>) -> Vec<u8> {
+    digits
+        .iter()
+        .map(|digit| match digit {
+            Digit::DoubleMinus => b'=',
+            Digit::Minus => b'-',
+            Digit::Zero => b'0',
+            Digit::One => b'1',
+            Digit::Two => b'2',
+        })
+        .collect()
+}
+
+fn snafu_to_int(digits: &Vec<Digit>) -> i64 {
+    let mut sum = 0;
+    let mut power = 1;
+    for digit in digits.iter().rev() {
+        sum += match digit {
+            Digit::DoubleMinus => -2,
+            Digit::Minus => -1,
+            Digit::Zero => 0,
+            Digit::One => 1,
+            Digit::Two => 2,
+        } * power;
+        power *= 5;
+    }
+    sum
+}
+
+fn int_to_snafu(mut num: i64) -> Vec<Digit> {
+    let mut digits = vec![];
+    while num != 0 {
+        let remainder = num % 5;
+        digits.push(match remainder {
+            0 => Digit::Zero,
+            1 => {
+                num -= 1;
+                Digit::One
+            }
+            2 => {
+                num -= 2;
+                Digit::Two
+            }
+            3 => {
+                num += 2;
+                Digit::DoubleMinus
+            }
+            4 => {
+                num += 1;
+                Digit::Minus
+            }
+            _ => panic!("Modulo 5"),
+        });
+        num /= 5;
+    }
+    digits.reverse();
+    digits
+}
+
+#[test]
+fn test_examples() {
+    for (num, str) in [
+        (2022, "1=11-2"),
+        (12345, "1-0---0"),
+        (314159265, "1121-1110-1=0"),
+    ] {
+        let digits = parse_snafu(&mut aoc_parse::Cursor::new(str, 1)).unwrap();
+        assert_eq!(snafu_to_int(&digits), num);
+        assert_eq!(render_snafu(&int_to_snafu(num)), str.as_bytes());
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<Vec<Digit>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, parse_snafu)
+    }
+
+    fn part_a(numbers: &Self::Input) -> Option<String> {
+        let sum = numbers.iter().map(snafu_to_int).sum();
+        Some(String::from_utf8(render_snafu(&int_to_snafu(sum))).unwrap())
+    }
+}
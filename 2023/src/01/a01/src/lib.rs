@@ -0,0 +1,39 @@
+use aoc::Solution;
+
+/// Reads a line of the calibration document, returning its first and last
+/// digits.
+fn parse_calibration(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<(u32, u32)> {
+    let start = *line;
+    let mut digits = vec![];
+    while !line.is_empty() {
+        let before = *line;
+        let c = line.char()?;
+        if !c.is_ascii_alphanumeric() {
+            return Err(before.error(format!("expected a letter or digit, found `{c}`")));
+        }
+        digits.extend(c.to_digit(10));
+    }
+    match (digits.first(), digits.last()) {
+        (Some(&first), Some(&last)) => Ok((first, last)),
+        _ => Err(start.error("expected a digit somewhere in the line")),
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<(u32, u32)>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, parse_calibration)
+    }
+
+    fn part_a(calibrations: &Self::Input) -> Option<String> {
+        let mut total = 0;
+        for (first_num, last_num) in calibrations {
+            let ans = (first_num * 10) + last_num;
+            total += ans;
+        }
+        Some(total.to_string())
+    }
+}
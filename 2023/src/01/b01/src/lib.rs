@@ -0,0 +1,56 @@
+use aoc::Solution;
+
+const DIGIT_NAMES: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Reads a line of the calibration document, returning its first and last
+/// digits, whether written as digits or spelled out. Spelled-out digits can
+/// overlap, as in `eightwo`.
+fn parse_calibration(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<(u32, u32)> {
+    let start = *line;
+    let mut digits = vec![];
+    while !line.is_empty() {
+        let before = *line;
+        if let Some(n) = DIGIT_NAMES
+            .iter()
+            .position(|name| before.rest().starts_with(name))
+        {
+            digits.push(n as u32);
+        }
+        let c = line.char()?;
+        if !c.is_ascii_alphanumeric() {
+            return Err(before.error(format!("expected a letter or digit, found `{c}`")));
+        }
+        digits.extend(c.to_digit(10));
+    }
+    match (digits.first(), digits.last()) {
+        (Some(&first), Some(&last)) => Ok((first, last)),
+        _ => Err(start.error("expected a digit somewhere in the line")),
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<(u32, u32)>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, parse_calibration)
+    }
+
+    fn part_b(calibrations: &Self::Input) -> Option<String> {
+        let mut total = 0;
+        for (first_num, last_num) in calibrations {
+            let ans = (first_num * 10) + last_num;
+            total += ans;
+        }
+        Some(total.to_string())
+    }
+}
+
+#[test]
+fn test_overlapping_names() {
+    let mut line = aoc_parse::Cursor::new("eightwo", 1);
+    assert_eq!(parse_calibration(&mut line), Ok((8, 2)));
+}
@@ -0,0 +1,73 @@
+use aoc::Solution;
+
+/// How many red, green and blue cubes were shown at once.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Cubes {
+    red: i32,
+    green: i32,
+    blue: i32,
+}
+
+pub struct Game {
+    id: i32,
+    rounds: Vec<Cubes>,
+}
+
+fn parse_round(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Cubes> {
+    let mut cubes = Cubes::default();
+    line.list(",", |line| {
+        let num_cubes = line.int()?;
+        line.literal(" ")?;
+        let before = *line;
+        match line.word()? {
+            "red" => cubes.red = num_cubes,
+            "green" => cubes.green = num_cubes,
+            "blue" => cubes.blue = num_cubes,
+            color => return Err(before.error(format!("`{color}` isn't a cube color"))),
+        }
+        Ok(())
+    })?;
+    Ok(cubes)
+}
+
+fn parse_game(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Game> {
+    line.literal("Game ")?;
+    let id = line.int()?;
+    line.literal(": ")?;
+    let rounds = line.list(";", parse_round)?;
+    Ok(Game { id, rounds })
+}
+
+fn legal_game_id(game: &Game) -> Option<i32> {
+    let num_red = 12;
+    let num_green = 13;
+    let num_blue = 14;
+
+    for round in &game.rounds {
+        if round.red > num_red || round.green > num_green || round.blue > num_blue {
+            return None;
+        }
+    }
+
+    Some(game.id)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, parse_game)
+    }
+
+    fn part_a(games: &Self::Input) -> Option<String> {
+        let mut sum_legal_games = 0;
+        for game in games {
+            if let Some(id) = legal_game_id(game) {
+                sum_legal_games += id;
+            }
+        }
+        Some(sum_legal_games.to_string())
+    }
+}
@@ -0,0 +1,70 @@
+use aoc::Solution;
+
+/// How many red, green and blue cubes were shown at once.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Cubes {
+    red: i32,
+    green: i32,
+    blue: i32,
+}
+
+pub struct Game {
+    rounds: Vec<Cubes>,
+}
+
+fn parse_round(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Cubes> {
+    let mut cubes = Cubes::default();
+    line.list(",", |line| {
+        let num_cubes = line.int()?;
+        line.literal(" ")?;
+        let before = *line;
+        match line.word()? {
+            "red" => cubes.red = num_cubes,
+            "green" => cubes.green = num_cubes,
+            "blue" => cubes.blue = num_cubes,
+            color => return Err(before.error(format!("`{color}` isn't a cube color"))),
+        }
+        Ok(())
+    })?;
+    Ok(cubes)
+}
+
+fn parse_game(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Game> {
+    line.literal("Game ")?;
+    line.int::<i32>()?;
+    line.literal(": ")?;
+    let rounds = line.list(";", parse_round)?;
+    Ok(Game { rounds })
+}
+
+fn game_power(game: &Game) -> i32 {
+    let mut num_red = 0;
+    let mut num_green = 0;
+    let mut num_blue = 0;
+
+    for round in &game.rounds {
+        num_red = num_red.max(round.red);
+        num_green = num_green.max(round.green);
+        num_blue = num_blue.max(round.blue);
+    }
+
+    num_red * num_green * num_blue
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, parse_game)
+    }
+
+    fn part_b(games: &Self::Input) -> Option<String> {
+        let mut sum_power = 0;
+        for game in games {
+            sum_power += game_power(game);
+        }
+        Some(sum_power.to_string())
+    }
+}
@@ -0,0 +1,51 @@
+use aoc::Solution;
+use aoc_grid::{Grid, Pos};
+
+fn is_symbol(c: u8) -> bool {
+    c != b'.' && !c.is_ascii_digit()
+}
+
+fn symbol_adjacent_number(map: &Grid, (mut x, y): Pos) -> (i32, usize) {
+    let mut total: i32 = 0;
+    let mut valid = false;
+    while x < map.width() && map[(x, y)].is_ascii_digit() {
+        total *= 10;
+        total += (map[(x, y)] - b'0') as i32;
+        if !valid {
+            valid = map.neighbors8((x, y)).any(|pos| is_symbol(map[pos]));
+        }
+        x += 1;
+    }
+    if valid {
+        (total, x)
+    } else {
+        (0, x)
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Grid;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part_a(map: &Self::Input) -> Option<String> {
+        let mut sum = 0;
+        for y in 0..map.height() {
+            let mut x = 0;
+            while x < map.width() {
+                if map[(x, y)].is_ascii_digit() {
+                    let (num, new_x) = symbol_adjacent_number(map, (x, y));
+                    x = new_x;
+                    sum += num;
+                } else {
+                    x += 1;
+                }
+            }
+        }
+        Some(sum.to_string())
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::HashSet;
+
+use aoc::Solution;
+use aoc_grid::{Grid, Pos};
+
+fn get_num(map: &Grid, y: usize, min_x: usize, max_x: usize) -> i32 {
+    let mut x = min_x;
+    let mut num = 0;
+    while x < max_x {
+        num *= 10;
+        num += (map[(x, y)] - b'0') as i32;
+        x += 1;
+    }
+    num
+}
+
+fn get_num_span(map: &Grid, (x, y): Pos) -> (usize, usize, usize) {
+    let mut min_x = x;
+    while min_x > 0 && map[(min_x - 1, y)].is_ascii_digit() {
+        min_x -= 1;
+    }
+
+    let mut max_x = x;
+    while max_x < map.width() && map[(max_x, y)].is_ascii_digit() {
+        max_x += 1;
+    }
+
+    (y, min_x, max_x)
+}
+
+fn get_ratio(map: &Grid, pos: Pos) -> i32 {
+    let mut spans = HashSet::new();
+
+    for pos in map.neighbors8(pos) {
+        if map[pos].is_ascii_digit() {
+            spans.insert(get_num_span(map, pos));
+        }
+    }
+
+    if spans.len() != 2 {
+        return 0;
+    }
+    spans.iter().fold(1, |acc, &(y, min_x, max_x)| {
+        acc * get_num(map, y, min_x, max_x)
+    })
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Grid;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part_b(map: &Self::Input) -> Option<String> {
+        let mut sum = 0;
+        for pos in map.find_all(&b'*') {
+            sum += get_ratio(map, pos);
+        }
+        Some(sum.to_string())
+    }
+}
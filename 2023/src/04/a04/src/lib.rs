@@ -0,0 +1,59 @@
+use std::collections::HashSet;
+
+use aoc::Solution;
+
+pub struct Card {
+    winning: HashSet<i32>,
+    mine: HashSet<i32>,
+}
+
+fn parse_card(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Card> {
+    line.literal("Card")?;
+    line.skip_spaces();
+    line.int::<usize>()?;
+    line.literal(":")?;
+    let mut winning = HashSet::new();
+    loop {
+        line.skip_spaces();
+        if line.eat("|") {
+            break;
+        }
+        winning.insert(line.int()?);
+    }
+    let mut mine = HashSet::new();
+    loop {
+        line.skip_spaces();
+        if line.is_empty() {
+            break;
+        }
+        mine.insert(line.int()?);
+    }
+    Ok(Card { winning, mine })
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, parse_card)
+    }
+
+    fn part_a(cards: &Self::Input) -> Option<String> {
+        let mut total = 0;
+        for card in cards {
+            let winning = &card.winning;
+            let mine = &card.mine;
+
+            let num_winning = winning.intersection(mine).count() as u32;
+            if num_winning == 0 {
+                continue;
+            }
+
+            let score = 2_usize.pow(num_winning - 1);
+            total += score;
+        }
+        Some(total.to_string())
+    }
+}
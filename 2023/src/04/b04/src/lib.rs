@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+
+use aoc::Solution;
+
+pub struct Card {
+    winning: HashSet<i32>,
+    mine: HashSet<i32>,
+}
+
+fn parse_card(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Card> {
+    line.literal("Card")?;
+    line.skip_spaces();
+    line.int::<usize>()?;
+    line.literal(":")?;
+    let mut winning = HashSet::new();
+    loop {
+        line.skip_spaces();
+        if line.eat("|") {
+            break;
+        }
+        winning.insert(line.int()?);
+    }
+    let mut mine = HashSet::new();
+    loop {
+        line.skip_spaces();
+        if line.is_empty() {
+            break;
+        }
+        mine.insert(line.int()?);
+    }
+    Ok(Card { winning, mine })
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, parse_card)
+    }
+
+    fn part_b(cards: &Self::Input) -> Option<String> {
+        let mut num_matching = vec![];
+        for card in cards {
+            let winning = &card.winning;
+            let mine = &card.mine;
+
+            num_matching.push(winning.intersection(mine).count());
+        }
+
+        let mut num_resolved = vec![0; num_matching.len()];
+        let mut num_unresolved = vec![1; num_matching.len()];
+        while num_unresolved.iter().any(|&x| x > 0) {
+            let mut new_unresolved = vec![0; num_matching.len()];
+            for (i, n) in num_unresolved.iter().enumerate() {
+                for j in 1..num_matching[i] + 1 {
+                    new_unresolved[i + j] += n
+                }
+                num_resolved[i] += n;
+            }
+            num_unresolved = new_unresolved;
+        }
+
+        let mut total = 0;
+        for n in &num_resolved {
+            total += n;
+        }
+        Some(total.to_string())
+    }
+}
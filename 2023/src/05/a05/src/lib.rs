@@ -0,0 +1,138 @@
+use aoc::Solution;
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct Range {
+    src: usize,
+    dest: usize,
+    len: usize,
+}
+
+impl Range {
+    fn convert(&self, src_value: usize) -> Option<usize> {
+        if src_value < self.src || src_value - self.src >= self.len {
+            None
+        } else {
+            Some(self.dest + (src_value - self.src))
+        }
+    }
+}
+
+/// The almanac's maps, in the order it lists them.
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+pub struct Almanac {
+    seeds: Vec<usize>,
+    seed_to_soil: Vec<Range>,
+    soil_to_fertilizer: Vec<Range>,
+    fertilizer_to_water: Vec<Range>,
+    water_to_light: Vec<Range>,
+    light_to_temperature: Vec<Range>,
+    temperature_to_humidity: Vec<Range>,
+    humidity_to_location: Vec<Range>,
+}
+
+fn parse_map(section: aoc_parse::Section, name: &str) -> aoc_parse::Result<Vec<Range>> {
+    let mut lines = section.lines();
+    let mut header = lines.next().unwrap();
+    header.literal(name)?;
+    header.literal(" map:")?;
+    header.end()?;
+    lines
+        .map(|mut line| {
+            let start = line;
+            let [dest, src, len] = line.ints(" ")?.try_into().map_err(|_| {
+                start.error("expected a destination start, a source start, and a length")
+            })?;
+            line.end()?;
+            Ok(Range { src, dest, len })
+        })
+        .collect::<aoc_parse::Result<Vec<_>>>()
+        .map(|mut map| {
+            map.sort();
+            map
+        })
+}
+
+fn parse_almanac(input: &str) -> aoc_parse::Result<Almanac> {
+    let [seeds, maps @ ..] = aoc_parse::split_sections::<8>(input)?;
+    let [mut line] = seeds.split_lines()?;
+    line.field("seeds")?;
+    let seeds = line.ints(" ")?;
+    line.end()?;
+
+    let mut maps = maps
+        .into_iter()
+        .zip(MAPS)
+        .map(|(section, name)| parse_map(section, name));
+    let mut next_map = || maps.next().unwrap();
+    Ok(Almanac {
+        seeds,
+        seed_to_soil: next_map()?,
+        soil_to_fertilizer: next_map()?,
+        fertilizer_to_water: next_map()?,
+        water_to_light: next_map()?,
+        light_to_temperature: next_map()?,
+        temperature_to_humidity: next_map()?,
+        humidity_to_location: next_map()?,
+    })
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        parse_almanac(input)
+    }
+
+    fn part_a(almanac: &Self::Input) -> Option<String> {
+        let Almanac {
+            seeds,
+            seed_to_soil,
+            soil_to_fertilizer,
+            fertilizer_to_water,
+            water_to_light,
+            light_to_temperature,
+            temperature_to_humidity,
+            humidity_to_location,
+        } = almanac;
+
+        let get_locations = |seeds: Vec<usize>| -> Vec<usize> {
+            macro_rules! lookup {
+                ($srcs: ident, $dsts: ident, $lookup: ident) => {
+                    let mut $dsts = vec![];
+                    for src in $srcs {
+                        let dst = $lookup
+                            .iter()
+                            .flat_map(|range| range.convert(src))
+                            .next()
+                            .unwrap_or(src);
+                        $dsts.push(dst);
+                    }
+                };
+            }
+
+            lookup!(seeds, soils, seed_to_soil);
+            lookup!(soils, fertilizers, soil_to_fertilizer);
+            lookup!(fertilizers, waters, fertilizer_to_water);
+            lookup!(waters, lights, water_to_light);
+            lookup!(lights, temperatures, light_to_temperature);
+            lookup!(temperatures, humidities, temperature_to_humidity);
+            lookup!(humidities, locations, humidity_to_location);
+            locations
+        };
+
+        let locations = get_locations(seeds.clone());
+        let min_location = locations.into_iter().min().unwrap();
+        Some(min_location.to_string())
+    }
+}
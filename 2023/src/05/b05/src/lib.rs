@@ -0,0 +1,127 @@
+use aoc::Solution;
+use aoc_interval::{IntervalSet, RangeMap};
+
+/// The almanac's maps, in the order it lists them.
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+pub struct Almanac {
+    seeds: Vec<usize>,
+    seed_to_soil: RangeMap<usize>,
+    soil_to_fertilizer: RangeMap<usize>,
+    fertilizer_to_water: RangeMap<usize>,
+    water_to_light: RangeMap<usize>,
+    light_to_temperature: RangeMap<usize>,
+    temperature_to_humidity: RangeMap<usize>,
+    humidity_to_location: RangeMap<usize>,
+}
+
+fn parse_map(section: aoc_parse::Section, name: &str) -> aoc_parse::Result<RangeMap<usize>> {
+    let mut lines = section.lines();
+    let mut header = lines.next().unwrap();
+    header.literal(name)?;
+    header.literal(" map:")?;
+    header.end()?;
+    let mut map = RangeMap::new();
+    for mut line in lines {
+        let start = line;
+        let [dest, src, len] = line.ints(" ")?.try_into().map_err(|_| {
+            start.error("expected a destination start, a source start, and a length")
+        })?;
+        line.end()?;
+        if map.overlaps(&(src..src + len)) {
+            return Err(start.error("overlaps a source range mapped above"));
+        }
+        map.insert(src..src + len, dest);
+    }
+    Ok(map)
+}
+
+fn parse_almanac(input: &str) -> aoc_parse::Result<Almanac> {
+    let [seeds, maps @ ..] = aoc_parse::split_sections::<8>(input)?;
+    let [mut line] = seeds.split_lines()?;
+    line.field("seeds")?;
+    let seeds = line.ints(" ")?;
+    if seeds.len() % 2 != 0 {
+        return Err(line.error("expected seeds in pairs of a start and a length"));
+    }
+    line.end()?;
+
+    let mut maps = maps
+        .into_iter()
+        .zip(MAPS)
+        .map(|(section, name)| parse_map(section, name));
+    let mut next_map = || maps.next().unwrap();
+    Ok(Almanac {
+        seeds,
+        seed_to_soil: next_map()?,
+        soil_to_fertilizer: next_map()?,
+        fertilizer_to_water: next_map()?,
+        water_to_light: next_map()?,
+        light_to_temperature: next_map()?,
+        temperature_to_humidity: next_map()?,
+        humidity_to_location: next_map()?,
+    })
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        parse_almanac(input)
+    }
+
+    fn part_b(almanac: &Self::Input) -> Option<String> {
+        let Almanac {
+            seeds,
+            seed_to_soil,
+            soil_to_fertilizer,
+            fertilizer_to_water,
+            water_to_light,
+            light_to_temperature,
+            temperature_to_humidity,
+            humidity_to_location,
+        } = almanac;
+        let seeds: IntervalSet<usize> = seeds
+            .chunks(2)
+            .map(|pair| pair[0]..pair[0] + pair[1])
+            .collect();
+        let locations = [
+            seed_to_soil,
+            soil_to_fertilizer,
+            fertilizer_to_water,
+            water_to_light,
+            light_to_temperature,
+            temperature_to_humidity,
+            humidity_to_location,
+        ]
+        .into_iter()
+        .fold(seeds, |ranges, map| map.image(&ranges));
+        Some(locations.min().unwrap().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overlapping_map() {
+        let sample = include_str!("../../sample.txt");
+        assert_eq!(Day05::part_b(&Day05::parse(sample)), Some("46".to_owned()));
+        // Line 4 now maps 90..100, which line 5, mapping 50..98, runs into.
+        let overlapping = sample.replace("50 98 2", "50 90 10");
+        let err = Day05::try_parse(&overlapping).err().unwrap();
+        assert_eq!((err.line, err.col), (5, 1));
+        assert_eq!(err.msg, "overlaps a source range mapped above");
+    }
+}
@@ -0,0 +1,62 @@
+use aoc::Solution;
+
+#[derive(Debug)]
+pub struct Race {
+    time: usize,
+    distance: usize,
+}
+
+impl Race {
+    fn can_win(&self, button_time: usize) -> bool {
+        assert!(button_time <= self.time);
+        self.distance < button_time * (self.time - button_time)
+    }
+
+    fn ways_to_win(&self) -> usize {
+        let mut total = 0;
+        for button_time in 1..self.time {
+            if self.can_win(button_time) {
+                total += 1;
+            }
+        }
+        total
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Race>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let [section] = aoc_parse::split_sections(input)?;
+        let [mut time_line, mut distance_line] = section.split_lines()?;
+        time_line.field("Time")?;
+        let times = time_line.ints(" ")?;
+        time_line.end()?;
+        distance_line.field("Distance")?;
+        let start = distance_line;
+        let distances = distance_line.ints(" ")?;
+        distance_line.end()?;
+        if times.len() != distances.len() {
+            return Err(start.error(format!(
+                "expected {} distances, found {}",
+                times.len(),
+                distances.len()
+            )));
+        }
+        Ok(times
+            .into_iter()
+            .zip(distances)
+            .map(|(time, distance)| Race { time, distance })
+            .collect())
+    }
+
+    fn part_a(races: &Self::Input) -> Option<String> {
+        let mut combo_product = 1;
+        for race in races {
+            combo_product *= race.ways_to_win();
+        }
+        Some(combo_product.to_string())
+    }
+}
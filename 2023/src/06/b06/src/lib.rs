@@ -0,0 +1,60 @@
+use aoc::Solution;
+
+#[derive(Debug)]
+pub struct Race {
+    time: usize,
+    distance: usize,
+}
+
+impl Race {
+    fn can_win(&self, button_time: usize) -> bool {
+        assert!(button_time <= self.time);
+        self.distance < button_time * (self.time - button_time)
+    }
+
+    fn ways_to_win(&self) -> usize {
+        let mut total = 0;
+        for button_time in 1..self.time {
+            if self.can_win(button_time) {
+                total += 1;
+            }
+        }
+        total
+    }
+}
+
+/// Reads the rest of the line as one number, ignoring the spaces between
+/// its digits.
+fn parse_kerned(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<usize> {
+    let start = *line;
+    let mut total: usize = 0;
+    while !line.is_empty() {
+        let digit = line.digit()? as usize;
+        total = total
+            .checked_mul(10)
+            .and_then(|total| total.checked_add(digit))
+            .ok_or_else(|| start.error("number is too big"))?;
+        line.skip_spaces();
+    }
+    Ok(total)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Race;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let [section] = aoc_parse::split_sections(input)?;
+        let [mut time_line, mut distance_line] = section.split_lines()?;
+        time_line.field("Time")?;
+        let time = parse_kerned(&mut time_line)?;
+        distance_line.field("Distance")?;
+        let distance = parse_kerned(&mut distance_line)?;
+        Ok(Race { time, distance })
+    }
+
+    fn part_b(race: &Self::Input) -> Option<String> {
+        Some(race.ways_to_win().to_string())
+    }
+}
@@ -0,0 +1,187 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use aoc::Solution;
+
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq)]
+pub enum Card {
+    Ace = 14,
+    King = 13,
+    Queen = 12,
+    Ten = 10,
+    Nine = 9,
+    Eight = 8,
+    Seven = 7,
+    Six = 6,
+    Five = 5,
+    Four = 4,
+    Three = 3,
+    Two = 2,
+    Joker = 0,
+}
+
+impl Card {
+    fn rank(&self) -> usize {
+        *self as usize
+    }
+}
+
+fn parse_card(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Card> {
+    line.one_of(&[
+        ("A", Card::Ace),
+        ("K", Card::King),
+        ("Q", Card::Queen),
+        ("J", Card::Joker),
+        ("T", Card::Ten),
+        ("9", Card::Nine),
+        ("8", Card::Eight),
+        ("7", Card::Seven),
+        ("6", Card::Six),
+        ("5", Card::Five),
+        ("4", Card::Four),
+        ("3", Card::Three),
+        ("2", Card::Two),
+    ])
+}
+
+fn parse_hand(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Hand> {
+    let mut cards = [Card::Ace; 5];
+    for card in &mut cards {
+        *card = parse_card(line)?;
+    }
+    line.literal(" ")?;
+    line.skip_spaces();
+    let bid = line.int()?;
+    Ok(Hand { cards, bid })
+}
+
+impl PartialOrd for Card {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.rank().partial_cmp(&other.rank())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq)]
+enum HandType {
+    FiveOfAKind = 7,
+    FourOfAKind = 6,
+    FullHouse = 5,
+    ThreeOfAKind = 4,
+    TwoPair = 3,
+    OnePair = 2,
+    HighCard = 1,
+}
+
+impl HandType {
+    fn ranking(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl PartialOrd for HandType {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.ranking().partial_cmp(&other.ranking())
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, Ord, PartialEq)]
+pub struct Hand {
+    cards: [Card; 5],
+    bid: usize,
+}
+
+fn hand_type(cards: &[Card; 5]) -> HandType {
+    let mut counter: HashMap<Card, usize> = HashMap::new();
+    let mut num_jokers = 0;
+    for &card in cards {
+        *counter.entry(card).or_insert(0) += 1;
+        if card == Card::Joker {
+            num_jokers += 1;
+        }
+    }
+
+    // Sort highest count first
+    let mut card_counts: Vec<(Card, usize)> = counter.into_iter().collect();
+    card_counts
+        .sort_by(|(card1, count1), (card2, count2)| count2.cmp(&count1).then(card2.cmp(&card1)));
+
+    let mut num_three_of_a_kind = 0;
+    let mut num_two_of_a_kind = 0;
+    for (card, count) in card_counts {
+        // Ignore Jokers unless the whole hand is Jokers
+        if card == Card::Joker {
+            if count == 5 {
+                return HandType::FiveOfAKind;
+            } else {
+                continue;
+            }
+        }
+        assert!(count + num_jokers <= 5);
+        match count + num_jokers {
+            5 => {
+                return HandType::FiveOfAKind;
+            }
+            4 => {
+                return HandType::FourOfAKind;
+            }
+            3 => {
+                num_jokers -= 3 - count;
+                num_three_of_a_kind += 1;
+            }
+            2 => {
+                num_jokers -= 2 - count;
+                num_two_of_a_kind += 1;
+            }
+            _ => {}
+        };
+    }
+    if num_three_of_a_kind == 1 && num_two_of_a_kind == 1 {
+        HandType::FullHouse
+    } else if num_three_of_a_kind == 1 {
+        HandType::ThreeOfAKind
+    } else if num_two_of_a_kind == 2 {
+        HandType::TwoPair
+    } else if num_two_of_a_kind == 1 {
+        HandType::OnePair
+    } else {
+        HandType::HighCard
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        let hand_type_ordering = hand_type(&self.cards).cmp(&hand_type(&other.cards));
+        if hand_type_ordering != Ordering::Equal {
+            return Some(hand_type_ordering);
+        }
+        for i in 0..5 {
+            let card_ordering = self.cards[i].cmp(&other.cards[i]);
+            if card_ordering != Ordering::Equal {
+                return Some(card_ordering);
+            }
+        }
+        self.bid.partial_cmp(&other.bid)
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Hand>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, parse_hand)
+    }
+
+    fn part_b(hands: &Self::Input) -> Option<String> {
+        let mut hands = hands.clone();
+        hands.sort();
+
+        let mut score = 0;
+        for (i, hand) in hands.iter().enumerate() {
+            let rank = i + 1;
+            let hand_score = rank * hand.bid;
+            score += hand_score;
+        }
+        Some(score.to_string())
+    }
+}
@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use aoc::Solution;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+struct Node {
+    id: String,
+    left: String,
+    right: String,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Turn {
+    Left,
+    Right,
+}
+
+pub struct Network {
+    directions: Vec<Turn>,
+    nodes: HashMap<String, Node>,
+}
+
+fn parse_network(input: &str) -> aoc_parse::Result<Network> {
+    let [directions, nodes] = aoc_parse::split_sections(input)?;
+    let [mut line] = directions.split_lines()?;
+    let mut directions = vec![];
+    while !line.is_empty() {
+        directions.push(line.one_of(&[("L", Turn::Left), ("R", Turn::Right)])?);
+    }
+
+    let mut node_map = HashMap::new();
+    let mut links = vec![];
+    for mut line in nodes.lines() {
+        let id = line.word()?.to_owned();
+        line.literal(" = (")?;
+        links.push(line);
+        let left = line.word()?.to_owned();
+        line.literal(", ")?;
+        links.push(line);
+        let right = line.word()?.to_owned();
+        line.literal(")")?;
+        line.end()?;
+        node_map.insert(id.clone(), Node { id, left, right });
+    }
+    for mut link in links {
+        let name = link.word()?;
+        if !node_map.contains_key(name) {
+            return Err(link.error(format!("there's no node `{name}`")));
+        }
+    }
+    Ok(Network {
+        directions,
+        nodes: node_map,
+    })
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Network;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        parse_network(input)
+    }
+
+    fn part_a(network: &Self::Input) -> Option<String> {
+        let Network { directions, nodes } = network;
+
+        let mut node = nodes.get("AAA")?;
+        let mut num_steps = 0;
+        while node.id != "ZZZ" {
+            let dir = directions[num_steps % directions.len()];
+            node = match dir {
+                Turn::Left => &nodes[&node.left],
+                Turn::Right => &nodes[&node.right],
+            };
+            num_steps += 1;
+        }
+
+        Some(num_steps.to_string())
+    }
+}
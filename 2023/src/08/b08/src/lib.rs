@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+
+use aoc::Solution;
+use aoc_num::Congruence;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+struct Node {
+    id: String,
+    left: String,
+    right: String,
+}
+
+/// Where one ghost's walk goes. The ghost's state is its node and how far
+/// through the directions it is, so it's back where it was after at most
+/// `nodes * directions` steps, and loops from then on.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Walk {
+    /// The step the loop starts at.
+    pre_period: usize,
+    /// How many steps the loop takes.
+    cycle: usize,
+    /// Every step up to the end of the first time round the loop that lands
+    /// on a `..Z` node, in order.
+    hits: Vec<usize>,
+}
+
+impl Walk {
+    fn new(start: &Node, node_map: &HashMap<String, Node>, directions: &[Turn]) -> Self {
+        let mut seen = HashMap::new();
+        let mut hits = vec![];
+        let mut node = start;
+        let mut num_steps = 0;
+        loop {
+            let dir_index = num_steps % directions.len();
+            if let Some(&first) = seen.get(&(node.id.as_str(), dir_index)) {
+                return Walk {
+                    pre_period: first,
+                    cycle: num_steps - first,
+                    hits,
+                };
+            }
+            seen.insert((node.id.as_str(), dir_index), num_steps);
+            if node.id.ends_with('Z') {
+                hits.push(num_steps);
+            }
+
+            let dir = directions[dir_index];
+            node = match dir {
+                Turn::Left => &node_map[&node.left],
+                Turn::Right => &node_map[&node.right],
+            };
+            num_steps += 1;
+        }
+    }
+
+    /// Whether the ghost is on a `..Z` node after `num_steps` steps.
+    fn arrives_at(&self, num_steps: usize) -> bool {
+        let num_steps = if num_steps < self.pre_period {
+            num_steps
+        } else {
+            self.pre_period + (num_steps - self.pre_period) % self.cycle
+        };
+        self.hits.binary_search(&num_steps).is_ok()
+    }
+
+    /// The steps that land on a `..Z` node every time round the loop.
+    fn looping_hits(&self) -> impl Iterator<Item = Congruence> + '_ {
+        self.hits
+            .iter()
+            .filter(|&&hit| hit >= self.pre_period)
+            .map(|&hit| Congruence::new(hit as i128, self.cycle as i128))
+    }
+}
+
+/// The first step at which every ghost is on a `..Z` node at once, if they
+/// ever are.
+fn first_arrival(walks: &[Walk]) -> Option<usize> {
+    // Until every ghost is in its loop, just try each step.
+    let settled = walks.iter().map(|walk| walk.pre_period).max()?;
+    if let Some(num_steps) =
+        (0..settled).find(|&num_steps| walks.iter().all(|walk| walk.arrives_at(num_steps)))
+    {
+        return Some(num_steps);
+    }
+
+    // After that, each ghost arrives on one of a few residues modulo its
+    // loop, and every way of picking one residue per ghost is a CRT problem.
+    let mut arrivals = vec![Congruence::new(0, 1)];
+    for walk in walks {
+        arrivals = arrivals
+            .iter()
+            .flat_map(|&arrival| walk.looping_hits().filter_map(move |hit| arrival.meet(hit)))
+            .collect();
+    }
+    arrivals
+        .iter()
+        .map(|arrival| arrival.first_from(settled as i128) as usize)
+        .min()
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Turn {
+    Left,
+    Right,
+}
+
+pub struct Network {
+    directions: Vec<Turn>,
+    nodes: HashMap<String, Node>,
+}
+
+fn parse_network(input: &str) -> aoc_parse::Result<Network> {
+    let [directions, nodes] = aoc_parse::split_sections(input)?;
+    let [mut line] = directions.split_lines()?;
+    let mut directions = vec![];
+    while !line.is_empty() {
+        directions.push(line.one_of(&[("L", Turn::Left), ("R", Turn::Right)])?);
+    }
+
+    let mut node_map = HashMap::new();
+    let mut links = vec![];
+    for mut line in nodes.lines() {
+        let id = line.word()?.to_owned();
+        line.literal(" = (")?;
+        links.push(line);
+        let left = line.word()?.to_owned();
+        line.literal(", ")?;
+        links.push(line);
+        let right = line.word()?.to_owned();
+        line.literal(")")?;
+        line.end()?;
+        node_map.insert(id.clone(), Node { id, left, right });
+    }
+    for mut link in links {
+        let name = link.word()?;
+        if !node_map.contains_key(name) {
+            return Err(link.error(format!("there's no node `{name}`")));
+        }
+    }
+    Ok(Network {
+        directions,
+        nodes: node_map,
+    })
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Network;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        parse_network(input)
+    }
+
+    fn part_b(network: &Self::Input) -> Option<String> {
+        let Network {
+            directions,
+            nodes: node_map,
+        } = network;
+
+        let walks: Vec<Walk> = node_map
+            .values()
+            .filter(|node| node.id.ends_with('A'))
+            .map(|start| Walk::new(start, &node_map, directions))
+            .collect();
+        let num_steps = first_arrival(&walks).expect("The ghosts never all arrive at once");
+        Some(num_steps.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample() {
+        let network = Day08::parse(include_str!("../../sample3.txt"));
+        let walk = Walk::new(&network.nodes["22A"], &network.nodes, &network.directions);
+        assert_eq!(
+            walk,
+            Walk {
+                pre_period: 1,
+                cycle: 6,
+                hits: vec![3, 6]
+            }
+        );
+        assert_eq!(Day08::part_b(&network), Some("6".to_string()));
+    }
+
+    #[test]
+    fn test_offset_loops() {
+        // 11A lands on 11Z every other step. 22A takes a while to reach its
+        // loop of three, and lands on 22Z at step 5, 8, 11 and so on, so the
+        // LCM of the first arrivals, 10, is wrong.
+        let input = "L
+
+11A = (11B, XXX)
+11B = (11Z, XXX)
+11Z = (11B, XXX)
+22A = (22P, XXX)
+22P = (22Q, XXX)
+22Q = (22R, XXX)
+22R = (22B, XXX)
+22B = (22Z, XXX)
+22Z = (22C, XXX)
+22C = (22B, XXX)
+XXX = (XXX, XXX)";
+        assert_eq!(Day08::part_b(&Day08::parse(input)), Some("8".to_string()));
+
+        // Loops of 4 and 6 that land on 1 and 2 modulo them never meet.
+        let walks = [
+            Walk {
+                pre_period: 0,
+                cycle: 4,
+                hits: vec![1],
+            },
+            Walk {
+                pre_period: 0,
+                cycle: 6,
+                hits: vec![2],
+            },
+        ];
+        assert_eq!(first_arrival(&walks), None);
+    }
+}
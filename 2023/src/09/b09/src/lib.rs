@@ -0,0 +1,36 @@
+use aoc::Solution;
+
+fn difference_seq(seq: &[i64]) -> Vec<i64> {
+    let mut diff_seq = vec![];
+    for i in 1..seq.len() {
+        diff_seq.push(seq[i] - seq[i - 1]);
+    }
+    diff_seq
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<i64>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, |line| line.ints(" "))
+    }
+
+    fn part_b(sequences: &Self::Input) -> Option<String> {
+        let mut extra_sum = 0;
+        for seq in sequences {
+            let mut seq_stack = vec![seq.clone()];
+            while seq_stack.last().unwrap().iter().any(|&n| n != 0) {
+                seq_stack.push(difference_seq(seq_stack.last().unwrap()));
+            }
+
+            let mut first_val_now = 0;
+            for i in (0..(seq_stack.len() - 1)).rev() {
+                first_val_now = *seq_stack[i].first().unwrap() - first_val_now;
+            }
+            extra_sum += first_val_now;
+        }
+        Some(extra_sum.to_string())
+    }
+}
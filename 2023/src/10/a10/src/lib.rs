@@ -0,0 +1,232 @@
+use std::fmt;
+
+use aoc::Solution;
+use aoc_grid::{Dir, Grid, Pos};
+
+/// The two ways out of a pipe, or `None` for ground.
+fn exits(pipe: u8) -> Option<[Dir; 2]> {
+    match pipe {
+        b'|' => Some([Dir::N, Dir::S]),
+        b'-' => Some([Dir::E, Dir::W]),
+        b'L' => Some([Dir::N, Dir::E]),
+        b'J' => Some([Dir::N, Dir::W]),
+        b'7' => Some([Dir::S, Dir::W]),
+        b'F' => Some([Dir::E, Dir::S]),
+        _ => None,
+    }
+}
+
+/// An error pointing at a tile of the map.
+fn tile_error((x, y): Pos, msg: impl Into<String>) -> aoc::ParseError {
+    aoc::ParseError::new(y + 1, x + 1, msg)
+}
+
+pub fn get_start_pos(map: &Grid) -> aoc_parse::Result<Pos> {
+    map.find(&b'S')
+        .ok_or_else(|| aoc::ParseError::new(1, 1, "expected an `S` somewhere in the map"))
+}
+
+/// The pipe under the animal, going by which of its neighbours lead back
+/// to it.
+pub fn get_start_shape(map: &Grid, start_pos: Pos) -> aoc_parse::Result<u8> {
+    let connected: Vec<Dir> = Dir::ALL
+        .into_iter()
+        .filter(|&dir| {
+            map.step(start_pos, dir)
+                .and_then(|pos| exits(map[pos]))
+                .is_some_and(|exits| exits.contains(&dir.reverse()))
+        })
+        .collect();
+    b"|-LJ7F"
+        .iter()
+        .copied()
+        .find(|&pipe| {
+            exits(pipe)
+                .unwrap()
+                .iter()
+                .all(|dir| connected.contains(dir))
+        })
+        .ok_or_else(|| {
+            tile_error(
+                start_pos,
+                format!("expected two pipes to lead here, found {connected:?}"),
+            )
+        })
+}
+
+/// The map along with the main loop through it.
+pub struct Pipes {
+    /// The map, with the pipe under the start filled in.
+    map: Grid,
+    /// The tiles of the main loop, in order from the start.
+    path: Vec<Pos>,
+}
+
+impl Pipes {
+    pub fn new(mut map: Grid) -> aoc_parse::Result<Self> {
+        let start = get_start_pos(&map)?;
+        map[start] = get_start_shape(&map, start)?;
+
+        let mut path = vec![];
+        let mut pos = start;
+        let mut dir = exits(map[start]).unwrap()[0];
+        loop {
+            path.push(pos);
+            pos = map
+                .step(pos, dir)
+                .ok_or_else(|| tile_error(pos, "the loop runs off the map"))?;
+            if pos == start {
+                break;
+            }
+            dir = match exits(map[pos]) {
+                Some([a, b]) if a == dir.reverse() => b,
+                Some([a, b]) if b == dir.reverse() => a,
+                _ => return Err(tile_error(pos, "the loop breaks off here")),
+            };
+        }
+        Ok(Self { map, path })
+    }
+
+    /// The tiles of the main loop, in order from the start.
+    pub fn path(&self) -> &[Pos] {
+        &self.path
+    }
+
+    /// How many steps along the loop the tile farthest from the start is.
+    pub fn farthest(&self) -> usize {
+        self.path.len() / 2
+    }
+
+    /// Counts the tiles inside the loop from its area. The shoelace formula
+    /// gives the area of the polygon through the centres of the loop's
+    /// tiles, and Pick's theorem, `A = i + b/2 - 1`, says how many whole
+    /// tiles `i` that leaves inside once the `b` tiles of the loop itself
+    /// are taken out.
+    pub fn enclosed_by_area(&self) -> usize {
+        let twice_area = self
+            .path
+            .iter()
+            .zip(self.path.iter().cycle().skip(1))
+            .map(|(&(x1, y1), &(x2, y2))| (x1 * y2) as isize - (x2 * y1) as isize)
+            .sum::<isize>()
+            .unsigned_abs();
+        (twice_area - self.path.len()) / 2 + 1
+    }
+
+    /// Which tiles are inside the loop. Scanning along a row, each pipe of
+    /// the loop that leads north takes the scan across the loop, in or out.
+    /// A `-` doesn't, and of an `L7` or `LJ` only the `L` and `J` count, so
+    /// a bend that turns back is crossed twice or not at all.
+    fn inside(&self) -> Grid<bool> {
+        let mut on_loop = Grid::new(self.map.width(), self.map.height(), false);
+        for &pos in &self.path {
+            on_loop[pos] = true;
+        }
+        let mut inside = Grid::new(self.map.width(), self.map.height(), false);
+        for y in 0..self.map.height() {
+            let mut crossings = 0;
+            for x in 0..self.map.width() {
+                if on_loop[(x, y)] {
+                    if exits(self.map[(x, y)]).unwrap().contains(&Dir::N) {
+                        crossings += 1;
+                    }
+                } else {
+                    inside[(x, y)] = crossings % 2 == 1;
+                }
+            }
+        }
+        inside
+    }
+
+    /// Counts the tiles inside the loop by scanning each row, as a check on
+    /// [`Pipes::enclosed_by_area`].
+    pub fn enclosed_by_parity(&self) -> usize {
+        self.inside().iter().filter(|&(_, &inside)| inside).count()
+    }
+}
+
+/// Draws the loop with box-drawing characters, the tiles inside it as `I`,
+/// and everything else as `.`.
+impl fmt::Display for Pipes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut picture = self.inside().map(|&inside| if inside { 'I' } else { '.' });
+        for &pos in &self.path {
+            picture[pos] = match self.map[pos] {
+                b'|' => '│',
+                b'-' => '─',
+                b'L' => '└',
+                b'J' => '┘',
+                b'7' => '┐',
+                b'F' => '┌',
+                pipe => pipe as char,
+            };
+        }
+        for row in picture.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Pipes;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        Pipes::new(Grid::parse(input)?)
+    }
+
+    fn part_a(pipes: &Self::Input) -> Option<String> {
+        Some(pipes.farthest().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start() {
+        let map = Grid::parse(include_str!("../../sample1.txt")).unwrap();
+        let start = get_start_pos(&map).unwrap();
+        assert_eq!(start, (0, 2));
+        assert_eq!(get_start_shape(&map, start), Ok(b'F'));
+        assert_eq!(
+            Day10::parse(include_str!("../../sample1.txt")).farthest(),
+            8
+        );
+    }
+
+    #[test]
+    fn test_enclosed() {
+        for (sample, enclosed) in [
+            (include_str!("../../sample2.txt"), 4),
+            (include_str!("../../sample3.txt"), 8),
+            (include_str!("../../sample4.txt"), 10),
+        ] {
+            let pipes = Day10::parse(sample);
+            assert_eq!(pipes.enclosed_by_area(), enclosed);
+            assert_eq!(pipes.enclosed_by_parity(), enclosed);
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let pipes = Day10::parse(include_str!("../../sample2.txt"));
+        assert_eq!(
+            pipes.to_string(),
+            "\
+...........
+.┌───────┐.
+.│┌─────┐│.
+.││.....││.
+.││.....││.
+.│└─┐.┌─┘│.
+.│II│.│II│.
+.└──┘.└──┘.
+...........
+"
+        );
+    }
+}
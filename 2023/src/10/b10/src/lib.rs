@@ -0,0 +1,22 @@
+use aoc::Solution;
+use aoc2023_a10::Pipes;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Pipes;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc2023_a10::Day10::try_parse(input)
+    }
+
+    fn part_b(pipes: &Self::Input) -> Option<String> {
+        let enclosed = pipes.enclosed_by_area();
+        assert_eq!(
+            enclosed,
+            pipes.enclosed_by_parity(),
+            "Counting by area and by scanning disagree about this loop:\n{pipes}"
+        );
+        Some(enclosed.to_string())
+    }
+}
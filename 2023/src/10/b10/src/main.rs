@@ -0,0 +1,3 @@
+fn main() {
+    aoc::run::<aoc2023_b10::Day10>(&aoc::read_stdin());
+}
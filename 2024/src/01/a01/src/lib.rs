@@ -0,0 +1,27 @@
+use aoc::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<(i32, i32)>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, |line| {
+            let left = line.int()?;
+            line.skip_spaces();
+            Ok((left, line.int()?))
+        })
+    }
+
+    fn part_a(pairs: &Self::Input) -> Option<String> {
+        let (mut left, mut right): (Vec<i32>, Vec<i32>) = pairs.iter().copied().unzip();
+        left.sort();
+        right.sort();
+
+        let mut total_distance = 0;
+        for (l, r) in left.iter().zip(right.iter()) {
+            total_distance += (r - l).abs();
+        }
+        Some(total_distance.to_string())
+    }
+}
@@ -0,0 +1,34 @@
+use std::collections::HashMap;
+
+use aoc::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<(i32, i32)>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, |line| {
+            let left = line.int()?;
+            line.skip_spaces();
+            Ok((left, line.int()?))
+        })
+    }
+
+    fn part_b(pairs: &Self::Input) -> Option<String> {
+        let mut left = HashMap::<i32, i32>::new();
+        let mut right = HashMap::<i32, i32>::new();
+        for &(l, r) in pairs {
+            *left.entry(l).or_default() += 1;
+            *right.entry(r).or_default() += 1;
+        }
+
+        let mut score = 0;
+        for (l, num_l) in left {
+            let num_r = right.get(&l).unwrap_or(&0);
+            score += l * num_l * num_r;
+        }
+
+        Some(score.to_string())
+    }
+}
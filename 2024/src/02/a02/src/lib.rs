@@ -0,0 +1,37 @@
+use aoc::Solution;
+
+fn is_safe(levels: &Vec<i32>) -> bool {
+    for i in 1..levels.len() {
+        match (levels[i] - levels[i - 1]).abs() {
+            1..=3 => {}
+            _ => return false,
+        }
+
+        // Levels must be all increasing or all decreasing
+        if (levels[i] - levels[i - 1]) * (levels[1] - levels[0]) < 0 {
+            return false;
+        }
+    }
+    true
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<i32>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, |line| line.ints(" "))
+    }
+
+    fn part_a(reports: &Self::Input) -> Option<String> {
+        let mut num_safe = 0;
+        for levels in reports {
+            if is_safe(&levels) {
+                num_safe += 1;
+            }
+        }
+
+        Some(num_safe.to_string())
+    }
+}
@@ -0,0 +1,60 @@
+use aoc::Solution;
+use aoc_parse::Cursor;
+
+/// Reads a `mul` operand, which is one to three digits.
+fn parse_operand(cursor: &mut Cursor) -> aoc_parse::Result<i32> {
+    let mut operand = i32::from(cursor.digit()?);
+    for _ in 0..2 {
+        let mut next = *cursor;
+        match next.digit() {
+            Ok(digit) => operand = operand * 10 + i32::from(digit),
+            Err(_) => break,
+        }
+        *cursor = next;
+    }
+    Ok(operand)
+}
+
+fn parse_mul(cursor: &mut Cursor) -> aoc_parse::Result<(i32, i32)> {
+    cursor.literal("mul(")?;
+    let lhs = parse_operand(cursor)?;
+    cursor.literal(",")?;
+    let rhs = parse_operand(cursor)?;
+    cursor.literal(")")?;
+    Ok((lhs, rhs))
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<(i32, i32)>;
+
+    /// Picks the `mul` instructions out of the corrupted memory, skipping
+    /// everything else.
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let mut muls = vec![];
+        for mut line in aoc_parse::lines(input) {
+            while !line.is_empty() {
+                let mut attempt = line;
+                match parse_mul(&mut attempt) {
+                    Ok(mul) => {
+                        muls.push(mul);
+                        line = attempt;
+                    }
+                    Err(_) => {
+                        line.char()?;
+                    }
+                }
+            }
+        }
+        Ok(muls)
+    }
+
+    fn part_a(muls: &Self::Input) -> Option<String> {
+        let mut total = 0;
+        for (lhs, rhs) in muls {
+            total += lhs * rhs;
+        }
+        Some(total.to_string())
+    }
+}
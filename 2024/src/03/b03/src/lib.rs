@@ -0,0 +1,84 @@
+use aoc::Solution;
+use aoc_parse::Cursor;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Instruction {
+    Mul(i32, i32),
+    Do,
+    Dont,
+}
+
+/// Reads a `mul` operand, which is one to three digits.
+fn parse_operand(cursor: &mut Cursor) -> aoc_parse::Result<i32> {
+    let mut operand = i32::from(cursor.digit()?);
+    for _ in 0..2 {
+        let mut next = *cursor;
+        match next.digit() {
+            Ok(digit) => operand = operand * 10 + i32::from(digit),
+            Err(_) => break,
+        }
+        *cursor = next;
+    }
+    Ok(operand)
+}
+
+fn parse_mul(cursor: &mut Cursor) -> aoc_parse::Result<(i32, i32)> {
+    cursor.literal("mul(")?;
+    let lhs = parse_operand(cursor)?;
+    cursor.literal(",")?;
+    let rhs = parse_operand(cursor)?;
+    cursor.literal(")")?;
+    Ok((lhs, rhs))
+}
+
+fn parse_instruction(cursor: &mut Cursor) -> aoc_parse::Result<Instruction> {
+    if cursor.eat("do()") {
+        Ok(Instruction::Do)
+    } else if cursor.eat("don't()") {
+        Ok(Instruction::Dont)
+    } else {
+        let (lhs, rhs) = parse_mul(cursor)?;
+        Ok(Instruction::Mul(lhs, rhs))
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Instruction>;
+
+    /// Picks the instructions out of the corrupted memory, skipping
+    /// everything else.
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let mut instructions = vec![];
+        for mut line in aoc_parse::lines(input) {
+            while !line.is_empty() {
+                let mut attempt = line;
+                match parse_instruction(&mut attempt) {
+                    Ok(instruction) => {
+                        instructions.push(instruction);
+                        line = attempt;
+                    }
+                    Err(_) => {
+                        line.char()?;
+                    }
+                }
+            }
+        }
+        Ok(instructions)
+    }
+
+    fn part_b(instructions: &Self::Input) -> Option<String> {
+        let mut enabled = true;
+        let mut total = 0;
+        for instruction in instructions {
+            match *instruction {
+                Instruction::Mul(lhs, rhs) if enabled => total += lhs * rhs,
+                Instruction::Mul(..) => {}
+                Instruction::Do => enabled = true,
+                Instruction::Dont => enabled = false,
+            }
+        }
+        Some(total.to_string())
+    }
+}
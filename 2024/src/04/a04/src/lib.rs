@@ -0,0 +1,38 @@
+use aoc::Solution;
+use aoc_grid::{Dir8, Grid, Pos};
+
+const XMAS: &[u8] = b"XMAS";
+
+fn all_xmas(grid: &Grid, start: Pos) -> usize {
+    Dir8::ALL
+        .into_iter()
+        .filter(|dir| {
+            let mut pos = Some(start);
+            XMAS.iter().all(|&c| match pos {
+                Some(p) if grid[p] == c => {
+                    pos = grid.offset(p, dir.delta());
+                    true
+                }
+                _ => false,
+            })
+        })
+        .count()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part_a(grid: &Self::Input) -> Option<String> {
+        let mut count = 0;
+        for pos in grid.find_all(&b'X') {
+            count += all_xmas(grid, pos);
+        }
+        Some(count.to_string())
+    }
+}
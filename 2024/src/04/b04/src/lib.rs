@@ -0,0 +1,38 @@
+use aoc::Solution;
+use aoc_grid::Grid;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part_b(grid: &Self::Input) -> Option<String> {
+        let mut count = 0;
+        for y in 1..grid.height() - 1 {
+            for x in 1..grid.width() - 1 {
+                if grid[(x, y)] != b'A' {
+                    continue;
+                }
+
+                if !((grid[(x - 1, y - 1)] == b'M' && grid[(x + 1, y + 1)] == b'S')
+                    || (grid[(x - 1, y - 1)] == b'S' && grid[(x + 1, y + 1)] == b'M'))
+                {
+                    continue;
+                }
+
+                if !((grid[(x - 1, y + 1)] == b'M' && grid[(x + 1, y - 1)] == b'S')
+                    || (grid[(x - 1, y + 1)] == b'S' && grid[(x + 1, y - 1)] == b'M'))
+                {
+                    continue;
+                }
+
+                count += 1;
+            }
+        }
+        Some(count.to_string())
+    }
+}
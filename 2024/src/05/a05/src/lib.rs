@@ -0,0 +1,54 @@
+use aoc::Solution;
+
+fn satisfies_rules(update: &Vec<i32>, rules: &Vec<(i32, i32)>) -> bool {
+    for (left, right) in rules {
+        let left_idx = update.iter().position(|x| x == left);
+        if let Some(left_idx) = left_idx {
+            let right_idx = update.iter().position(|x| x == right);
+            if let Some(right_idx) = right_idx {
+                if right_idx < left_idx {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+pub struct Manual {
+    rules: Vec<(i32, i32)>,
+    updates: Vec<Vec<i32>>,
+}
+
+fn parse_manual(input: &str) -> aoc_parse::Result<Manual> {
+    let [rules, updates] = aoc_parse::split_sections(input)?;
+    let rules = rules.parse_lines(|line| {
+        let left = line.int()?;
+        line.literal("|")?;
+        Ok((left, line.int()?))
+    })?;
+    let updates = updates.parse_lines(|line| line.ints(","))?;
+    Ok(Manual { rules, updates })
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Manual;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        parse_manual(input)
+    }
+
+    fn part_a(manual: &Self::Input) -> Option<String> {
+        let Manual { rules, updates } = manual;
+
+        let mut total = 0;
+        for update in updates {
+            if satisfies_rules(update, rules) {
+                total += update[update.len() / 2];
+            }
+        }
+        Some(total.to_string())
+    }
+}
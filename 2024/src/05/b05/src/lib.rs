@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+
+use aoc::Solution;
+
+fn satisfies_rules(update: &Vec<i32>, rules: &HashMap<i32, Vec<i32>>) -> bool {
+    for (right, lefts) in rules.iter() {
+        for left in lefts {
+            let left_idx = update.iter().position(|x| x == left);
+            let right_idx = update.iter().position(|x| x == right);
+            // println!("({left} {right}) {update:?} ({left_idx:?} {right_idx:?})");
+            if let Some(left_idx) = left_idx {
+                if let Some(right_idx) = right_idx {
+                    if right_idx < left_idx {
+                        return false;
+                    }
+                }
+            }
+        }
+    }
+    true
+}
+
+pub struct Manual {
+    rules: Vec<(i32, i32)>,
+    updates: Vec<Vec<i32>>,
+}
+
+fn parse_manual(input: &str) -> aoc_parse::Result<Manual> {
+    let [rules, updates] = aoc_parse::split_sections(input)?;
+    let rules = rules.parse_lines(|line| {
+        let left = line.int()?;
+        line.literal("|")?;
+        let before = *line;
+        let right = line.int()?;
+        if left == right {
+            return Err(before.error(format!("page {left} can't come before itself")));
+        }
+        Ok((left, right))
+    })?;
+    let updates = updates.parse_lines(|line| line.ints(","))?;
+    Ok(Manual { rules, updates })
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Manual;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        parse_manual(input)
+    }
+
+    fn part_b(manual: &Self::Input) -> Option<String> {
+        let mut rules: HashMap<i32, Vec<i32>> = HashMap::new();
+        for &(left, right) in &manual.rules {
+            rules.entry(right).or_default().push(left);
+        }
+
+        let mut total = 0;
+        for update in &manual.updates {
+            if satisfies_rules(update, &rules) {
+                continue;
+            }
+
+            let sorted = aoc_dag::kahn(update.iter().copied(), |page| {
+                rules.get(page).cloned().unwrap_or_default()
+            })
+            .unwrap_or_else(|cycle| panic!("The rules contradict each other: {cycle}"));
+            total += sorted[sorted.len() / 2];
+        }
+        Some(total.to_string())
+    }
+}
@@ -0,0 +1,58 @@
+use aoc::Solution;
+use aoc_grid::{Dir, Grid, Pos};
+
+struct Lab {
+    map: Grid,
+    guard: Pos,
+    dir: Dir,
+}
+
+impl Lab {
+    fn new(map: Grid) -> Self {
+        let guard = map.find(&b'^').unwrap();
+        Self {
+            map,
+            guard,
+            dir: Dir::N,
+        }
+    }
+
+    fn next(&mut self) -> bool {
+        self.map[self.guard] = b'X';
+        let Some(new_guard) = self.map.step(self.guard, self.dir) else {
+            return false;
+        };
+        match self.map[new_guard] {
+            b'#' => {
+                self.dir = self.dir.cw();
+                self.map[self.guard] = self.dir.arrow();
+            }
+            _ => {
+                self.guard = new_guard;
+            }
+        }
+        true
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Grid;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part_a(map: &Self::Input) -> Option<String> {
+        let mut lab = Lab::new(map.clone());
+
+        loop {
+            if !lab.next() {
+                break;
+            }
+        }
+        let num_visited = lab.map.find_all(&b'X').count();
+        Some(num_visited.to_string())
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+
+use aoc::Solution;
+use aoc_grid::{Dir, Grid, Pos};
+
+#[derive(Clone, Debug)]
+struct Lab {
+    map: Grid,
+    guard: Pos,
+    dir: Dir,
+}
+
+impl Lab {
+    fn new(map: Grid) -> Self {
+        let guard = map.find(&b'^').unwrap();
+        Self {
+            map,
+            guard,
+            dir: Dir::N,
+        }
+    }
+
+    fn next_guard(&self) -> Option<Pos> {
+        self.map.step(self.guard, self.dir)
+    }
+
+    fn next(&mut self) -> bool {
+        self.map[self.guard] = b'X';
+        let Some(new_guard) = self.next_guard() else {
+            return false;
+        };
+        match self.map[new_guard] {
+            b'#' | b'O' => {
+                self.dir = self.dir.cw();
+                self.map[self.guard] = self.dir.arrow();
+            }
+            _ => {
+                self.guard = new_guard;
+            }
+        }
+        true
+    }
+}
+
+fn place_obstacle(mut lab: Lab, mut states: HashSet<(Pos, Dir)>, obstacle: Pos) -> Option<Lab> {
+    if lab.map[obstacle] != b'.' {
+        return None;
+    }
+
+    lab.map[obstacle] = b'O';
+
+    while states.insert((lab.guard, lab.dir)) {
+        if !lab.next() {
+            return None;
+        }
+    }
+    Some(lab)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Grid;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part_b(map: &Self::Input) -> Option<String> {
+        let mut lab = Lab::new(map.clone());
+
+        let mut states: HashSet<(Pos, Dir)> = HashSet::new();
+
+        let mut obstacles: Vec<(Pos, Lab)> = Vec::new();
+        loop {
+            if let Some(obstacle) = lab.next_guard() {
+                if let Some(new_lab) = place_obstacle(lab.clone(), states.clone(), obstacle) {
+                    obstacles.push((obstacle, new_lab));
+                }
+            }
+            states.insert((lab.guard, lab.dir));
+            if !lab.next() {
+                break;
+            }
+        }
+
+        Some(obstacles.len().to_string())
+    }
+}
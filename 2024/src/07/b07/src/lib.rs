@@ -0,0 +1,58 @@
+use std::collections::HashSet;
+
+use aoc::Solution;
+
+fn concat(a: i64, b: i64) -> i64 {
+    let mut concatenated = a;
+    let mut to_multiply = b;
+
+    while to_multiply > 0 {
+        concatenated *= 10;
+        to_multiply /= 10;
+    }
+
+    concatenated + b
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<(i64, Vec<i64>)>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, |line| {
+            let target = line.int()?;
+            line.literal(":")?;
+            line.skip_spaces();
+            Ok((target, line.ints(" ")?))
+        })
+    }
+
+    fn part_b(problems: &Self::Input) -> Option<String> {
+        let achievable_targets = aoc::par_map(problems, |(target, parts)| {
+            let mut last_iteration: HashSet<i64> = HashSet::new();
+            last_iteration.insert(0);
+            for &num in parts.iter() {
+                let mut next_iteration: HashSet<i64> = HashSet::new();
+                for &result in last_iteration.iter() {
+                    if result <= *target {
+                        next_iteration.insert(result + num);
+                        next_iteration.insert(result * num);
+                        next_iteration.insert(concat(result, num));
+                    }
+                }
+
+                std::mem::swap(&mut last_iteration, &mut next_iteration);
+            }
+
+            if last_iteration.contains(&target) {
+                *target
+            } else {
+                0
+            }
+        });
+
+        let total: i64 = achievable_targets.iter().sum();
+        Some(total.to_string())
+    }
+}
@@ -0,0 +1,46 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc::Solution;
+use aoc_grid::Grid;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Grid;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part_a(grid: &Self::Input) -> Option<String> {
+        let (width, height) = (grid.width(), grid.height());
+
+        let mut antennae: HashMap<u8, Vec<(i32, i32)>> = HashMap::new();
+        for ((x, y), &c) in grid.iter() {
+            if !matches!(c, b'.') {
+                antennae.entry(c).or_default().push((x as i32, y as i32));
+            }
+        }
+
+        let mut antinodes: HashSet<(i32, i32)> = HashSet::new();
+        for (_, antennae) in antennae {
+            for (i, &(x1, y1)) in antennae.iter().enumerate() {
+                for &(x2, y2) in antennae.iter().skip(i + 1) {
+                    let dx = x2 - x1;
+                    let dy = y2 - y1;
+
+                    let mut maybe_insert = |(x, y)| {
+                        if 0 <= x && x < (width as i32) && 0 <= y && y < (height as i32) {
+                            antinodes.insert((x, y));
+                        }
+                    };
+
+                    maybe_insert((x1 - dx, y1 - dy));
+                    maybe_insert((x2 + dx, y2 + dy));
+                }
+            }
+        }
+
+        Some(antinodes.len().to_string())
+    }
+}
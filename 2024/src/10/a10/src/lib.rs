@@ -0,0 +1,79 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc::Solution;
+
+fn score_trailhead(grid: &Vec<Vec<i32>>, r: usize, c: usize) -> usize {
+    let mut seen: HashSet<(usize, usize)> = HashSet::new();
+    let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
+    queue.push_back((r, c));
+
+    let get = |r: usize, c: usize| {
+        if r < grid.len() && c < grid[r].len() {
+            Some(grid[r][c])
+        } else {
+            None
+        }
+    };
+
+    let mut nines = 0;
+    while let Some((r, c)) = queue.pop_front() {
+        if !seen.insert((r, c)) {
+            continue;
+        }
+
+        let h = grid[r][c];
+
+        if h == 9 {
+            nines += 1;
+        }
+
+        if let Some(h2) = get(r + 1, c) {
+            if h2 - h == 1 {
+                queue.push_back((r + 1, c));
+            }
+        }
+        if let Some(h2) = get(r, c + 1) {
+            if h2 - h == 1 {
+                queue.push_back((r, c + 1));
+            }
+        }
+        if r > 0 {
+            if let Some(h2) = get(r - 1, c) {
+                if h2 - h == 1 {
+                    queue.push_back((r - 1, c));
+                }
+            }
+        }
+        if c > 0 {
+            if let Some(h2) = get(r, c - 1) {
+                if h2 - h == 1 {
+                    queue.push_back((r, c - 1));
+                }
+            }
+        }
+    }
+
+    nines
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Vec<i32>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_rows(input, |height| height.digit().map(i32::from))
+    }
+
+    fn part_a(grid: &Self::Input) -> Option<String> {
+        let mut scores = 0;
+        for (r, row) in grid.iter().enumerate() {
+            for (c, &height) in row.iter().enumerate() {
+                if height == 0 {
+                    scores += score_trailhead(grid, r, c);
+                }
+            }
+        }
+        Some(scores.to_string())
+    }
+}
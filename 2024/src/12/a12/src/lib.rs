@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+
+use aoc::Solution;
+use aoc_grid::Grid;
+
+type Regions = Vec<(u8, usize, usize)>;
+
+fn regions(grid: &Grid) -> Regions {
+    let (width, height) = (grid.width(), grid.height());
+
+    let get = |x: usize, y: usize| grid[(x, y)];
+
+    let mut seen: HashSet<usize> = HashSet::new();
+
+    let mut regions = Regions::new();
+    for y in 0..height {
+        for x in 0..width {
+            let offset = y * width + x;
+            if seen.contains(&offset) {
+                continue;
+            }
+
+            let label = get(x, y);
+            let mut area = 0;
+            let mut perimeter = 0;
+
+            let mut stack: Vec<(i32, i32)> = vec![(x as i32, y as i32)];
+            while let Some((x, y)) = stack.pop() {
+                let offset = y as usize * width + x as usize;
+                if !seen.insert(offset) {
+                    continue;
+                }
+
+                area += 1;
+
+                for (dx, dy) in vec![(1, 0), (0, 1), (-1, 0), (0, -1)] {
+                    let nx = x + dx;
+                    let ny = y + dy;
+                    if nx < 0
+                        || nx as usize >= width
+                        || ny < 0
+                        || ny as usize >= height
+                        || get(nx as usize, ny as usize) != label
+                    {
+                        perimeter += 1;
+                    } else {
+                        stack.push((nx, ny));
+                    }
+                }
+            }
+            regions.push((label, area, perimeter));
+        }
+    }
+
+    regions
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part_a(grid: &Self::Input) -> Option<String> {
+        let mut total_score = 0;
+        for (_, area, perimeter) in regions(grid) {
+            total_score += area * perimeter;
+        }
+
+        Some(total_score.to_string())
+    }
+}
@@ -0,0 +1,78 @@
+use aoc::Solution;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Puzzle {
+    a: (i32, i32),
+    b: (i32, i32),
+    prize: (i32, i32),
+}
+
+fn div(a: i32, b: i32) -> Option<i32> {
+    if a % b == 0 {
+        Some(a / b)
+    } else {
+        None
+    }
+}
+
+fn parse_puzzle(section: &aoc_parse::Section) -> aoc_parse::Result<Puzzle> {
+    let [mut a, mut b, mut prize] = section.split_lines()?;
+    let button = |line: &mut aoc_parse::Cursor, name: &str| {
+        line.literal(name)?;
+        line.literal(": X+")?;
+        let x = line.int()?;
+        line.literal(", Y+")?;
+        let y = line.int()?;
+        line.end()?;
+        Ok((x, y))
+    };
+    let a = button(&mut a, "Button A")?;
+    let b = button(&mut b, "Button B")?;
+    prize.literal("Prize: X=")?;
+    let x = prize.int()?;
+    prize.literal(", Y=")?;
+    let y = prize.int()?;
+    prize.end()?;
+    Ok(Puzzle {
+        a,
+        b,
+        prize: (x, y),
+    })
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Puzzle>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::sections(input)
+            .iter()
+            .map(parse_puzzle)
+            .collect()
+    }
+
+    fn part_a(puzzles: &Self::Input) -> Option<String> {
+        let costs = aoc::par_map(puzzles, |puzzle| {
+            // 94a + 22b = 8400 X
+            // 34a + 67b = 5400 Y
+
+            // b = (5400 - 34a) / 67
+            // 94a + 22(5400 - 34a)/67 = 8400
+            // 94*67a + 22*5400 - 22*34a = 8400 * 67
+            // a(94*67 - 22*34) = 8400 * 67 - 22*5400
+            // a = (8400 * 67 - (22 * 5400)) / (94*67 - 22 * 34)
+            // a = ( X   * by - (bx *  Y  )) / (ax*by - bx * ay)
+
+            let (ax, ay) = puzzle.a;
+            let (bx, by) = puzzle.b;
+            let (x, y) = puzzle.prize;
+
+            let a = div(x * by - (bx * y), ax * by - bx * ay)?;
+            let b = div(x - (ax * a), bx)?;
+            Some(3 * a + b)
+        });
+        let total_cost: i32 = costs.into_iter().flatten().sum();
+        Some(total_cost.to_string())
+    }
+}
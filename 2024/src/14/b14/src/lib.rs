@@ -0,0 +1,83 @@
+use aoc::Solution;
+
+pub type Robot = ((i32, i32), (i32, i32));
+
+const WIDTH: i32 = 101;
+const HEIGHT: i32 = 103;
+
+fn parse_robot(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Robot> {
+    line.literal("p=")?;
+    let px = line.int()?;
+    line.literal(",")?;
+    let py = line.int()?;
+    line.literal(" v=")?;
+    let vx = line.int()?;
+    line.literal(",")?;
+    let vy = line.int()?;
+    Ok(((px, py), (vx, vy)))
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, parse_robot)
+    }
+
+    fn part_b(robots: &Self::Input) -> Option<String> {
+        let mut robots = robots.clone();
+
+        let mut s = 0;
+        loop {
+            let is_done = (|| {
+                let mut quad_a = 0;
+                let mut quad_b = 0;
+                let mut quad_c = 0;
+                let mut quad_d = 0;
+
+                for &((px, py), _) in &robots {
+                    if px == (WIDTH - 1) / 2 || py == (HEIGHT - 1) / 2 {
+                        // Skip robots in the center
+                        continue;
+                    }
+
+                    let left = px < (WIDTH - 1) / 2;
+                    let top = py < (HEIGHT - 1) / 2;
+                    if top {
+                        if left {
+                            quad_a += 1;
+                        } else {
+                            quad_b += 1;
+                        }
+                    } else {
+                        if left {
+                            quad_c += 1;
+                        } else {
+                            quad_d += 1;
+                        }
+                    }
+                }
+                let threshold = robots.len() * 7 / 16;
+                for quad in [quad_a, quad_b, quad_c, quad_d] {
+                    if quad > threshold {
+                        return true;
+                    }
+                }
+                false
+            })();
+            if is_done {
+                break;
+            }
+
+            for ((px, py), (vx, vy)) in robots.iter_mut() {
+                *px = (*px + *vx).rem_euclid(WIDTH);
+                *py = (*py + *vy).rem_euclid(HEIGHT);
+            }
+
+            s += 1;
+        }
+        Some(s.to_string())
+    }
+}
@@ -0,0 +1,112 @@
+use std::ascii::escape_default;
+
+use aoc::Solution;
+use aoc_grid::{Dir, Grid, Pos};
+use aoc_parse::{Error, Section};
+
+#[derive(Clone)]
+pub struct Warehouse {
+    map: Grid,
+    bot_pos: Pos,
+}
+
+impl Warehouse {
+    /// Reads the map, which must be walled in, with one robot in it.
+    fn parse(section: &Section) -> aoc_parse::Result<Self> {
+        let map = Grid::parse_section(section)?;
+        let error = |(x, y): Pos, msg: String| Error::new(section.first_line() + y, x + 1, msg);
+        for (pos @ (x, y), &c) in map.iter() {
+            let edge = x == 0 || y == 0 || x + 1 == map.width() || y + 1 == map.height();
+            if !b"#.O@".contains(&c) {
+                return Err(error(pos, format!("unexpected `{}`", c as char)));
+            }
+            if edge && c != b'#' {
+                return Err(error(
+                    pos,
+                    "expected a wall around the warehouse".to_owned(),
+                ));
+            }
+        }
+        let robots: Vec<Pos> = map.find_all(&b'@').collect();
+        match robots[..] {
+            [bot_pos] => Ok(Self { map, bot_pos }),
+            [] => Err(section.error("expected a robot `@` in the warehouse")),
+            [_, second, ..] => Err(error(second, "expected only one robot".to_owned())),
+        }
+    }
+
+    fn move_in_dir(&mut self, src: Pos, dir: Dir) -> bool {
+        let me = self.map[src];
+        let dst = self.map.step(src, dir).unwrap();
+        let byte_at_dst = self.map[dst];
+        match byte_at_dst {
+            b'#' => false,
+            b'.' => {
+                self.map[src] = b'.';
+                self.map[dst] = me;
+                if src == self.bot_pos {
+                    self.bot_pos = dst;
+                }
+                true
+            }
+            b'O' => {
+                let could_move_boulder = self.move_in_dir(dst, dir);
+                if could_move_boulder {
+                    self.map[src] = b'.';
+                    self.map[dst] = me;
+                    if src == self.bot_pos {
+                        self.bot_pos = dst;
+                    }
+                }
+                could_move_boulder
+            }
+            _ => {
+                panic!(
+                    "Unexpected character {} found in {:?} -> {:?} -> {:?}",
+                    escape_default(byte_at_dst),
+                    src,
+                    dir,
+                    dst
+                );
+            }
+        }
+    }
+
+    fn gps(&self) -> usize {
+        self.map.find_all(&b'O').map(|(x, y)| 100 * y + x).sum()
+    }
+}
+
+fn parse_moves(section: &Section) -> aoc_parse::Result<Vec<Dir>> {
+    let mut moves = vec![];
+    for mut line in section.lines() {
+        while !line.is_empty() {
+            moves.push(line.one_of(&[
+                ("^", Dir::N),
+                (">", Dir::E),
+                ("v", Dir::S),
+                ("<", Dir::W),
+            ])?);
+        }
+    }
+    Ok(moves)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = (Warehouse, Vec<Dir>);
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let [map, moves] = aoc_parse::split_sections(input)?;
+        Ok((Warehouse::parse(&map)?, parse_moves(&moves)?))
+    }
+
+    fn part_a((warehouse, moves): &Self::Input) -> Option<String> {
+        let mut warehouse = warehouse.clone();
+        for &dir in moves {
+            warehouse.move_in_dir(warehouse.bot_pos, dir);
+        }
+        Some(warehouse.gps().to_string())
+    }
+}
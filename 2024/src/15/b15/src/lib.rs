@@ -0,0 +1,240 @@
+use std::ascii::escape_default;
+
+use aoc::Solution;
+use aoc_grid::{Dir, Grid, Pos};
+use aoc_parse::{Error, Section};
+
+pub struct Warehouse {
+    map: Grid,
+    bot_pos: Pos,
+}
+
+impl Warehouse {
+    /// Reads the map, which must be walled in, with one robot in it.
+    fn parse(section: &Section) -> aoc_parse::Result<Self> {
+        let map = Grid::parse_section(section)?;
+        let error = |(x, y): Pos, msg: String| Error::new(section.first_line() + y, x + 1, msg);
+        for (pos @ (x, y), &c) in map.iter() {
+            let edge = x == 0 || y == 0 || x + 1 == map.width() || y + 1 == map.height();
+            if !b"#.O@".contains(&c) {
+                return Err(error(pos, format!("unexpected `{}`", c as char)));
+            }
+            if edge && c != b'#' {
+                return Err(error(
+                    pos,
+                    "expected a wall around the warehouse".to_owned(),
+                ));
+            }
+        }
+        let robots: Vec<Pos> = map.find_all(&b'@').collect();
+        match robots[..] {
+            [bot_pos] => Ok(Self { map, bot_pos }),
+            [] => Err(section.error("expected a robot `@` in the warehouse")),
+            [_, second, ..] => Err(error(second, "expected only one robot".to_owned())),
+        }
+    }
+
+    /// The warehouse with everything but the robot twice as wide.
+    fn widen(&self) -> Self {
+        let map = Grid::from_fn(self.map.width() * 2, self.map.height(), |(x, y)| {
+            match (self.map[(x / 2, y)], x % 2) {
+                (b'O', 0) => b'[',
+                (b'O', _) => b']',
+                (b'@', 0) => b'@',
+                (b'@', _) => b'.',
+                (c, _) => c,
+            }
+        });
+        let (x, y) = self.bot_pos;
+        Self {
+            map,
+            bot_pos: (x * 2, y),
+        }
+    }
+
+    fn step(&self, pos: Pos, dir: Dir) -> Pos {
+        self.map.step(pos, dir).unwrap()
+    }
+
+    fn move_bot(&mut self, src: Pos, dir: Dir) {
+        assert_eq!(self.bot_pos, src);
+        assert_eq!(self.map[src], b'@');
+
+        let dst = self.step(src, dir);
+        let byte_at_dst = self.map[dst];
+        let can_move = match byte_at_dst {
+            b'#' => false,
+            b'.' => true,
+            b'[' => {
+                if self.can_move_box(dst, dir) {
+                    self.move_box(dst, dir);
+                    true
+                } else {
+                    false
+                }
+            }
+            b']' => {
+                if self.can_move_box((dst.0 - 1, dst.1), dir) {
+                    self.move_box((dst.0 - 1, dst.1), dir);
+                    true
+                } else {
+                    false
+                }
+            }
+            _ => {
+                panic!(
+                    "Unexpected character {} found in {:?} -> {:?} -> {:?}",
+                    escape_default(byte_at_dst),
+                    src,
+                    dir,
+                    dst
+                );
+            }
+        };
+        if can_move {
+            self.map[src] = b'.';
+            self.map[dst] = b'@';
+            self.bot_pos = dst;
+        }
+    }
+
+    fn can_move_box(&self, left_src: Pos, dir: Dir) -> bool {
+        let right_src = (left_src.0 + 1, left_src.1);
+
+        if self.map[left_src] != b'[' {
+            panic!("Not [: {}", escape_default(self.map[left_src]));
+        }
+        if self.map[right_src] != b']' {
+            panic!("Not ]: {}", escape_default(self.map[right_src]));
+        }
+
+        let left_dst = self.step(left_src, dir);
+        let right_dst = self.step(right_src, dir);
+
+        let valid_dst = |dst: Pos| -> bool {
+            match self.map[dst] {
+                b'#' => false,
+                b'[' => self.can_move_box(dst, dir),
+                b']' => self.can_move_box((dst.0 - 1, dst.1), dir),
+                b'.' => true,
+                _ => panic!("Unexpected byte {}", escape_default(self.map[dst])),
+            }
+        };
+
+        match dir {
+            Dir::N | Dir::S => valid_dst(left_dst) && valid_dst(right_dst),
+            Dir::E => valid_dst(right_dst),
+            Dir::W => valid_dst(left_dst),
+        }
+    }
+
+    fn move_box(&mut self, left_src: Pos, dir: Dir) {
+        if self.map[left_src] != b'[' {
+            panic!("Expected [, got {}", escape_default(self.map[left_src]));
+        }
+        assert_eq!(self.map[left_src], b'[');
+        let right_src = (left_src.0 + 1, left_src.1);
+        assert_eq!(self.map[(left_src.0 + 1, left_src.1)], b']');
+
+        let left_dst = self.step(left_src, dir);
+        let right_dst = self.step(right_src, dir);
+
+        match dir {
+            Dir::N | Dir::S => {
+                for dst in [left_dst, right_dst] {
+                    match self.map[dst] {
+                        b'[' => {
+                            self.move_box(dst, dir);
+                        }
+                        b']' => {
+                            self.move_box((dst.0 - 1, dst.1), dir);
+                        }
+                        b'.' => {}
+                        _ => {
+                            panic!("Unexpected char: {}", escape_default(self.map[dst]))
+                        }
+                    }
+                    assert_eq!(self.map[dst], b'.');
+                }
+
+                self.map[left_src] = b'.';
+                self.map[right_src] = b'.';
+            }
+            Dir::E => {
+                assert_eq!(self.map[left_dst], b']');
+                match self.map[right_dst] {
+                    b'[' => {
+                        self.move_box(right_dst, dir);
+                    }
+                    b'.' => {}
+                    _ => {
+                        panic!(
+                            "Unexpected char: {} while moving {:?} -> {:?}",
+                            escape_default(self.map[right_dst]),
+                            right_src,
+                            right_dst,
+                        )
+                    }
+                }
+                assert_eq!(self.map[right_dst], b'.');
+
+                self.map[left_src] = b'.';
+            }
+            Dir::W => {
+                assert_eq!(self.map[right_dst], b'[');
+                match self.map[left_dst] {
+                    b']' => {
+                        self.move_box((left_dst.0 - 1, left_dst.1), dir);
+                    }
+                    b'.' => {}
+                    _ => {
+                        panic!("Unexpected char: {}", escape_default(self.map[left_dst]))
+                    }
+                }
+                assert_eq!(self.map[left_dst], b'.');
+
+                self.map[right_src] = b'.';
+            }
+        };
+        self.map[left_dst] = b'[';
+        self.map[right_dst] = b']';
+    }
+
+    fn gps(&self) -> usize {
+        self.map.find_all(&b'[').map(|(x, y)| 100 * y + x).sum()
+    }
+}
+
+fn parse_moves(section: &Section) -> aoc_parse::Result<Vec<Dir>> {
+    let mut moves = vec![];
+    for mut line in section.lines() {
+        while !line.is_empty() {
+            moves.push(line.one_of(&[
+                ("^", Dir::N),
+                (">", Dir::E),
+                ("v", Dir::S),
+                ("<", Dir::W),
+            ])?);
+        }
+    }
+    Ok(moves)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = (Warehouse, Vec<Dir>);
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let [map, moves] = aoc_parse::split_sections(input)?;
+        Ok((Warehouse::parse(&map)?, parse_moves(&moves)?))
+    }
+
+    fn part_b((warehouse, moves): &Self::Input) -> Option<String> {
+        let mut warehouse = warehouse.widen();
+        for &dir in moves {
+            warehouse.move_bot(warehouse.bot_pos, dir);
+        }
+        Some(warehouse.gps().to_string())
+    }
+}
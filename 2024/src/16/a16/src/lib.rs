@@ -0,0 +1,37 @@
+use aoc::Solution;
+use aoc_grid::{Dir, Grid, Pos};
+
+fn successors(grid: &Grid, (pos, dir): (Pos, Dir)) -> Vec<((Pos, Dir), usize)> {
+    let mut next = vec![((pos, dir.cw()), 1000), ((pos, dir.ccw()), 1000)];
+    let forward = grid.step(pos, dir).unwrap();
+    if grid[forward] != b'#' {
+        next.push(((forward, dir), 1));
+    }
+    next
+}
+
+fn lowest_score(grid: &Grid) -> usize {
+    let start = grid.find(&b'S').unwrap();
+    let end = grid.find(&b'E').unwrap();
+    let (_, score) = aoc_search::dijkstra(
+        [(start, Dir::E)],
+        |&state| successors(grid, state),
+        |&(pos, _)| pos == end,
+    )
+    .expect("No path to end found");
+    score
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Grid;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part_a(grid: &Self::Input) -> Option<String> {
+        Some(lowest_score(grid).to_string())
+    }
+}
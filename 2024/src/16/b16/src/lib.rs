@@ -0,0 +1,41 @@
+use std::collections::HashSet;
+
+use aoc::Solution;
+use aoc_grid::{Dir, Grid, Pos};
+
+fn successors(grid: &Grid, (pos, dir): (Pos, Dir)) -> Vec<((Pos, Dir), usize)> {
+    let mut next = vec![((pos, dir.cw()), 1000), ((pos, dir.ccw()), 1000)];
+    let forward = grid.step(pos, dir).unwrap();
+    if grid[forward] != b'#' {
+        next.push(((forward, dir), 1));
+    }
+    next
+}
+
+fn best_seats(grid: &Grid) -> (usize, HashSet<Pos>) {
+    let start = grid.find(&b'S').unwrap();
+    let end = grid.find(&b'E').unwrap();
+    let (paths, best_score) = aoc_search::dijkstra_all(
+        [(start, Dir::E)],
+        |&state| successors(grid, state),
+        |&(pos, _)| pos == end,
+    )
+    .expect("No path to end found");
+    let seats = paths.nodes().into_iter().map(|(pos, _)| pos).collect();
+    (best_score, seats)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Grid;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part_b(grid: &Self::Input) -> Option<String> {
+        let (_, seats) = best_seats(grid);
+        Some(seats.len().to_string())
+    }
+}
@@ -0,0 +1,25 @@
+use aoc::Solution;
+use aoc_vm::Machine;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Machine;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_vm::parse(input)
+    }
+
+    fn part_a(machine: &Self::Input) -> Option<String> {
+        let outputs = machine
+            .clone()
+            .outputs()
+            .unwrap_or_else(|fault| panic!("{fault}"));
+        let output_str = outputs
+            .iter()
+            .map(|&n| n.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+        Some(output_str)
+    }
+}
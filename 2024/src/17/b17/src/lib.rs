@@ -0,0 +1,20 @@
+use aoc::Solution;
+use aoc_vm::Machine;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Machine;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_vm::parse(input)
+    }
+
+    /// Part B searches for register A's starting value, so the registers the
+    /// input starts with are ignored.
+    fn part_b(machine: &Self::Input) -> Option<String> {
+        let a = aoc_vm::find_quine(machine.program())
+            .unwrap_or_else(|err| panic!("Can't solve this program: {err}"))?;
+        Some(a.to_string())
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::HashSet;
+
+use aoc::Solution;
+
+const N: usize = 70;
+const NUM_BYTES: usize = 1024;
+
+fn h((x, y): (usize, usize)) -> usize {
+    assert!(x <= N);
+    assert!(y <= N);
+    N - x + N - y
+}
+
+fn neighbors(
+    corrupted: &HashSet<(usize, usize)>,
+    (x, y): (usize, usize),
+) -> Vec<((usize, usize), usize)> {
+    [
+        (x.wrapping_add(1), y),
+        (x.wrapping_sub(1), y),
+        (x, y.wrapping_add(1)),
+        (x, y.wrapping_sub(1)),
+    ]
+    .into_iter()
+    .filter(|&(x2, y2)| x2 <= N && y2 <= N && !corrupted.contains(&(x2, y2)))
+    .map(|pos| (pos, 1))
+    .collect()
+}
+
+fn parse_coord(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<usize> {
+    let before = *line;
+    let coord = line.int()?;
+    if coord > N {
+        return Err(before.error(format!("{coord} is outside the memory space")));
+    }
+    Ok(coord)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<(usize, usize)>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, |line| {
+            let x = parse_coord(line)?;
+            line.literal(",")?;
+            Ok((x, parse_coord(line)?))
+        })
+    }
+
+    fn part_a(hazards: &Self::Input) -> Option<String> {
+        let corrupted: HashSet<(usize, usize)> = hazards.iter().take(NUM_BYTES).cloned().collect();
+        assert_eq!(corrupted.len(), NUM_BYTES);
+
+        let (_, time) = aoc_search::astar(
+            [(0, 0)],
+            |&pos| neighbors(&corrupted, pos),
+            |&pos| h(pos),
+            |&pos| pos == (N, N),
+        )?;
+        Some(time.to_string())
+    }
+}
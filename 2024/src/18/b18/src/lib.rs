@@ -0,0 +1,77 @@
+use std::collections::HashSet;
+
+use aoc::Solution;
+
+const N: usize = 70;
+
+fn h((x, y): (usize, usize)) -> usize {
+    assert!(x <= N);
+    assert!(y <= N);
+    N - x + N - y
+}
+
+fn neighbors(
+    corrupted: &HashSet<(usize, usize)>,
+    (x, y): (usize, usize),
+) -> Vec<((usize, usize), usize)> {
+    [
+        (x.wrapping_add(1), y),
+        (x.wrapping_sub(1), y),
+        (x, y.wrapping_add(1)),
+        (x, y.wrapping_sub(1)),
+    ]
+    .into_iter()
+    .filter(|&(x2, y2)| x2 <= N && y2 <= N && !corrupted.contains(&(x2, y2)))
+    .map(|pos| (pos, 1))
+    .collect()
+}
+
+fn reachable(hazards: &Vec<(usize, usize)>, num_bytes: usize) -> bool {
+    let corrupted: HashSet<(usize, usize)> = hazards.iter().take(num_bytes).cloned().collect();
+
+    aoc_search::astar(
+        [(0, 0)],
+        |&pos| neighbors(&corrupted, pos),
+        |&pos| h(pos),
+        |&pos| pos == (N, N),
+    )
+    .is_some()
+}
+
+fn parse_coord(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<usize> {
+    let before = *line;
+    let coord = line.int()?;
+    if coord > N {
+        return Err(before.error(format!("{coord} is outside the memory space")));
+    }
+    Ok(coord)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<(usize, usize)>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, |line| {
+            let x = parse_coord(line)?;
+            line.literal(",")?;
+            Ok((x, parse_coord(line)?))
+        })
+    }
+
+    fn part_b(hazards: &Self::Input) -> Option<String> {
+        let mut last_possible = 0;
+        let mut first_impossible = hazards.len();
+        while last_possible + 1 < first_impossible {
+            let mid = last_possible + (first_impossible - last_possible) / 2;
+            if reachable(hazards, mid) {
+                last_possible = mid;
+            } else {
+                first_impossible = mid;
+            }
+        }
+        let (x, y) = hazards[last_possible];
+        Some(format!("{x},{y}"))
+    }
+}
@@ -0,0 +1,53 @@
+use aoc::Solution;
+
+fn can_make_pattern(pattern: &Vec<u8>, towels: &Vec<Vec<u8>>) -> bool {
+    let mut dp = vec![false; pattern.len()];
+    for i in (0..pattern.len()).rev() {
+        let pattern_slice = &pattern[i..];
+        for towel in towels {
+            if pattern_slice.len() < towel.len() {
+                // Towel too big for rest of pattern
+                continue;
+            }
+
+            if i + towel.len() < pattern.len() && !dp[i + towel.len()] {
+                // We can't make the pattern even if this towel matches
+                continue;
+            }
+
+            dp[i] = dp[i] || towel.iter().zip(pattern_slice.iter()).all(|(t, p)| t == p);
+        }
+    }
+    dp[0]
+}
+
+pub struct Onsen {
+    towels: Vec<Vec<u8>>,
+    patterns: Vec<Vec<u8>>,
+}
+
+fn parse_onsen(input: &str) -> aoc_parse::Result<Onsen> {
+    let [towels, patterns] = aoc_parse::split_sections(input)?;
+    let [mut line] = towels.split_lines()?;
+    let towels = line.list(",", |towel| Ok(towel.word()?.as_bytes().to_vec()))?;
+    line.end()?;
+    let patterns = patterns.parse_lines(|line| Ok(line.word()?.as_bytes().to_vec()))?;
+    Ok(Onsen { towels, patterns })
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Onsen;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        parse_onsen(input)
+    }
+
+    fn part_a(onsen: &Self::Input) -> Option<String> {
+        let Onsen { towels, patterns } = onsen;
+        let possible = aoc::par_map(patterns, |pattern| can_make_pattern(pattern, towels));
+        let total = possible.into_iter().filter(|&possible| possible).count();
+        Some(total.to_string())
+    }
+}
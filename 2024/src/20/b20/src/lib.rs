@@ -0,0 +1,113 @@
+use std::{
+    ascii::escape_default,
+    collections::{hash_map::Entry, HashMap},
+};
+
+use aoc::Solution;
+use aoc_grid::{Grid, Pos};
+
+struct Track {
+    map: Grid,
+    start: Pos,
+    end: Pos,
+}
+
+impl Track {
+    fn new(map: Grid) -> Self {
+        for (pos, &c) in map.iter() {
+            if !matches!(c, b'S' | b'E' | b'#' | b'.') {
+                panic!("Unexpected character at {:?}: {}", pos, escape_default(c));
+            }
+        }
+        let start = map.find(&b'S').unwrap();
+        let end = map.find(&b'E').unwrap();
+        Self { map, start, end }
+    }
+}
+
+fn get_times(track: &Track) -> (HashMap<Pos, usize>, HashMap<(Pos, Pos), usize>) {
+    let mut time_from_pos_to_end = HashMap::new();
+    time_from_pos_to_end.insert(track.end, 0);
+    let mut time_to_end = 0;
+    let mut pos = track.end;
+
+    let mut cheats = HashMap::new();
+    loop {
+        for dx in -20..=20_isize {
+            for dy in -20..=20_isize {
+                if dx == 0 && dy == 0 {
+                    continue;
+                }
+                let cheat_distance = (dx.abs() + dy.abs()) as usize;
+                if cheat_distance > 20 {
+                    continue;
+                }
+                let Some(pos2) = track.map.offset(pos, (dx, dy)) else {
+                    continue;
+                };
+                if let Some(time_after_cheat) = time_from_pos_to_end.get(&pos2) {
+                    let time_saved = time_to_end - cheat_distance - time_after_cheat;
+                    cheats.insert((pos, pos2), time_saved);
+                }
+            }
+        }
+
+        if pos == track.start {
+            break;
+        }
+        time_to_end += 1;
+        for pos2 in track.map.neighbors4(pos) {
+            match track.map[pos2] {
+                b'E' | b'#' => {}
+                b'.' | b'S' => {
+                    match time_from_pos_to_end.entry(pos2) {
+                        Entry::Occupied(_) => {
+                            continue;
+                        }
+                        Entry::Vacant(e) => e.insert(time_to_end),
+                    };
+                    pos = pos2;
+                    break;
+                }
+                c => panic!("Unexpected char: {}", escape_default(c)),
+            }
+        }
+    }
+    (time_from_pos_to_end, cheats)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Grid;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part_b(map: &Self::Input) -> Option<String> {
+        let track = Track::new(map.clone());
+
+        let (_, cheats) = get_times(&track);
+
+        let mut cheats_by_time_saved: HashMap<usize, usize> = HashMap::new();
+        for (_, time_saved) in cheats {
+            *cheats_by_time_saved.entry(time_saved).or_default() += 1;
+        }
+
+        let mut times: Vec<usize> = cheats_by_time_saved.keys().cloned().collect();
+        times.sort();
+        let mut num_good_cheats = 0;
+        for time_saved in &times {
+            // println!(
+            //     "There are {} cheats that save {}",
+            //     cheats_by_time_saved.get(time_saved).unwrap(),
+            //     time_saved
+            // );
+            if *time_saved >= 100 {
+                num_good_cheats += cheats_by_time_saved.get(time_saved).unwrap();
+            }
+        }
+        Some(num_good_cheats.to_string())
+    }
+}
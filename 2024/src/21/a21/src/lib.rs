@@ -0,0 +1,173 @@
+use aoc::Solution;
+use std::ascii::escape_default;
+
+type Edge = (u8, u8);
+
+fn get_numeric_edges(pos: u8) -> &'static [Edge] {
+    match pos {
+        b'A' => &[(b'<', b'0'), (b'^', b'3')],
+        b'0' => &[(b'>', b'A'), (b'^', b'2')],
+        b'1' => &[(b'>', b'2'), (b'^', b'4')],
+        b'2' => &[(b'v', b'0'), (b'<', b'1'), (b'>', b'3'), (b'^', b'5')],
+        b'3' => &[(b'v', b'A'), (b'<', b'2'), (b'^', b'6')],
+        b'4' => &[(b'v', b'1'), (b'>', b'5'), (b'^', b'7')],
+        b'5' => &[(b'v', b'2'), (b'<', b'4'), (b'>', b'6'), (b'^', b'8')],
+        b'6' => &[(b'v', b'3'), (b'<', b'5'), (b'^', b'9')],
+        b'7' => &[(b'v', b'4'), (b'>', b'8')],
+        b'8' => &[(b'v', b'5'), (b'<', b'7'), (b'>', b'9')],
+        b'9' => &[(b'v', b'6'), (b'<', b'8')],
+        _ => panic!("Unexpected position: {}", escape_default(pos)),
+    }
+}
+
+fn get_direction_edges(pos: u8) -> &'static [Edge] {
+    match pos {
+        b'A' => &[(b'v', b'>'), (b'<', b'^')],
+        b'^' => &[(b'v', b'v'), (b'>', b'A')],
+        b'<' => &[(b'>', b'v')],
+        b'v' => &[(b'<', b'<'), (b'>', b'>'), (b'^', b'^')],
+        b'>' => &[(b'<', b'v'), (b'^', b'A')],
+        _ => panic!("Unexpected position: {}", escape_default(pos)),
+    }
+}
+
+fn apply_directional_input(
+    edges_function: &dyn Fn(u8) -> &'static [Edge],
+    position: u8,
+    directional_input: u8,
+) -> Option<u8> {
+    edges_function(position)
+        .iter()
+        .find(|&&(direction, _)| direction == directional_input)
+        .map(|(_, new_position)| *new_position)
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct State {
+    numeric_key: u8,
+    directional_keys: [u8; 2],
+}
+
+impl State {
+    fn input_to_directional_keypad(
+        &self,
+        layer: usize,
+        directional_input: u8,
+    ) -> Option<(Self, bool)> {
+        match directional_input {
+            b'A' => {
+                if self.directional_keys.is_empty() {
+                    self.input_to_numeric_keypad(directional_input)
+                } else if layer == self.directional_keys.len() - 1 {
+                    self.input_to_numeric_keypad(self.directional_keys[layer])
+                } else {
+                    self.input_to_directional_keypad(layer + 1, self.directional_keys[layer])
+                }
+            }
+            _ => apply_directional_input(
+                &get_direction_edges,
+                self.directional_keys[layer],
+                directional_input,
+            )
+            .map(|new_directional_key| {
+                let mut next_state = *self;
+                next_state.directional_keys[layer] = new_directional_key;
+                (next_state, false)
+            }),
+        }
+    }
+
+    fn input_to_numeric_keypad(&self, directional_input: u8) -> Option<(Self, bool)> {
+        match directional_input {
+            b'A' => Some((*self, true)),
+            _ => apply_directional_input(&get_numeric_edges, self.numeric_key, directional_input)
+                .map(|numeric_key| {
+                    let mut next_state = *self;
+                    next_state.numeric_key = numeric_key;
+                    (next_state, false)
+                }),
+        }
+    }
+}
+
+/// The fewest button presses on the outermost directional keypad that type
+/// out `code`.
+fn fewest_presses(start: State, code: &[u8]) -> usize {
+    let successors = |&(num_output, state): &(usize, State)| {
+        let mut directional_inputs = vec![b'A'];
+        for &(direction, _) in get_direction_edges(state.directional_keys[0]) {
+            directional_inputs.push(direction);
+        }
+        let mut next = vec![];
+        for directional_input in directional_inputs {
+            if let Some((next_state, did_output_code)) =
+                state.input_to_directional_keypad(0, directional_input)
+            {
+                if did_output_code && state.numeric_key != code[num_output] {
+                    continue;
+                }
+                let next_num_output = num_output + if did_output_code { 1 } else { 0 };
+                next.push((next_num_output, next_state));
+            }
+        }
+        next
+    };
+    let path = aoc_search::bfs([(0, start)], successors, |&(num_output, _)| {
+        num_output == code.len()
+    })
+    .unwrap_or_else(|| panic!("Couldn't input code {}", String::from_utf8_lossy(code)));
+    path.len() - 1
+}
+
+fn code_to_number(code: &[u8]) -> usize {
+    let mut num = 0;
+
+    for &c in code {
+        if c.is_ascii_digit() {
+            num *= 10;
+            num += (c - b'0') as usize;
+        }
+    }
+
+    num
+}
+
+/// Reads a code for the numeric keypad.
+fn parse_code(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Vec<u8>> {
+    let mut code = vec![];
+    while !line.is_empty() {
+        let before = *line;
+        let key = line.char()?;
+        if !(key.is_ascii_digit() || key == 'A') {
+            return Err(before.error(format!("`{key}` isn't on the numeric keypad")));
+        }
+        code.push(key as u8);
+    }
+    Ok(code)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<Vec<u8>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, parse_code)
+    }
+
+    fn part_a(codes: &Self::Input) -> Option<String> {
+        let mut total_complexity = 0;
+        for code in codes {
+            let presses = fewest_presses(
+                State {
+                    numeric_key: b'A',
+                    directional_keys: [b'A', b'A'],
+                },
+                code,
+            );
+            let num = code_to_number(code);
+            total_complexity += presses * num;
+        }
+        Some(total_complexity.to_string())
+    }
+}
@@ -0,0 +1,44 @@
+use aoc::Solution;
+
+fn mix(a: usize, b: usize) -> usize {
+    a ^ b
+}
+
+fn prune(secret: usize) -> usize {
+    secret % 16777216
+}
+
+fn evolve(secret: usize) -> usize {
+    let secret = mix(secret, secret * 64);
+    let secret = prune(secret);
+
+    let secret = mix(secret, secret / 32);
+    let secret = prune(secret);
+
+    let secret = mix(secret, secret * 2048);
+    let secret = prune(secret);
+
+    secret
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<usize>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, |line| line.int())
+    }
+
+    fn part_a(secrets: &Self::Input) -> Option<String> {
+        let evolved = aoc::par_map(secrets, |&secret| {
+            let mut evolved = secret;
+            for _ in 0..2000 {
+                evolved = evolve(evolved);
+            }
+            evolved
+        });
+        let total: usize = evolved.into_iter().sum();
+        Some(total.to_string())
+    }
+}
@@ -0,0 +1,47 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc::Solution;
+
+pub type Edges = HashMap<String, HashSet<String>>;
+
+fn parse_edges(input: &str) -> aoc_parse::Result<Edges> {
+    let mut edges: Edges = HashMap::new();
+    for mut line in aoc_parse::lines(input) {
+        let left = line.word()?.to_string();
+        line.literal("-")?;
+        let right = line.word()?.to_string();
+        line.end()?;
+
+        edges.entry(left.clone()).or_default().insert(right.clone());
+        edges.entry(right).or_default().insert(left);
+    }
+    Ok(edges)
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Edges;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        parse_edges(input)
+    }
+
+    fn part_a(edges: &Self::Input) -> Option<String> {
+        let t_nodes = edges.keys().filter(|node| node.starts_with('t'));
+
+        let mut triangles = HashSet::new();
+        for node1 in t_nodes {
+            let edges1 = edges.get(node1).unwrap();
+            for node2 in edges1 {
+                let edges2 = edges.get(node2).unwrap();
+                for node3 in edges1.intersection(edges2) {
+                    let mut triangle = vec![node1, node2, node3];
+                    triangle.sort();
+                    triangles.insert(triangle);
+                }
+            }
+        }
+        Some(triangles.len().to_string())
+    }
+}
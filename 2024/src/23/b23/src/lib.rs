@@ -0,0 +1,98 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc::Solution;
+
+pub type Edges = HashMap<String, HashSet<String>>;
+
+fn parse_edges(input: &str) -> aoc_parse::Result<Edges> {
+    let mut edges: Edges = HashMap::new();
+    for mut line in aoc_parse::lines(input) {
+        let left = line.word()?.to_string();
+        line.literal("-")?;
+        let right = line.word()?.to_string();
+        line.end()?;
+
+        edges.entry(left.clone()).or_default().insert(right.clone());
+        edges.entry(right).or_default().insert(left);
+    }
+    Ok(edges)
+}
+
+fn bron_kerbosch(edges: &Edges) -> HashSet<&String> {
+    let mut stack = vec![(
+        HashSet::<&String>::new(),
+        edges.keys().collect::<HashSet<&String>>(),
+        HashSet::<&String>::new(),
+    )];
+    let mut maximal_cliques = vec![];
+    while let Some((must_haves, mut can_haves, mut cannot_haves)) = stack.pop() {
+        if can_haves.is_empty() && cannot_haves.is_empty() {
+            maximal_cliques.push(must_haves);
+            continue;
+        }
+        let can_have_nodes: Vec<&String> = can_haves.iter().cloned().collect();
+        for node in can_have_nodes {
+            let node_neighbors: HashSet<&String> = edges.get(node).unwrap().iter().collect();
+
+            let mut new_must_haves: HashSet<&String> = must_haves.clone();
+            new_must_haves.insert(node);
+
+            let new_can_haves: HashSet<&String> =
+                can_haves.intersection(&node_neighbors).cloned().collect();
+
+            let new_cannot_haves: HashSet<&String> = cannot_haves
+                .intersection(&node_neighbors)
+                .cloned()
+                .collect();
+
+            stack.push((new_must_haves, new_can_haves, new_cannot_haves));
+
+            can_haves.remove(&node);
+            cannot_haves.insert(node);
+        }
+    }
+    maximal_cliques
+        .into_iter()
+        .max_by(|a, b| a.len().cmp(&b.len()))
+        .unwrap()
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Edges;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        parse_edges(input)
+    }
+
+    fn part_b(edges: &Self::Input) -> Option<String> {
+        let mut max_clique: Vec<String> = bron_kerbosch(edges).into_iter().cloned().collect();
+        max_clique.sort();
+        Some(max_clique.join(","))
+    }
+}
+
+/// The LAN as an undirected graph, with the largest clique highlighted.
+pub fn graph(input: &str) -> Result<aoc_viz::Graph, aoc::ParseError> {
+    let edges = Day23::try_parse(input)?;
+    let mut computers: Vec<&String> = edges.keys().collect();
+    computers.sort();
+
+    let mut graph = aoc_viz::Graph::undirected();
+    for &computer in &computers {
+        graph.node(computer);
+    }
+    for &computer in &computers {
+        let mut neighbors: Vec<&String> = edges[computer].iter().collect();
+        neighbors.sort();
+        for neighbor in neighbors {
+            if computer < neighbor {
+                graph.edge(computer, neighbor);
+            }
+        }
+    }
+    let clique: Vec<&String> = bron_kerbosch(&edges).into_iter().collect();
+    graph.highlight_clique(&clique);
+    Ok(graph)
+}
@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use aoc::Solution;
+use aoc_dag::Evaluator;
+
+type Registers<'s> = HashMap<&'s str, i64>;
+type Assignments<'s> = HashMap<&'s str, (Op, Vec<&'s str>)>;
+
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+/// The wires' starting values and the gates, keyed by the wire each gate
+/// drives.
+pub struct Circuit {
+    registers: HashMap<String, i64>,
+    gates: HashMap<String, (Op, Vec<String>)>,
+}
+
+fn parse_circuit(input: &str) -> aoc_parse::Result<Circuit> {
+    let [registers, wiring] = aoc_parse::split_sections(input)?;
+    let registers = registers
+        .parse_lines(|line| {
+            let wire = line.word()?;
+            line.literal(": ")?;
+            let value = line.one_of(&[("0", 0), ("1", 1)])?;
+            Ok((wire.to_owned(), value))
+        })?
+        .into_iter()
+        .collect();
+
+    let mut gates = HashMap::new();
+    for mut line in wiring.lines() {
+        let lhs = line.word()?;
+        line.literal(" ")?;
+        let op = line.one_of(&[("AND", Op::And), ("OR", Op::Or), ("XOR", Op::Xor)])?;
+        line.literal(" ")?;
+        let rhs = line.word()?;
+        line.literal(" -> ")?;
+        let start = line;
+        let result = line.word()?;
+        line.end()?;
+        let gate = (op, vec![lhs.to_owned(), rhs.to_owned()]);
+        if gates.insert(result.to_owned(), gate).is_some() {
+            return Err(start.error(format!("`{result}` is already driven by another gate")));
+        }
+    }
+    Ok(Circuit { registers, gates })
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Circuit;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        parse_circuit(input)
+    }
+
+    fn part_a(circuit: &Self::Input) -> Option<String> {
+        let registers: Registers = circuit
+            .registers
+            .iter()
+            .map(|(wire, &value)| (wire.as_str(), value))
+            .collect();
+        let assigns: Assignments = circuit
+            .gates
+            .iter()
+            .map(|(result, (op, args))| {
+                let args = args.iter().map(String::as_str).collect();
+                (result.as_str(), (*op, args))
+            })
+            .collect();
+        let z_labels = assigns.keys().filter(|result| result.starts_with('z'));
+
+        let mut evaluator = Evaluator::new(&assigns, registers);
+
+        let mut z: i64 = 0;
+        for z_label in z_labels {
+            let z_bit: i64 = z_label[1..].parse().ok()?;
+
+            let z_value = evaluator
+                .eval(z_label, |op, args| match op {
+                    Op::And => args[0] & args[1],
+                    Op::Or => args[0] | args[1],
+                    Op::Xor => args[0] ^ args[1],
+                })
+                .unwrap_or_else(|err| panic!("Can't work out {z_label}: {err}"));
+            z |= z_value << z_bit;
+        }
+        Some(z.to_string())
+    }
+}
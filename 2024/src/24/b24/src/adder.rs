@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+
+use crate::{make_swaps, AssignmentBinop, Assignments, Circuit, Op};
+
+/// Give up rather than try every way of pairing up more wires than this.
+const MAX_MISPLACED: usize = 12;
+
+/// How many random additions a set of swaps has to get right.
+const TRIALS: usize = 100;
+
+fn is_input(wire: &str) -> bool {
+    wire.starts_with(['x', 'y'])
+}
+
+/// Which bit a wire like `x07` or `z45` carries.
+fn bit(wire: &str) -> Option<usize> {
+    wire.get(1..)?.parse().ok()
+}
+
+/// The outputs of the gates that aren't where a ripple-carry adder would
+/// put them.
+///
+/// Bit `i` of the adder is wired up as
+///
+/// ```text
+/// half  = x XOR y
+/// z     = half XOR carry
+/// carry = (x AND y) OR (half AND carry)
+/// ```
+///
+/// except that bit 0 has no carry in, so `z00` is just `x00 XOR y00` and
+/// `x00 AND y00` is the carry out. The last carry out is the top bit of `z`.
+/// Any gate whose kind doesn't fit the wires it reads from and feeds into
+/// must have had its output swapped.
+fn misplaced<'s>(assignments: &Assignments<'s>, bits: usize) -> Vec<&'s str> {
+    let mut consumers: HashMap<&str, Vec<Op>> = HashMap::new();
+    for &(lhs, op, rhs) in assignments.values() {
+        consumers.entry(lhs).or_default().push(op);
+        consumers.entry(rhs).or_default().push(op);
+    }
+    let feeds = |wire: &str, op: Op| consumers.get(wire).is_some_and(|ops| ops.contains(&op));
+    let top = format!("z{bits:02}");
+
+    let mut wrong = vec![];
+    for (&out, &(lhs, op, rhs)) in assignments {
+        let from_inputs = is_input(lhs) && is_input(rhs);
+        let first_bit = from_inputs && bit(lhs) == Some(0);
+        let fits = if out.starts_with('z') {
+            if out == top {
+                op == Op::Or
+            } else {
+                // Only `z00` comes straight from the inputs.
+                op == Op::Xor && from_inputs == first_bit
+            }
+        } else {
+            match op {
+                Op::Xor => from_inputs && !first_bit && feeds(out, Op::Xor),
+                Op::And if first_bit => feeds(out, Op::Xor),
+                Op::And => feeds(out, Op::Or),
+                Op::Or => feeds(out, Op::Xor) && feeds(out, Op::And),
+            }
+        };
+        if !fits {
+            wrong.push(out);
+        }
+    }
+    wrong.sort();
+    wrong
+}
+
+/// Every way of splitting `wires` into pairs.
+fn pairings<'s>(wires: &[&'s str]) -> Vec<Vec<(&'s str, &'s str)>> {
+    let Some((&first, rest)) = wires.split_first() else {
+        return vec![vec![]];
+    };
+    let mut all = vec![];
+    for (i, &partner) in rest.iter().enumerate() {
+        let mut others = rest.to_vec();
+        others.remove(i);
+        for mut pairs in pairings(&others) {
+            pairs.insert(0, (first, partner));
+            all.push(pairs);
+        }
+    }
+    all
+}
+
+/// The value on `wire`, or `None` if it depends on itself.
+fn eval<'s>(
+    gates: &HashMap<&'s str, AssignmentBinop<'s>>,
+    (x, y): (u64, u64),
+    wire: &'s str,
+    values: &mut HashMap<&'s str, Option<bool>>,
+) -> Option<bool> {
+    if is_input(wire) {
+        let n = if wire.starts_with('x') { x } else { y };
+        return Some(n >> bit(wire)? & 1 == 1);
+    }
+    if let Some(&value) = values.get(wire) {
+        return value;
+    }
+    // Mark the wire as in progress, so a loop comes back as `None`.
+    values.insert(wire, None);
+    let &(lhs, op, rhs) = gates.get(wire)?;
+    let lhs = eval(gates, (x, y), lhs, values)?;
+    let rhs = eval(gates, (x, y), rhs, values)?;
+    let value = match op {
+        Op::And => lhs & rhs,
+        Op::Or => lhs | rhs,
+        Op::Xor => lhs ^ rhs,
+    };
+    values.insert(wire, Some(value));
+    Some(value)
+}
+
+/// What the circuit makes of `x + y`, with each pair of gate outputs in
+/// `swaps` swapped back.
+fn add(
+    assignments: &Assignments,
+    swaps: &[(&str, &str)],
+    bits: usize,
+    x: u64,
+    y: u64,
+) -> Option<u64> {
+    let swapped = make_swaps(swaps.to_vec());
+    let gates: HashMap<&str, AssignmentBinop> = assignments
+        .iter()
+        .map(|(&out, &gate)| (swapped.get(out).copied().unwrap_or(out), gate))
+        .collect();
+    let mut values = HashMap::new();
+    let mut z = 0;
+    for i in 0..=bits {
+        let wire = format!("z{i:02}");
+        let wire = gates.get_key_value(wire.as_str())?.0;
+        if eval(&gates, (x, y), wire, &mut values)? {
+            z |= 1 << i;
+        }
+    }
+    Some(z)
+}
+
+/// A small, fixed pseudorandom sequence, so the checks are repeatable.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+/// Whether swapping back `swaps` makes the circuit add correctly, judging by
+/// the edge cases and a batch of random sums.
+fn adds_correctly(assignments: &Assignments, swaps: &[(&str, &str)], bits: usize) -> bool {
+    let max = (1 << bits) - 1;
+    let mut cases = vec![(0, 0), (max, 0), (0, max), (max, 1), (max, max)];
+    let mut state = 0;
+    for _ in 0..TRIALS {
+        cases.push((splitmix64(&mut state) & max, splitmix64(&mut state) & max));
+    }
+    cases
+        .into_iter()
+        .all(|(x, y)| add(assignments, swaps, bits, x, y) == Some(x + y))
+}
+
+/// Finds the pairs of gates whose outputs were swapped, by checking the
+/// circuit against the shape of a ripple-carry adder and then testing each
+/// way of pairing up the misplaced outputs.
+///
+/// Returns `None` if the circuit isn't an adder that a few swaps can fix.
+pub(crate) fn find_swaps(circuit: &Circuit) -> Option<Vec<(&str, &str)>> {
+    let bits = circuit
+        .registers
+        .keys()
+        .filter(|wire| wire.starts_with('x'))
+        .count();
+    if bits == 0 || bits >= 64 {
+        return None;
+    }
+    let assignments = circuit.assignments();
+    let wrong = misplaced(&assignments, bits);
+    if !wrong.len().is_multiple_of(2) || wrong.len() > MAX_MISPLACED {
+        return None;
+    }
+    pairings(&wrong)
+        .into_iter()
+        .find(|swaps| adds_correctly(&assignments, swaps, bits))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_circuit;
+
+    /// A ripple-carry adder with the outputs of each pair in `swaps`
+    /// swapped.
+    fn adder(bits: usize, swaps: &[(&str, &str)]) -> String {
+        let mut lines = vec![];
+        for i in 0..bits {
+            lines.push(format!("x{i:02}: 0"));
+            lines.push(format!("y{i:02}: 1"));
+        }
+        lines.push(String::new());
+
+        let mut gates = vec![
+            ("x00 XOR y00".to_owned(), "z00".to_owned()),
+            ("x00 AND y00".to_owned(), "c00".to_owned()),
+        ];
+        for i in 1..bits {
+            let carry_in = format!("c{:02}", i - 1);
+            let carry_out = if i + 1 == bits {
+                format!("z{bits:02}")
+            } else {
+                format!("c{i:02}")
+            };
+            gates.extend([
+                (format!("x{i:02} XOR y{i:02}"), format!("s{i:02}")),
+                (format!("s{i:02} XOR {carry_in}"), format!("z{i:02}")),
+                (format!("x{i:02} AND y{i:02}"), format!("a{i:02}")),
+                (format!("s{i:02} AND {carry_in}"), format!("b{i:02}")),
+                (format!("a{i:02} OR b{i:02}"), carry_out),
+            ]);
+        }
+        let swapped = make_swaps(swaps.to_vec());
+        for (gate, out) in gates {
+            let out = swapped.get(out.as_str()).copied().unwrap_or(&out);
+            lines.push(format!("{gate} -> {out}"));
+        }
+        lines.join("\n")
+    }
+
+    #[test]
+    fn test_find_swaps() {
+        let input = adder(10, &[]);
+        assert_eq!(find_swaps(&parse_circuit(&input).unwrap()), Some(vec![]));
+
+        let input = adder(10, &[("s02", "a02"), ("z05", "c05"), ("z07", "b07")]);
+        let circuit = parse_circuit(&input).unwrap();
+        assert_eq!(
+            misplaced(&circuit.assignments(), 10),
+            ["a02", "b07", "c05", "s02", "z05", "z07"]
+        );
+        let mut swaps = find_swaps(&circuit).unwrap();
+        swaps.sort();
+        assert_eq!(swaps, [("a02", "s02"), ("b07", "z07"), ("c05", "z05")]);
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc::Solution;
+use aoc_viz::{Graph, Rank};
+
+mod adder;
+
+type AssignmentBinop<'s> = (&'s str, Op, &'s str);
+type Assignments<'s> = HashMap<&'s str, AssignmentBinop<'s>>;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+impl Op {
+    fn color(&self) -> &'static str {
+        match *self {
+            Op::And => "red",
+            Op::Or => "blue",
+            Op::Xor => "green",
+        }
+    }
+}
+
+fn make_swaps<'s>(pairs: Vec<(&'s str, &'s str)>) -> HashMap<&'s str, &'s str> {
+    let mut swaps = HashMap::new();
+    for (a, b) in pairs {
+        swaps.insert(a, b);
+        swaps.insert(b, a);
+    }
+    swaps
+}
+
+/// The wires' starting values and the gates, keyed by the wire each gate
+/// drives.
+pub struct Circuit {
+    registers: HashMap<String, i64>,
+    gates: HashMap<String, (String, Op, String)>,
+}
+
+impl Circuit {
+    fn assignments(&self) -> Assignments<'_> {
+        self.gates
+            .iter()
+            .map(|(result, (lhs, op, rhs))| (result.as_str(), (lhs.as_str(), *op, rhs.as_str())))
+            .collect()
+    }
+}
+
+fn parse_circuit(input: &str) -> aoc_parse::Result<Circuit> {
+    let [registers, wiring] = aoc_parse::split_sections(input)?;
+    let registers = registers
+        .parse_lines(|line| {
+            let wire = line.word()?;
+            line.literal(": ")?;
+            let value = line.one_of(&[("0", 0), ("1", 1)])?;
+            Ok((wire.to_owned(), value))
+        })?
+        .into_iter()
+        .collect();
+
+    let mut gates = HashMap::new();
+    for mut line in wiring.lines() {
+        let lhs = line.word()?;
+        line.literal(" ")?;
+        let op = line.one_of(&[("AND", Op::And), ("OR", Op::Or), ("XOR", Op::Xor)])?;
+        line.literal(" ")?;
+        let rhs = line.word()?;
+        line.literal(" -> ")?;
+        let start = line;
+        let result = line.word()?;
+        line.end()?;
+        let gate = (lhs.to_owned(), op, rhs.to_owned());
+        if gates.insert(result.to_owned(), gate).is_some() {
+            return Err(start.error(format!("`{result}` is already driven by another gate")));
+        }
+    }
+    Ok(Circuit { registers, gates })
+}
+
+/// The circuit as a directed graph, with the inputs at the top and the
+/// outputs at the bottom. Each gate's result is drawn as if the wires in
+/// `swaps` had been swapped back, and the swapped wires are highlighted.
+fn to_viz(circuit: &Circuit, swaps: &[(&str, &str)]) -> Graph {
+    let swapped = make_swaps(swaps.to_vec());
+    assert_eq!(swapped.len(), 2 * swaps.len());
+
+    let assignments = circuit.assignments();
+    let mut wires: HashSet<&str> = circuit.registers.keys().map(String::as_str).collect();
+    for (&result, &(lhs, _, rhs)) in &assignments {
+        wires.extend([result, lhs, rhs]);
+    }
+    let mut wires: Vec<&str> = wires.into_iter().collect();
+    wires.sort();
+
+    let mut graph = Graph::directed();
+    for &wire in &wires {
+        graph.node(wire);
+    }
+    graph.rank(
+        Rank::Source,
+        wires.iter().filter(|wire| wire.starts_with(['x', 'y'])),
+    );
+    graph.rank(
+        Rank::Sink,
+        wires.iter().filter(|wire| wire.starts_with('z')),
+    );
+
+    let mut gates: Vec<_> = assignments.iter().collect();
+    gates.sort();
+    for (result, (lhs, op, rhs)) in gates {
+        let result = swapped.get(result).unwrap_or(result);
+        graph.edge(lhs, result).color(op.color());
+        graph.edge(rhs, result).color(op.color());
+    }
+    graph.highlight_swaps(swaps);
+    graph
+}
+
+/// The circuit as a graph, with the swapped wires highlighted if they can be
+/// found.
+pub fn graph(input: &str) -> Result<Graph, aoc::ParseError> {
+    let circuit = parse_circuit(input)?;
+    let swaps = adder::find_swaps(&circuit).unwrap_or_default();
+    Ok(to_viz(&circuit, &swaps))
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Circuit;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        parse_circuit(input)
+    }
+
+    fn part_b(circuit: &Self::Input) -> Option<String> {
+        let swaps = adder::find_swaps(circuit)?;
+        let mut wires: Vec<&str> = swaps.into_iter().flat_map(|(a, b)| [a, b]).collect();
+        wires.sort();
+        Some(wires.join(","))
+    }
+}
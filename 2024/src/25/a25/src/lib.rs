@@ -0,0 +1,67 @@
+use aoc::Solution;
+
+pub enum Graphic {
+    Lock([i32; 5]),
+    Key([i32; 5]),
+}
+
+fn parse_graphic(section: &aoc_parse::Section) -> aoc_parse::Result<Graphic> {
+    let rows = section.parse_rows(|pin| pin.one_of(&[("#", true), (".", false)]))?;
+    if rows[0].len() != 5 {
+        return Err(section.error(format!("expected 5 columns, found {}", rows[0].len())));
+    }
+    let mut cols = [-1; 5];
+    for row in &rows {
+        for (i, &filled) in row.iter().enumerate() {
+            if filled {
+                cols[i] += 1;
+            }
+        }
+    }
+    if rows[0].iter().all(|&filled| filled) {
+        Ok(Graphic::Lock(cols))
+    } else if rows[0].iter().all(|&filled| !filled) {
+        Ok(Graphic::Key(cols))
+    } else {
+        Err(section.error("expected a lock's top row of `#` or a key's of `.`"))
+    }
+}
+
+fn fits(lock: &[i32; 5], key: &[i32; 5]) -> bool {
+    (0..5).all(|i| lock[i] + key[i] <= 5)
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<Graphic>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::sections(input)
+            .iter()
+            .map(parse_graphic)
+            .collect()
+    }
+
+    fn part_a(graphics: &Self::Input) -> Option<String> {
+        let mut locks = vec![];
+        let mut keys = vec![];
+        for graphic in graphics {
+            match graphic {
+                Graphic::Lock(cols) => locks.push(cols),
+                Graphic::Key(cols) => keys.push(cols),
+            }
+        }
+
+        let mut num_fits = 0;
+        for &lock in &locks {
+            for &key in &keys {
+                if fits(lock, key) {
+                    num_fits += 1;
+                }
+            }
+        }
+
+        Some(num_fits.to_string())
+    }
+}
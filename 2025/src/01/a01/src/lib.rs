@@ -0,0 +1,60 @@
+use aoc::Solution;
+
+#[derive(Debug)]
+pub enum Rotation {
+    Left(i32),
+    Right(i32),
+}
+
+impl Rotation {
+    /// Reads a rotation like `L68`.
+    fn parse(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Self> {
+        let left = line.one_of(&[("L", true), ("R", false)])?;
+        let before = *line;
+        let magnitude = line.int()?;
+        if magnitude < 0 {
+            return Err(before.error("expected a non-negative distance"));
+        }
+        Ok(if left {
+            Self::Left(magnitude)
+        } else {
+            Self::Right(magnitude)
+        })
+    }
+
+    fn apply(&self, mut dial: i32) -> i32 {
+        match self {
+            Rotation::Left(magnitude) => dial -= magnitude,
+            Rotation::Right(magnitude) => dial += magnitude,
+        }
+        while dial < 0 {
+            dial += 100;
+        }
+        while dial > 99 {
+            dial -= 100;
+        }
+        dial
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<Rotation>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, Rotation::parse)
+    }
+
+    fn part_a(rotations: &Self::Input) -> Option<String> {
+        let mut num_zeros = 0;
+        let mut dial = 50;
+        for rotation in rotations {
+            dial = rotation.apply(dial);
+            if dial == 0 {
+                num_zeros += 1;
+            }
+        }
+        Some(num_zeros.to_string())
+    }
+}
@@ -0,0 +1,74 @@
+use aoc::Solution;
+
+#[derive(Clone, Copy, Debug)]
+enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+pub struct Rotation {
+    dir: Direction,
+    magnitude: i32,
+}
+
+impl Rotation {
+    /// Reads a rotation like `L68`.
+    fn parse(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Self> {
+        let dir = line.one_of(&[("L", Direction::Left), ("R", Direction::Right)])?;
+        let before = *line;
+        let magnitude = line.int()?;
+        if magnitude < 0 {
+            return Err(before.error("expected a non-negative distance"));
+        }
+        Ok(Self { dir, magnitude })
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<Rotation>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, Rotation::parse)
+    }
+
+    fn part_b(rotations: &Self::Input) -> Option<String> {
+        let mut num_zeros = 0;
+        let mut dial = 50;
+        for rotation in rotations {
+            let mut incr_zeros = 0;
+
+            let magnitude = if rotation.magnitude >= 100 {
+                incr_zeros += rotation.magnitude / 100;
+                rotation.magnitude % 100
+            } else {
+                rotation.magnitude
+            };
+
+            let mut next_dial = match rotation.dir {
+                Direction::Left => dial - magnitude,
+                Direction::Right => dial + magnitude,
+            };
+            if next_dial == 0 && dial != 0 {
+                incr_zeros += 1;
+            }
+            if next_dial < 0 {
+                next_dial += 100;
+                if dial != 0 {
+                    incr_zeros += 1;
+                }
+            }
+            if next_dial >= 100 {
+                next_dial -= 100;
+                if dial != 0 {
+                    incr_zeros += 1;
+                }
+            }
+            dial = next_dial;
+            num_zeros += incr_zeros;
+        }
+        Some(num_zeros.to_string())
+    }
+}
@@ -0,0 +1,62 @@
+use std::ops::RangeInclusive;
+
+use aoc::Solution;
+
+/// Reads a range of IDs like `11-22`.
+fn parse_range(ids: &mut aoc_parse::Cursor) -> aoc_parse::Result<RangeInclusive<i64>> {
+    let before = *ids;
+    let start = ids.int()?;
+    ids.literal("-")?;
+    let end = ids.int()?;
+    if end < start {
+        return Err(before.error("range ends before it starts"));
+    }
+    Ok(start..=end)
+}
+
+fn is_invalid(n: i64) -> bool {
+    let n_str = n.to_string().into_bytes();
+    if n_str.len() % 2 == 1 {
+        return false;
+    }
+
+    for i in 0..n_str.len() / 2 {
+        if n_str[i] != n_str[i + n_str.len() / 2] {
+            return false;
+        }
+    }
+    true
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<RangeInclusive<i64>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        // The ranges may wrap onto several lines, each ending in a comma.
+        let lines = aoc_parse::parse_lines(input, |line| {
+            let mut ranges = vec![];
+            while !line.is_empty() {
+                ranges.push(parse_range(line)?);
+                if !line.eat(",") {
+                    break;
+                }
+            }
+            Ok(ranges)
+        })?;
+        Ok(lines.into_iter().flatten().collect())
+    }
+
+    fn part_a(ranges: &Self::Input) -> Option<String> {
+        let mut invalid_sum: i64 = 0;
+        for range in ranges {
+            for n in range.clone() {
+                if is_invalid(n) {
+                    invalid_sum += n;
+                }
+            }
+        }
+        Some(invalid_sum.to_string())
+    }
+}
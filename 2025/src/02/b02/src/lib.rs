@@ -0,0 +1,80 @@
+use std::ops::RangeInclusive;
+
+use aoc::Solution;
+
+/// Reads a range of IDs like `11-22`.
+fn parse_range(ids: &mut aoc_parse::Cursor) -> aoc_parse::Result<RangeInclusive<i64>> {
+    let before = *ids;
+    let start = ids.int()?;
+    ids.literal("-")?;
+    let end = ids.int()?;
+    if end < start {
+        return Err(before.error("range ends before it starts"));
+    }
+    Ok(start..=end)
+}
+
+fn is_invalid_with_factor(n_str: &[u8], f: usize) -> bool {
+    if n_str.len() % f != 0 {
+        return false;
+    }
+
+    let step = n_str.len() / f;
+    for i in 0..step {
+        for j in ((i + step)..n_str.len()).step_by(step) {
+            if n_str[i] != n_str[j] {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+fn is_invalid(n: i64) -> bool {
+    let n_str = n.to_string().into_bytes();
+    for f in 2..=n_str.len() {
+        if is_invalid_with_factor(&n_str, f) {
+            return true;
+        }
+    }
+    false
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<RangeInclusive<i64>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        // The ranges may wrap onto several lines, each ending in a comma.
+        let lines = aoc_parse::parse_lines(input, |line| {
+            let mut ranges = vec![];
+            while !line.is_empty() {
+                ranges.push(parse_range(line)?);
+                if !line.eat(",") {
+                    break;
+                }
+            }
+            Ok(ranges)
+        })?;
+        Ok(lines.into_iter().flatten().collect())
+    }
+
+    fn part_b(ranges: &Self::Input) -> Option<String> {
+        let mut invalid_sum: i64 = 0;
+        for range in ranges {
+            for n in range.clone() {
+                if is_invalid(n) {
+                    invalid_sum += n;
+                }
+            }
+        }
+        Some(invalid_sum.to_string())
+    }
+}
+
+#[test]
+fn test_twenty_two() {
+    assert!(is_invalid_with_factor("22".as_bytes(), 2));
+    assert!(is_invalid(22));
+}
@@ -0,0 +1,45 @@
+use aoc::Solution;
+
+fn max_joltage(batteries: &[u8]) -> i32 {
+    let mut best_joltage = 0;
+    let mut first_battery_joltage = 0;
+    for &battery in batteries {
+        let battery_joltage = battery as i32;
+        let joltage = first_battery_joltage * 10 + battery_joltage;
+        if joltage > best_joltage {
+            best_joltage = joltage;
+        }
+        if battery_joltage > first_battery_joltage {
+            first_battery_joltage = battery_joltage;
+        }
+    }
+    best_joltage
+}
+
+/// Reads a bank of single-digit battery joltages.
+fn parse_batteries(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Vec<u8>> {
+    let mut batteries = vec![];
+    while !line.is_empty() {
+        batteries.push(line.digit()?);
+    }
+    Ok(batteries)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<u8>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, parse_batteries)
+    }
+
+    fn part_a(banks: &Self::Input) -> Option<String> {
+        let mut total_joltage = 0;
+        for batteries in banks {
+            total_joltage += max_joltage(batteries);
+        }
+
+        Some(total_joltage.to_string())
+    }
+}
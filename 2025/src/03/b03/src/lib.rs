@@ -0,0 +1,83 @@
+use aoc::Solution;
+
+const MAX_STACK_SIZE: usize = 12;
+
+fn max_joltage(batteries: &[i64]) -> Option<i64> {
+    let mut stack = Vec::with_capacity(MAX_STACK_SIZE);
+
+    for i in 0..batteries.len() {
+        while let Some(last_battery) = stack.last() {
+            if *last_battery >= batteries[i] || i + MAX_STACK_SIZE - stack.len() >= batteries.len()
+            {
+                break;
+            } else {
+                stack.pop();
+            }
+        }
+        if stack.len() < MAX_STACK_SIZE {
+            stack.push(batteries[i]);
+        }
+    }
+
+    if stack.len() < MAX_STACK_SIZE {
+        return None;
+    }
+    let mut joltage = 0;
+    for battery in stack {
+        joltage *= 10;
+        joltage += battery;
+    }
+    Some(joltage)
+}
+
+/// Reads a bank of single-digit battery joltages.
+fn parse_batteries(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Vec<i64>> {
+    let mut batteries = vec![];
+    while !line.is_empty() {
+        batteries.push(line.digit()?.into());
+    }
+    Ok(batteries)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<i64>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, parse_batteries)
+    }
+
+    fn part_b(banks: &Self::Input) -> Option<String> {
+        let mut total_joltage = 0;
+        for batteries in banks {
+            total_joltage += max_joltage(batteries)?;
+        }
+
+        Some(total_joltage.to_string())
+    }
+}
+
+#[test]
+fn first_sample_row() {
+    let batteries = &Day03::parse("987654321111111")[0];
+    assert_eq!(max_joltage(batteries), Some(987654321111));
+}
+
+#[test]
+fn second_sample_row() {
+    let batteries = &Day03::parse("811111111111119")[0];
+    assert_eq!(max_joltage(batteries), Some(811111111119));
+}
+
+#[test]
+fn third_sample_row() {
+    let batteries = &Day03::parse("234234234234278")[0];
+    assert_eq!(max_joltage(batteries), Some(434234234278));
+}
+
+#[test]
+fn fourth_sample_row() {
+    let batteries = &Day03::parse("818181911112111")[0];
+    assert_eq!(max_joltage(batteries), Some(888911112111));
+}
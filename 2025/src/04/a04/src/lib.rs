@@ -0,0 +1,25 @@
+use aoc::Solution;
+use aoc_grid::{Grid, Pos};
+
+fn is_accessible(grid: &Grid, pos: Pos) -> bool {
+    let num_rolls = grid.neighbors8(pos).filter(|&p| grid[p] == b'@').count();
+    num_rolls < 4
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part_a(grid: &Self::Input) -> Option<String> {
+        let num_accessible = grid
+            .find_all(&b'@')
+            .filter(|&pos| is_accessible(grid, pos))
+            .count();
+        Some(num_accessible.to_string())
+    }
+}
@@ -0,0 +1,37 @@
+use aoc::Solution;
+use aoc_grid::{Grid, Pos};
+
+fn is_accessible(grid: &Grid, pos: Pos) -> bool {
+    let num_rolls = grid.neighbors8(pos).filter(|&p| grid[p] == b'@').count();
+    num_rolls < 4
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part_b(grid: &Self::Input) -> Option<String> {
+        let mut grid = grid.clone();
+
+        let mut num_accessible = 0;
+        let mut progress = true;
+        while progress {
+            progress = false;
+            for y in 0..grid.height() {
+                for x in 0..grid.width() {
+                    if grid[(x, y)] == b'@' && is_accessible(&grid, (x, y)) {
+                        num_accessible += 1;
+                        grid[(x, y)] = b'.';
+                        progress = true;
+                    }
+                }
+            }
+        }
+        Some(num_accessible.to_string())
+    }
+}
@@ -0,0 +1,44 @@
+use std::ops::Range;
+
+use aoc::Solution;
+use aoc_interval::IntervalSet;
+
+/// Reads a range like `10-14`.
+fn parse_range(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Range<i64>> {
+    let before = *line;
+    let start = line.int()?;
+    line.literal("-")?;
+    let end: i64 = line.int()?;
+    if end < start {
+        return Err(before.error("range ends before it starts"));
+    }
+    Ok(start..end + 1)
+}
+
+pub struct Inventory {
+    fresh: IntervalSet<i64>,
+    queries: Vec<i64>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Inventory;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let [ranges, queries] = aoc_parse::split_sections(input)?;
+        Ok(Inventory {
+            fresh: ranges.parse_lines(parse_range)?.into_iter().collect(),
+            queries: queries.parse_lines(|line| line.int())?,
+        })
+    }
+
+    fn part_a(inventory: &Self::Input) -> Option<String> {
+        let num_fresh = inventory
+            .queries
+            .iter()
+            .filter(|&&query| inventory.fresh.contains(query))
+            .count();
+        Some(num_fresh.to_string())
+    }
+}
@@ -0,0 +1,31 @@
+use std::ops::Range;
+
+use aoc::Solution;
+use aoc_interval::IntervalSet;
+
+/// Reads a range like `10-14`.
+fn parse_range(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Range<i64>> {
+    let before = *line;
+    let start = line.int()?;
+    line.literal("-")?;
+    let end: i64 = line.int()?;
+    if end < start {
+        return Err(before.error("range ends before it starts"));
+    }
+    Ok(start..end + 1)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = IntervalSet<i64>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let [ranges, _queries] = aoc_parse::split_sections(input)?;
+        Ok(ranges.parse_lines(parse_range)?.into_iter().collect())
+    }
+
+    fn part_b(fresh: &Self::Input) -> Option<String> {
+        Some(fresh.len().to_string())
+    }
+}
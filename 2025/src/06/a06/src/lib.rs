@@ -0,0 +1,80 @@
+use aoc::Solution;
+
+#[derive(Clone, Copy, Debug)]
+enum Operator {
+    Plus,
+    Times,
+}
+
+/// One problem on the worksheet: its operator and the numbers it combines.
+pub struct Problem {
+    op: Operator,
+    nums: Vec<i64>,
+}
+
+/// Reads the numbers on a worksheet row, however widely they're spaced.
+fn parse_row(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Vec<i64>> {
+    let mut nums = vec![];
+    line.skip_spaces();
+    while !line.is_empty() {
+        nums.push(line.int()?);
+        line.skip_spaces();
+    }
+    Ok(nums)
+}
+
+fn parse_operators(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Vec<Operator>> {
+    let mut ops = vec![];
+    line.skip_spaces();
+    while !line.is_empty() {
+        ops.push(line.one_of(&[("+", Operator::Plus), ("*", Operator::Times)])?);
+        line.skip_spaces();
+    }
+    Ok(ops)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Problem>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let [sheet] = aoc_parse::split_sections(input)?;
+        let mut lines: Vec<_> = sheet.lines().collect();
+        let mut op_line = lines
+            .pop()
+            .ok_or_else(|| sheet.error("expected a worksheet"))?;
+        let ops = parse_operators(&mut op_line)?;
+        let mut problems: Vec<Problem> = ops
+            .into_iter()
+            .map(|op| Problem { op, nums: vec![] })
+            .collect();
+        for mut line in lines {
+            let before = line;
+            let row = parse_row(&mut line)?;
+            if row.len() != problems.len() {
+                return Err(before.error(format!(
+                    "expected {} numbers, found {}",
+                    problems.len(),
+                    row.len()
+                )));
+            }
+            for (problem, num) in problems.iter_mut().zip(row) {
+                problem.nums.push(num);
+            }
+        }
+        Ok(problems)
+    }
+
+    fn part_a(problems: &Self::Input) -> Option<String> {
+        let mut answer_total = 0;
+        for Problem { op, nums } in problems {
+            let answer: i64 = match op {
+                Operator::Plus => nums.iter().sum::<i64>(),
+                Operator::Times => nums.iter().product::<i64>(),
+            };
+            answer_total += answer;
+        }
+        Some(answer_total.to_string())
+    }
+}
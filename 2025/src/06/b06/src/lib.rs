@@ -0,0 +1,93 @@
+use aoc::Solution;
+
+#[derive(Clone, Copy, Debug)]
+enum Operator {
+    Plus,
+    Times,
+}
+
+/// One problem on the worksheet: its operator and the numbers it combines.
+pub struct Problem {
+    op: Operator,
+    nums: Vec<i64>,
+}
+
+/// Reads a row of digit cells, with `None` for the spaces between them.
+fn parse_digits(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Vec<Option<u8>>> {
+    let mut cells = vec![];
+    while !line.is_empty() {
+        cells.push(if line.eat(" ") {
+            None
+        } else {
+            Some(line.digit()?)
+        });
+    }
+    Ok(cells)
+}
+
+/// Reads the operator row, returning each operator with the column it's in.
+fn parse_operators(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Vec<(Operator, usize)>> {
+    let mut ops = vec![];
+    let mut col = 0;
+    while !line.is_empty() {
+        if !line.eat(" ") {
+            ops.push((
+                line.one_of(&[("+", Operator::Plus), ("*", Operator::Times)])?,
+                col,
+            ));
+        }
+        col += 1;
+    }
+    Ok(ops)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Problem>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let [sheet] = aoc_parse::split_sections(input)?;
+        let mut lines: Vec<_> = sheet.lines().collect();
+        let mut op_line = lines
+            .pop()
+            .ok_or_else(|| sheet.error("expected a worksheet"))?;
+        let ops = parse_operators(&mut op_line)?;
+        let rows = lines
+            .into_iter()
+            .map(|mut line| parse_digits(&mut line))
+            .collect::<aoc_parse::Result<Vec<_>>>()?;
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+
+        // Each problem's numbers are read down the columns up to the next
+        // operator.
+        let mut problems = Vec::new();
+        for (i, &(op, start)) in ops.iter().enumerate() {
+            let end = ops.get(i + 1).map_or(width, |&(_, next)| next);
+            let mut nums = Vec::new();
+            for c in (start..end).rev() {
+                let mut num = None;
+                for row in &rows {
+                    if let Some(&Some(digit)) = row.get(c) {
+                        num = Some(num.unwrap_or(0) * 10 + digit as i64);
+                    }
+                }
+                nums.extend(num);
+            }
+            problems.push(Problem { op, nums });
+        }
+        Ok(problems)
+    }
+
+    fn part_b(problems: &Self::Input) -> Option<String> {
+        let mut answer_total = 0;
+        for Problem { op, nums } in problems {
+            let answer: i64 = match op {
+                Operator::Plus => nums.iter().sum::<i64>(),
+                Operator::Times => nums.iter().product::<i64>(),
+            };
+            answer_total += answer;
+        }
+        Some(answer_total.to_string())
+    }
+}
@@ -0,0 +1,59 @@
+use aoc::Solution;
+
+/// The tachyon manifold: where the beam enters along the top row, and which
+/// cells hold splitters.
+pub struct Manifold {
+    start: usize,
+    splitters: Vec<Vec<bool>>,
+}
+
+fn parse_manifold(input: &str) -> aoc_parse::Result<Manifold> {
+    let rows = aoc_parse::parse_rows(input, |cell| {
+        cell.one_of(&[(".", b'.'), ("S", b'S'), ("^", b'^')])
+    })?;
+    let start = rows[0]
+        .iter()
+        .position(|&cell| cell == b'S')
+        .ok_or_else(|| aoc::ParseError::new(1, 1, "expected an `S` in the top row"))?;
+    let splitters = rows
+        .iter()
+        .map(|row| row.iter().map(|&cell| cell == b'^').collect())
+        .collect();
+    Ok(Manifold { start, splitters })
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Manifold;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        parse_manifold(input)
+    }
+
+    fn part_a(manifold: &Self::Input) -> Option<String> {
+        let width = manifold.splitters[0].len();
+        let mut beams = vec![false; width];
+        beams[manifold.start] = true;
+
+        let mut num_splits = 0;
+        for row in &manifold.splitters[1..] {
+            let mut next_beams = vec![false; width];
+            for c in (0..width).filter(|&c| beams[c]) {
+                if row[c] {
+                    num_splits += 1;
+                    if c > 0 {
+                        next_beams[c - 1] = true;
+                    }
+                    if c + 1 < width {
+                        next_beams[c + 1] = true;
+                    }
+                } else {
+                    next_beams[c] = true;
+                }
+            }
+            beams = next_beams;
+        }
+        Some(num_splits.to_string())
+    }
+}
@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+
+use aoc::Solution;
+
+/// The tachyon manifold: where the beam enters along the top row, and which
+/// cells hold splitters.
+pub struct Manifold {
+    start: usize,
+    splitters: Vec<Vec<bool>>,
+}
+
+fn parse_manifold(input: &str) -> aoc_parse::Result<Manifold> {
+    let rows = aoc_parse::parse_rows(input, |cell| {
+        cell.one_of(&[(".", b'.'), ("S", b'S'), ("^", b'^')])
+    })?;
+    let start = rows[0]
+        .iter()
+        .position(|&cell| cell == b'S')
+        .ok_or_else(|| aoc::ParseError::new(1, 1, "expected an `S` in the top row"))?;
+    let splitters = rows
+        .iter()
+        .map(|row| row.iter().map(|&cell| cell == b'^').collect())
+        .collect();
+    Ok(Manifold { start, splitters })
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Manifold;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        parse_manifold(input)
+    }
+
+    fn part_b(manifold: &Self::Input) -> Option<String> {
+        let width = manifold.splitters[0].len();
+        let mut column_timelines = HashMap::new();
+        column_timelines.insert(manifold.start, 1_i64);
+        for row in &manifold.splitters[1..] {
+            let mut next_column_timelines = HashMap::with_capacity(2 * column_timelines.len());
+            for (&c, &num_timelines) in column_timelines.iter() {
+                if row[c] {
+                    // A beam split off the edge of the manifold is lost.
+                    for next_c in [c.wrapping_sub(1), c + 1] {
+                        if next_c < width {
+                            next_column_timelines
+                                .entry(next_c)
+                                .and_modify(|n| *n += num_timelines)
+                                .or_insert(num_timelines);
+                        }
+                    }
+                } else {
+                    next_column_timelines
+                        .entry(c)
+                        .and_modify(|n| *n += num_timelines)
+                        .or_insert(num_timelines);
+                }
+            }
+            std::mem::swap(&mut column_timelines, &mut next_column_timelines);
+        }
+
+        let mut total_timelines = 0;
+        for (_, &num_timelines) in column_timelines.iter() {
+            total_timelines += num_timelines;
+        }
+        Some(total_timelines.to_string())
+    }
+}
@@ -0,0 +1,38 @@
+use aoc::Solution;
+
+pub struct Point {
+    x: i64,
+    y: i64,
+}
+
+/// Reads a red tile's position like `7,1`.
+fn parse_point(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Point> {
+    let x = line.int()?;
+    line.literal(",")?;
+    let y = line.int()?;
+    Ok(Point { x, y })
+}
+
+fn area(point1: &Point, point2: &Point) -> i64 {
+    ((point1.x - point2.x).abs() + 1) * ((point1.y - point2.y).abs() + 1)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Point>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, parse_point)
+    }
+
+    fn part_a(points: &Self::Input) -> Option<String> {
+        let mut best_area = 0;
+        for i in 0..points.len() {
+            for j in i + 1..points.len() {
+                best_area = best_area.max(area(&points[i], &points[j]));
+            }
+        }
+        Some(best_area.to_string())
+    }
+}
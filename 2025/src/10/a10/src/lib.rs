@@ -0,0 +1,176 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashSet},
+    fmt::Display,
+};
+
+use aoc::Solution;
+
+/// One line of the manual: the lights to turn on, the buttons, and the
+/// joltage each counter has to reach.
+#[derive(Clone, Debug)]
+pub struct Machine {
+    pub goal: u64,
+    pub buttons: Vec<Button>,
+    pub joltages: Vec<u64>,
+}
+
+/// The lights (or counters) a button is wired to, one bit each.
+#[derive(Clone, Copy, Debug)]
+pub struct Button {
+    pub bits: u64,
+}
+
+impl Button {
+    /// Whether pressing the button toggles light `i`, or bumps counter `i`.
+    pub fn touches(&self, i: usize) -> bool {
+        (self.bits >> i) & 1 == 1
+    }
+
+    fn highest_light(&self) -> usize {
+        let mut highest_light = 0;
+        while self.bits >> highest_light > 0 {
+            highest_light += 1;
+        }
+        highest_light
+    }
+}
+
+impl Display for Button {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, " (")?;
+        let mut comma = "";
+        for i in 0..63 {
+            if ((self.bits >> i) & 1) == 1 {
+                write!(f, "{comma}{i}")?;
+                comma = ",";
+            }
+        }
+        write!(f, ")")
+    }
+}
+
+impl Machine {
+    /// Reads a line like `[.##.] (3) (1,3) (2) {3,5,4,7}`.
+    pub fn parse(line: &mut aoc_parse::Cursor) -> aoc_parse::Result<Self> {
+        line.literal("[")?;
+        let mut goal = 0;
+        let mut num_lights = 0;
+        while !line.eat("]") {
+            if num_lights == 64 {
+                return Err(line.error("expected at most 64 lights"));
+            }
+            if line.one_of(&[("#", true), (".", false)])? {
+                goal |= 1 << num_lights;
+            }
+            num_lights += 1;
+        }
+
+        let mut buttons = vec![];
+        line.skip_spaces();
+        while line.eat("(") {
+            let lights = line.list(",", |light| {
+                let before = *light;
+                let i: usize = light.int()?;
+                if i >= num_lights {
+                    return Err(before.error(format!("expected a light below {num_lights}")));
+                }
+                Ok(i)
+            })?;
+            line.literal(")")?;
+            let bits = lights.iter().fold(0, |bits, light| bits | 1 << light);
+            buttons.push(Button { bits });
+            line.skip_spaces();
+        }
+
+        line.literal("{")?;
+        let before = *line;
+        let joltages: Vec<u64> = line.ints(",")?;
+        if joltages.len() != num_lights {
+            return Err(before.error(format!(
+                "expected {num_lights} joltages, found {}",
+                joltages.len()
+            )));
+        }
+        line.literal("}")?;
+        Ok(Self {
+            goal,
+            buttons,
+            joltages,
+        })
+    }
+}
+
+impl Display for Machine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let num_lights = self
+            .buttons
+            .iter()
+            .map(|button| button.highest_light())
+            .max()
+            .unwrap();
+
+        write!(f, "[")?;
+        for i in 0..num_lights {
+            if ((self.goal >> i) & 1) == 1 {
+                write!(f, "#")?;
+            } else {
+                write!(f, ".")?;
+            }
+        }
+        write!(f, "]")?;
+
+        for button in &self.buttons {
+            write!(f, "{button}")?;
+        }
+        if !self.joltages.is_empty() {
+            let joltages: Vec<String> = self.joltages.iter().map(u64::to_string).collect();
+            write!(f, " {{{}}}", joltages.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+fn solve_machine(machine: &Machine) -> Option<u64> {
+    let Machine { goal, buttons, .. } = machine;
+    let mut frontier = BinaryHeap::new();
+    frontier.push(Reverse((0, 0, 0, 0)));
+    let mut seen = HashSet::new();
+    while let Some(Reverse((num_presses, state, index, presses))) = frontier.pop() {
+        if state == *goal {
+            return Some(presses);
+        }
+        if index >= buttons.len() {
+            continue;
+        }
+        if !seen.insert((state, index)) {
+            continue;
+        }
+        frontier.push(Reverse((
+            num_presses + 1,
+            state ^ buttons[index].bits,
+            index + 1,
+            presses | (1 << index),
+        )));
+        frontier.push(Reverse((num_presses, state, index + 1, presses)));
+    }
+    None
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Machine>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, Machine::parse)
+    }
+
+    fn part_a(machines: &Self::Input) -> Option<String> {
+        let presses = aoc::par_map(machines, |machine| {
+            solve_machine(machine).map(|presses| presses.count_ones())
+        });
+        let total_presses: u32 = presses.into_iter().sum::<Option<u32>>()?;
+        Some(total_presses.to_string())
+    }
+}
@@ -0,0 +1,275 @@
+use aoc::Solution;
+use aoc_num::{gcd, lcm};
+use aoc2025_a10::Machine;
+
+/// Divides a row through by the gcd of its entries, so repeated
+/// elimination doesn't blow up the numbers.
+fn normalize(row: &mut [i128]) {
+    let divisor = row.iter().fold(0, |acc, &n| gcd(acc, n));
+    if divisor > 1 {
+        for n in row {
+            *n /= divisor;
+        }
+    }
+}
+
+/// The system `presses · buttons = joltages`, in reduced row echelon form.
+///
+/// Each row is one equation over the rationals, scaled up to whole numbers:
+/// the coefficients of every button, then the target. A row with a pivot
+/// says `d · pivot + Σ c · free = target`, with `d > 0` and no other pivot
+/// appearing in it.
+struct Reduced {
+    rows: Vec<Vec<i128>>,
+    pivots: Vec<usize>,
+    free: Vec<usize>,
+    /// The total presses, times `scale`, come to `base + Σ weight · free`.
+    scale: i128,
+    base: i128,
+    weights: Vec<i128>,
+}
+
+impl Reduced {
+    /// Gaussian elimination, or `None` if no presses at all, even fractional
+    /// or negative ones, give the right joltages.
+    fn new(machine: &Machine) -> Option<Self> {
+        let num_buttons = machine.buttons.len();
+        let mut rows: Vec<Vec<i128>> = machine
+            .joltages
+            .iter()
+            .enumerate()
+            .map(|(i, &joltage)| {
+                let mut row: Vec<i128> = machine
+                    .buttons
+                    .iter()
+                    .map(|button| button.touches(i) as i128)
+                    .collect();
+                row.push(joltage as i128);
+                row
+            })
+            .collect();
+
+        let mut pivots = vec![];
+        let mut free = vec![];
+        for col in 0..num_buttons {
+            let r = pivots.len();
+            let Some(found) = (r..rows.len()).find(|&i| rows[i][col] != 0) else {
+                free.push(col);
+                continue;
+            };
+            rows.swap(r, found);
+            if rows[r][col] < 0 {
+                rows[r].iter_mut().for_each(|n| *n = -*n);
+            }
+            normalize(&mut rows[r]);
+            for i in 0..rows.len() {
+                let factor = rows[i][col];
+                if i == r || factor == 0 {
+                    continue;
+                }
+                let pivot_row = rows[r].clone();
+                let pivot = pivot_row[col];
+                for (n, &p) in rows[i].iter_mut().zip(&pivot_row) {
+                    *n = *n * pivot - p * factor;
+                }
+                normalize(&mut rows[i]);
+            }
+            pivots.push(col);
+        }
+
+        // Whatever is left below the pivots reads `0 = target`.
+        if rows[pivots.len()..].iter().any(|row| row[num_buttons] != 0) {
+            return None;
+        }
+        rows.truncate(pivots.len());
+
+        let scale = rows
+            .iter()
+            .zip(&pivots)
+            .fold(1, |acc, (row, &pivot)| lcm(acc, row[pivot]));
+        let per_row: Vec<i128> = rows
+            .iter()
+            .zip(&pivots)
+            .map(|(row, &pivot)| scale / row[pivot])
+            .collect();
+        let base = rows
+            .iter()
+            .zip(&per_row)
+            .map(|(row, k)| row[num_buttons] * k)
+            .sum();
+        let weights = free
+            .iter()
+            .map(|&col| {
+                scale
+                    - rows
+                        .iter()
+                        .zip(&per_row)
+                        .map(|(row, k)| row[col] * k)
+                        .sum::<i128>()
+            })
+            .collect();
+        Some(Self {
+            rows,
+            pivots,
+            free,
+            scale,
+            base,
+            weights,
+        })
+    }
+
+    /// Whether no way of pressing the free buttons not yet decided, up to
+    /// `bounds`, can lead anywhere: either some pivot button would have to
+    /// be pressed a negative number of times, or the total can't come in
+    /// under `best`.
+    fn hopeless(&self, free_presses: &[i128], bounds: &[i128], best: Option<i128>) -> bool {
+        let decided = free_presses.len();
+        for row in &self.rows {
+            let mut most = row[row.len() - 1];
+            for (k, &col) in self.free.iter().enumerate() {
+                if k < decided {
+                    most -= row[col] * free_presses[k];
+                } else {
+                    most -= row[col].min(0) * bounds[k];
+                }
+            }
+            if most < 0 {
+                return true;
+            }
+        }
+        let Some(best) = best else {
+            return false;
+        };
+        let mut least = self.base;
+        for (k, &weight) in self.weights.iter().enumerate() {
+            if k < decided {
+                least += weight * free_presses[k];
+            } else {
+                least += weight.min(0) * bounds[k];
+            }
+        }
+        least >= best * self.scale
+    }
+
+    /// The total presses, given how often each free button is pressed, or
+    /// `None` if that leaves some pivot button pressed a negative or
+    /// fractional number of times.
+    fn total(&self, free_presses: &[i128]) -> Option<i128> {
+        let mut total: i128 = free_presses.iter().sum();
+        for (row, &pivot) in self.rows.iter().zip(&self.pivots) {
+            let rhs = row[row.len() - 1]
+                - self
+                    .free
+                    .iter()
+                    .zip(free_presses)
+                    .map(|(&col, &presses)| row[col] * presses)
+                    .sum::<i128>();
+            let d = row[pivot];
+            if rhs < 0 || rhs % d != 0 {
+                return None;
+            }
+            total += rhs / d;
+        }
+        Some(total)
+    }
+}
+
+/// Tries every number of presses of each free button, up to `bounds`,
+/// keeping the lowest total and skipping any branch that's
+/// [hopeless](Reduced::hopeless).
+fn search(
+    reduced: &Reduced,
+    bounds: &[i128],
+    free_presses: &mut Vec<i128>,
+    best: &mut Option<i128>,
+) {
+    if reduced.hopeless(free_presses, bounds, *best) {
+        return;
+    }
+    let Some(&bound) = bounds.get(free_presses.len()) else {
+        if let Some(total) = reduced.total(free_presses) {
+            *best = Some(best.map_or(total, |best| best.min(total)));
+        }
+        return;
+    };
+    for presses in 0..=bound {
+        free_presses.push(presses);
+        search(reduced, bounds, free_presses, best);
+        free_presses.pop();
+    }
+}
+
+/// The fewest presses that bring every counter up to its joltage exactly,
+/// or `None` if there's no way to.
+fn min_presses(machine: &Machine) -> Option<u64> {
+    let reduced = Reduced::new(machine)?;
+    // A button can't be pressed more often than the lowest target among
+    // the counters it bumps.
+    let bounds: Vec<i128> = reduced
+        .free
+        .iter()
+        .map(|&col| {
+            let button = machine.buttons[col];
+            machine
+                .joltages
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| button.touches(i))
+                .map(|(_, &joltage)| joltage as i128)
+                .min()
+                .unwrap_or(0)
+        })
+        .collect();
+    let mut best = None;
+    search(&reduced, &bounds, &mut vec![], &mut best);
+    best.map(|best| best as u64)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Machine>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        aoc_parse::parse_lines(input, Machine::parse)
+    }
+
+    fn part_b(machines: &Self::Input) -> Option<String> {
+        let presses = aoc::par_map(machines, min_presses);
+        let stuck: Vec<String> = machines
+            .iter()
+            .zip(&presses)
+            .filter(|(_, presses)| presses.is_none())
+            .map(|(machine, _)| machine.to_string())
+            .collect();
+        if !stuck.is_empty() {
+            panic!("No presses reach the joltages of:\n{}", stuck.join("\n"));
+        }
+        let total: u64 = presses.into_iter().flatten().sum();
+        Some(total.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample() {
+        let machines = Day10::parse(include_str!("../../sample.txt"));
+        let presses: Vec<_> = machines.iter().map(min_presses).collect();
+        assert_eq!(presses, [Some(10), Some(12), Some(11)]);
+    }
+
+    #[test]
+    fn test_no_solution() {
+        // Every counter wants half a press of each button.
+        let fractional = &Day10::parse("[...] (0,1) (1,2) (0,2) {1,1,1}")[0];
+        assert_eq!(min_presses(fractional), None);
+        let doubled = &Day10::parse("[...] (0,1) (1,2) (0,2) {2,2,2}")[0];
+        assert_eq!(min_presses(doubled), Some(3));
+        // Nothing bumps counter 1.
+        let unreachable = &Day10::parse("[..] (0) {1,2}")[0];
+        assert_eq!(min_presses(unreachable), None);
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc::run::<aoc2025_b10::Day10>(&aoc::read_stdin());
+}
@@ -0,0 +1,68 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc::Solution;
+
+/// Reads lines like `aaa: you hhh`, checking that every output is either
+/// `out` or a device with a line of its own.
+fn parse_devices(input: &str) -> aoc_parse::Result<HashMap<String, Vec<String>>> {
+    let mut lines = vec![];
+    for mut line in aoc_parse::lines(input) {
+        let device = line.word()?;
+        line.literal(":")?;
+        line.skip_spaces();
+        let outputs = line.list(" ", |output| Ok((*output, output.word()?)))?;
+        line.end()?;
+        lines.push((device, outputs));
+    }
+    let devices: HashSet<&str> = lines.iter().map(|&(device, _)| device).collect();
+    let mut adjs = HashMap::new();
+    for (device, outputs) in lines {
+        let mut adj = vec![];
+        for (before, output) in outputs {
+            if output != "out" && !devices.contains(output) {
+                return Err(before.error(format!("no device named `{output}`")));
+            }
+            adj.push(output.to_owned());
+        }
+        adjs.insert(device.to_owned(), adj);
+    }
+    Ok(adjs)
+}
+
+fn get_num_paths(
+    cache: &mut HashMap<String, usize>,
+    adjs: &HashMap<String, Vec<String>>,
+    node1: &str,
+) -> usize {
+    if let Some(ans) = cache.get(node1) {
+        return *ans;
+    }
+    if node1 == "out" {
+        return 1;
+    }
+
+    let mut total = 0;
+    for adj in adjs.get(node1).unwrap() {
+        total += get_num_paths(cache, adjs, &adj);
+    }
+    total
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = HashMap<String, Vec<String>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        parse_devices(input)
+    }
+
+    fn part_a(adjs: &Self::Input) -> Option<String> {
+        if !adjs.contains_key("you") {
+            return None;
+        }
+        let mut cache = HashMap::new();
+        let num_you_paths = get_num_paths(&mut cache, adjs, "you");
+        Some(num_you_paths.to_string())
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc::Solution;
+
+/// Reads lines like `aaa: you hhh`, checking that every output is either
+/// `out` or a device with a line of its own.
+fn parse_devices(input: &str) -> aoc_parse::Result<HashMap<String, Vec<String>>> {
+    let mut lines = vec![];
+    for mut line in aoc_parse::lines(input) {
+        let device = line.word()?;
+        line.literal(":")?;
+        line.skip_spaces();
+        let outputs = line.list(" ", |output| Ok((*output, output.word()?)))?;
+        line.end()?;
+        lines.push((device, outputs));
+    }
+    let devices: HashSet<&str> = lines.iter().map(|&(device, _)| device).collect();
+    let mut adjs = HashMap::new();
+    for (device, outputs) in lines {
+        let mut adj = vec![];
+        for (before, output) in outputs {
+            if output != "out" && !devices.contains(output) {
+                return Err(before.error(format!("no device named `{output}`")));
+            }
+            adj.push(output.to_owned());
+        }
+        adjs.insert(device.to_owned(), adj);
+    }
+    Ok(adjs)
+}
+
+fn get_num_paths(
+    cache: &mut HashMap<(String, bool, bool), usize>,
+    adjs: &HashMap<String, Vec<String>>,
+    (label1, mut dac, mut fft): (&str, bool, bool),
+) -> usize {
+    dac = dac || label1 == "dac";
+    fft = fft || label1 == "fft";
+
+    if let Some(ans) = cache.get(&(label1.to_owned(), dac, fft)) {
+        return *ans;
+    }
+    if label1 == "out" {
+        if dac && fft {
+            return 1;
+        } else {
+            return 0;
+        }
+    }
+
+    let mut total = 0;
+    for label2 in adjs.get(label1).unwrap() {
+        total += get_num_paths(cache, adjs, (label2, dac, fft));
+    }
+    cache.insert((label1.to_owned(), dac, fft), total);
+    total
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = HashMap<String, Vec<String>>;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        parse_devices(input)
+    }
+
+    fn part_b(adjs: &Self::Input) -> Option<String> {
+        if !adjs.contains_key("svr") {
+            return None;
+        }
+        let mut cache = HashMap::new();
+        let num_you_paths = get_num_paths(&mut cache, adjs, ("svr", false, false));
+        Some(num_you_paths.to_string())
+    }
+}
+
+/// The devices as a directed graph, with one of the shortest paths from
+/// `svr` to `out` that passes through both `dac` and `fft` highlighted.
+pub fn graph(input: &str) -> Result<aoc_viz::Graph, aoc::ParseError> {
+    let adjs = Day11::try_parse(input)?;
+    let mut devices: Vec<&String> = adjs.keys().collect();
+    devices.sort();
+
+    let mut graph = aoc_viz::Graph::directed();
+    for device in ["svr", "dac", "fft", "out"] {
+        graph.node(device).shape(aoc_viz::Shape::Box);
+    }
+    for &device in &devices {
+        for output in &adjs[device] {
+            graph.edge(device, output);
+        }
+    }
+
+    // Search over (device, seen dac, seen fft) so the path can't skip either.
+    let path = aoc_search::bfs(
+        [("svr", false, false)],
+        |&(device, dac, fft)| {
+            adjs.get(device)
+                .into_iter()
+                .flatten()
+                .map(|output| {
+                    (
+                        output.as_str(),
+                        dac || output == "dac",
+                        fft || output == "fft",
+                    )
+                })
+                .collect::<Vec<_>>()
+        },
+        |&(device, dac, fft)| device == "out" && dac && fft,
+    );
+    if let Some(path) = path {
+        let path: Vec<&str> = path.into_iter().map(|(device, _, _)| device).collect();
+        graph.highlight_path(&path);
+    }
+    Ok(graph)
+}
@@ -0,0 +1,188 @@
+use aoc::Solution;
+use aoc_cover::{ExactCover, Polyomino};
+use aoc_parse::{Cursor, Section};
+
+/// An area under a tree, and how many of each present have to fit in it.
+#[derive(Clone, Debug)]
+pub struct Region {
+    pub width: usize,
+    pub height: usize,
+    pub counts: Vec<usize>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Farm {
+    pub shapes: Vec<Polyomino>,
+    pub regions: Vec<Region>,
+}
+
+/// Reads a present like
+///
+/// ```text
+/// 4:
+/// ###
+/// #..
+/// ###
+/// ```
+fn parse_shape(section: &Section, index: usize) -> aoc_parse::Result<Polyomino> {
+    let mut lines = section.lines();
+    let mut header = lines.next().unwrap();
+    let before = header;
+    if header.int::<usize>()? != index {
+        return Err(before.error(format!("expected present {index}")));
+    }
+    header.literal(":")?;
+    header.end()?;
+
+    let mut cells = vec![];
+    for (r, mut line) in lines.enumerate() {
+        let mut c = 0;
+        while !line.is_empty() {
+            if line.one_of(&[("#", true), (".", false)])? {
+                cells.push((r, c));
+            }
+            c += 1;
+        }
+    }
+    if cells.is_empty() {
+        return Err(section.error_at_end("expected a present with at least one `#`"));
+    }
+    Ok(Polyomino::new(cells))
+}
+
+/// Reads a region like `12x5: 1 0 1 0 2 2`.
+fn parse_region(line: &mut Cursor, num_shapes: usize) -> aoc_parse::Result<Region> {
+    let width = line.int()?;
+    line.literal("x")?;
+    let height = line.int()?;
+    line.literal(":")?;
+    line.skip_spaces();
+    let before = *line;
+    let counts = line.ints(" ")?;
+    if counts.len() != num_shapes {
+        return Err(before.error(format!(
+            "expected a count for each of the {num_shapes} presents, found {}",
+            counts.len()
+        )));
+    }
+    Ok(Region {
+        width,
+        height,
+        counts,
+    })
+}
+
+/// Whether every present the region needs can be packed into it without
+/// overlapping.
+pub fn fits(shapes: &[Polyomino], region: &Region) -> bool {
+    let area = region.width * region.height;
+    let needed: usize = shapes
+        .iter()
+        .zip(&region.counts)
+        .map(|(shape, count)| shape.area() * count)
+        .sum();
+    if needed > area {
+        return false;
+    }
+    // If each present gets a square of its own, turned whichever way, there
+    // is no packing to do.
+    let size = shapes
+        .iter()
+        .zip(&region.counts)
+        .filter(|&(_, &count)| count > 0)
+        .map(|(shape, _)| shape.height().max(shape.width()))
+        .max()
+        .unwrap_or(1);
+    let total: usize = region.counts.iter().sum();
+    if (region.width / size) * (region.height / size) >= total {
+        return true;
+    }
+
+    // A column for each present, which has to be put down exactly once, and
+    // one for each cell, which at most one present can cover.
+    let mut cover = ExactCover::new(total, area);
+    let mut first = 0;
+    for (shape, &count) in shapes.iter().zip(&region.counts) {
+        for orientation in shape.orientations() {
+            for cells in orientation.placements(region.width, region.height) {
+                for present in first..first + count {
+                    let mut columns = vec![present];
+                    columns.extend(cells.iter().map(|&i| total + i));
+                    cover.add_row(&columns);
+                }
+            }
+        }
+        first += count;
+    }
+    cover.first().is_some()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Farm;
+
+    fn try_parse(input: &str) -> Result<Self::Input, aoc::ParseError> {
+        let sections = aoc_parse::sections(input);
+        let Some((regions, shapes)) = sections.split_last() else {
+            return Err(aoc::ParseError::new(1, 1, "expected presents and regions"));
+        };
+        let shapes = shapes
+            .iter()
+            .enumerate()
+            .map(|(i, section)| parse_shape(section, i))
+            .collect::<Result<Vec<_>, _>>()?;
+        let regions = regions.parse_lines(|line| parse_region(line, shapes.len()))?;
+        Ok(Farm { shapes, regions })
+    }
+
+    fn part_a(farm: &Self::Input) -> Option<String> {
+        let num_fit = farm
+            .regions
+            .iter()
+            .filter(|region| fits(&farm.shapes, region))
+            .count();
+        Some(num_fit.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample() {
+        let farm = Day12::parse(include_str!("../../sample.txt"));
+        let fit: Vec<bool> = farm
+            .regions
+            .iter()
+            .map(|region| fits(&farm.shapes, region))
+            .collect();
+        assert_eq!(fit, [true, true, false]);
+    }
+
+    #[test]
+    fn test_area_checks() {
+        let farm = Day12::parse(include_str!("../../sample.txt"));
+        let region = |width, height, count| Region {
+            width,
+            height,
+            counts: vec![count, 0, 0, 0, 0, 0],
+        };
+        // A square each, with no packing needed.
+        assert!(fits(&farm.shapes, &region(60, 60, 400)));
+        // More cells of present than of region.
+        assert!(!fits(&farm.shapes, &region(60, 60, 515)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Day12::try_parse("0:\n#x\n\n2x2: 1").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: expected one of `#`, `.`, found `x`"
+        );
+        let err = Day12::try_parse("0:\n##\n\n2x2: 1 1").unwrap_err();
+        assert_eq!(err.line, 4);
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc::run::<aoc2025_a12::Day12>(&aoc::read_stdin());
+}
@@ -0,0 +1,358 @@
+use std::{
+    collections::HashMap,
+    fmt::Write as _,
+    fs, panic,
+    path::{Path, PathBuf},
+    process::Command,
+    time::Duration,
+};
+
+use aoc::Part;
+
+use crate::{
+    check::{self, Manifest, SKIP},
+    Entry,
+};
+
+const DEFAULT_RUNS: usize = 5;
+
+/// How one solution fared on one input over every run.
+struct Timing {
+    year: u32,
+    day: u32,
+    part: Part,
+    file: String,
+    outcome: Result<Phases, String>,
+}
+
+/// The median time spent in each phase, plus the spread of the totals.
+struct Phases {
+    parse: Duration,
+    solve: Duration,
+    total: Duration,
+    fastest: Duration,
+    slowest: Duration,
+}
+
+impl Timing {
+    fn label(&self) -> String {
+        format!("{} {:02}{} {}", self.year, self.day, self.part, self.file)
+    }
+
+    fn total(&self) -> Option<Duration> {
+        self.outcome.as_ref().ok().map(|phases| phases.total)
+    }
+}
+
+fn median(mut times: Vec<Duration>) -> Duration {
+    times.sort();
+    times[times.len() / 2]
+}
+
+/// The files to time a day against: its real inputs, or its samples if the
+/// inputs aren't checked in.
+fn bench_files(dir: &Path) -> Vec<String> {
+    let files = check::input_files(dir);
+    let inputs: Vec<String> = files
+        .iter()
+        .filter(|file| file.starts_with("input"))
+        .cloned()
+        .collect();
+    if inputs.is_empty() {
+        files
+    } else {
+        inputs
+    }
+}
+
+/// Runs `entry` against `input` `runs` times, stopping at the first failure.
+fn time_entry(entry: &Entry, input: &str, runs: usize) -> Result<Phases, String> {
+    let &(_, part, solve) = entry;
+    let (mut parse, mut solve_times, mut totals) = (vec![], vec![], vec![]);
+    for _ in 0..runs {
+        let solved = check::run_quietly(solve, input, part)?;
+        parse.push(solved.parse_time);
+        solve_times.push(solved.solve_time);
+        totals.push(solved.parse_time + solved.solve_time);
+    }
+    Ok(Phases {
+        parse: median(parse),
+        solve: median(solve_times),
+        fastest: *totals.iter().min().unwrap(),
+        slowest: *totals.iter().max().unwrap(),
+        total: median(totals),
+    })
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// The report as JSON, slowest first. Times are medians in nanoseconds.
+fn to_json(timings: &[Timing], runs: usize, commit: Option<&str>, parallel: bool) -> String {
+    let mut out = String::from("{\n");
+    let commit = commit.map_or("null".to_owned(), json_string);
+    writeln!(out, "  \"commit\": {commit},").unwrap();
+    writeln!(out, "  \"parallel\": {parallel},").unwrap();
+    writeln!(out, "  \"runs\": {runs},").unwrap();
+    out.push_str("  \"results\": [");
+    for (i, timing) in timings.iter().enumerate() {
+        out.push_str(if i == 0 { "\n" } else { ",\n" });
+        write!(
+            out,
+            "    {{\"year\": {}, \"day\": {}, \"part\": \"{}\", \"file\": {}",
+            timing.year,
+            timing.day,
+            timing.part,
+            json_string(&timing.file)
+        )
+        .unwrap();
+        match &timing.outcome {
+            Ok(phases) => write!(
+                out,
+                ", \"parse_ns\": {}, \"solve_ns\": {}, \"total_ns\": {}, \"fastest_ns\": {}, \"slowest_ns\": {}}}",
+                phases.parse.as_nanos(),
+                phases.solve.as_nanos(),
+                phases.total.as_nanos(),
+                phases.fastest.as_nanos(),
+                phases.slowest.as_nanos()
+            ),
+            Err(msg) => write!(out, ", \"error\": {}}}", json_string(msg)),
+        }
+        .unwrap();
+    }
+    out.push_str("\n  ]\n}\n");
+    out
+}
+
+/// The value of `key` in one result line of a report.
+fn field<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    let key = format!("\"{key}\": ");
+    let rest = &line[line.find(&key)? + key.len()..];
+    let end = rest.find([',', '}'])?;
+    Some(rest[..end].trim_matches('"'))
+}
+
+/// Reads the median totals back out of a report written by [`to_json`],
+/// keyed by the same labels as the table.
+fn load_totals(json: &str) -> HashMap<String, Duration> {
+    json.lines()
+        .filter_map(|line| {
+            let day: u32 = field(line, "day")?.parse().ok()?;
+            let label = format!(
+                "{} {day:02}{} {}",
+                field(line, "year")?,
+                field(line, "part")?,
+                field(line, "file")?
+            );
+            let total = field(line, "total_ns")?.parse().ok()?;
+            Some((label, Duration::from_nanos(total)))
+        })
+        .collect()
+}
+
+fn print_comparison(timings: &[Timing], baseline: &HashMap<String, Duration>) {
+    println!(
+        "{:<24} {:>11} {:>11} {:>8}",
+        "solution", "baseline", "total", "speedup"
+    );
+    for timing in timings {
+        let label = timing.label();
+        if let (Some(before), Some(after)) = (baseline.get(&label), timing.total()) {
+            println!(
+                "{label:<24} {:>11} {:>11} {:>7.2}x",
+                format!("{before:.2?}"),
+                format!("{after:.2?}"),
+                before.as_secs_f64() / after.as_secs_f64()
+            );
+        }
+    }
+}
+
+fn print_table(timings: &[Timing]) {
+    println!(
+        "{:<24} {:>11} {:>11} {:>11} {:>11} {:>11}",
+        "solution", "parse", "solve", "total", "fastest", "slowest"
+    );
+    for timing in timings {
+        match &timing.outcome {
+            Ok(phases) => println!(
+                "{:<24} {:>11} {:>11} {:>11} {:>11} {:>11}",
+                timing.label(),
+                format!("{:.2?}", phases.parse),
+                format!("{:.2?}", phases.solve),
+                format!("{:.2?}", phases.total),
+                format!("{:.2?}", phases.fastest),
+                format!("{:.2?}", phases.slowest),
+            ),
+            Err(msg) => println!("{:<24} {msg}", timing.label()),
+        }
+    }
+}
+
+fn current_commit(root: &Path) -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .current_dir(root)
+        .output()
+        .ok()?;
+    let commit = String::from_utf8(output.stdout).ok()?;
+    output.status.success().then(|| commit.trim().to_owned())
+}
+
+/// `advent bench [--runs <n>] [--json <path>] [--compare <path>] [<year> [<day>]]`
+///
+/// Times parsing and solving separately for every matching solution, taking
+/// the median over `--runs` runs. Prints a table, slowest first, and writes
+/// the same numbers as JSON (to `target/bench.json` by default) so reports
+/// from different commits can be compared. Runs the day's manifest marks as
+/// too slow to check are skipped here too.
+///
+/// `--compare` reads an earlier report and prints how much faster each
+/// solution got. To see what the `parallel` feature buys:
+///
+/// ```text
+/// cargo run --release -p advent -- bench --json target/serial.json 2024
+/// cargo run --release -p advent --features parallel -- bench --compare target/serial.json 2024
+/// ```
+pub fn bench(mut args: &[String]) -> Result<(), String> {
+    let root = check::repo_root();
+    let mut runs = DEFAULT_RUNS;
+    let mut json_path = root.join("target/bench.json");
+    let mut baseline = None;
+    loop {
+        match args {
+            [flag, value, rest @ ..] if flag == "--runs" => {
+                runs = check::parse_arg(value, "number of runs")? as usize;
+                args = rest;
+            }
+            [flag, value, rest @ ..] if flag == "--json" => {
+                json_path = PathBuf::from(value);
+                args = rest;
+            }
+            [flag, value, rest @ ..] if flag == "--compare" => {
+                let json = fs::read_to_string(value).map_err(|err| format!("{value}: {err}"))?;
+                baseline = Some(load_totals(&json));
+                args = rest;
+            }
+            _ => break,
+        }
+    }
+    if runs == 0 {
+        return Err("Expected at least one run".to_owned());
+    }
+    let days = check::select_days(args)?;
+
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let mut timings = vec![];
+    let mut skipped = 0;
+    for ((year, day), entries) in days {
+        for entry in entries {
+            let part = entry.1;
+            let Some(dir) = check::day_dir(root, year, day, part) else {
+                continue;
+            };
+            let manifest = Manifest::load(&dir)?;
+            for file in bench_files(&dir) {
+                if manifest.get(&file, part) == Some(SKIP) {
+                    skipped += 1;
+                    continue;
+                }
+                let input = fs::read_to_string(dir.join(&file))
+                    .map_err(|err| format!("{}: {err}", dir.join(&file).display()))?;
+                eprintln!("bench   {year} {day:02}{part} {file}");
+                timings.push(Timing {
+                    year,
+                    day,
+                    part,
+                    outcome: time_entry(entry, &input, runs),
+                    file,
+                });
+            }
+        }
+    }
+    panic::set_hook(default_hook);
+
+    // Slowest first, with the failures at the end.
+    timings.sort_by_key(|timing| std::cmp::Reverse(timing.total()));
+    print_table(&timings);
+    println!("{} timed, {skipped} skipped", timings.len());
+    if let Some(baseline) = &baseline {
+        println!();
+        print_comparison(&timings, baseline);
+    }
+
+    if let Some(parent) = json_path.parent() {
+        fs::create_dir_all(parent).map_err(|err| format!("{}: {err}", parent.display()))?;
+    }
+    let json = to_json(
+        &timings,
+        runs,
+        current_commit(root).as_deref(),
+        cfg!(feature = "parallel"),
+    );
+    fs::write(&json_path, json).map_err(|err| format!("{}: {err}", json_path.display()))?;
+    println!("Wrote {}", json_path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json() {
+        let timings = [
+            Timing {
+                year: 2022,
+                day: 16,
+                part: Part::B,
+                file: "input.txt".to_owned(),
+                outcome: Ok(Phases {
+                    parse: Duration::from_nanos(10),
+                    solve: Duration::from_nanos(90),
+                    total: Duration::from_nanos(100),
+                    fastest: Duration::from_nanos(95),
+                    slowest: Duration::from_nanos(120),
+                }),
+            },
+            Timing {
+                year: 2024,
+                day: 2,
+                part: Part::A,
+                file: "sample.txt".to_owned(),
+                outcome: Err("malformed input: \"x\"".to_owned()),
+            },
+        ];
+        let json = to_json(&timings, 3, None, false);
+        assert_eq!(
+            json,
+            r#"{
+  "commit": null,
+  "parallel": false,
+  "runs": 3,
+  "results": [
+    {"year": 2022, "day": 16, "part": "b", "file": "input.txt", "parse_ns": 10, "solve_ns": 90, "total_ns": 100, "fastest_ns": 95, "slowest_ns": 120},
+    {"year": 2024, "day": 2, "part": "a", "file": "sample.txt", "error": "malformed input: \"x\""}
+  ]
+}
+"#
+        );
+        assert_eq!(
+            load_totals(&json),
+            HashMap::from([("2022 16b input.txt".to_owned(), Duration::from_nanos(100))])
+        );
+    }
+}
@@ -0,0 +1,329 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+};
+
+use aoc::{Part, Solved};
+
+use crate::{Entry, Solve, YEARS};
+
+/// The answers we've accepted for a day, stored in a file named `expected`
+/// next to its inputs. Each answer is keyed by input file and part:
+///
+/// ```text
+/// sample.txt a: 7036
+/// input.txt b:
+/// | #..#.####
+/// | ####.#...
+/// ```
+///
+/// Answers that span several lines are written one per `| ` line after an
+/// otherwise empty header. An answer of `?` marks a run that's too slow to
+/// check, so it's skipped.
+#[derive(Debug, Default, PartialEq)]
+pub struct Manifest {
+    answers: BTreeMap<(String, Part), String>,
+}
+
+const MANIFEST: &str = "expected";
+pub(crate) const SKIP: &str = "?";
+
+impl Manifest {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut answers = BTreeMap::new();
+        let mut open: Option<(String, Part)> = None;
+        for (i, line) in text.lines().enumerate() {
+            let line_no = i + 1;
+            if let Some(rest) = line.strip_prefix('|') {
+                let key = open
+                    .as_ref()
+                    .ok_or_else(|| format!("line {line_no}: continuation without a header"))?;
+                let answer: &mut String = answers.get_mut(key).unwrap();
+                if !answer.is_empty() {
+                    answer.push('\n');
+                }
+                answer.push_str(rest.strip_prefix(' ').unwrap_or(rest));
+                continue;
+            }
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, answer) = line
+                .split_once(':')
+                .ok_or_else(|| format!("line {line_no}: expected `<file> <part>: <answer>`"))?;
+            let (file, part) = key
+                .trim()
+                .rsplit_once(' ')
+                .ok_or_else(|| format!("line {line_no}: expected `<file> <part>`"))?;
+            let part: Part = part
+                .parse()
+                .map_err(|err| format!("line {line_no}: {err}"))?;
+            let key = (file.trim().to_owned(), part);
+            if answers
+                .insert(key.clone(), answer.trim().to_owned())
+                .is_some()
+            {
+                return Err(format!("line {line_no}: {file} {part} is listed twice"));
+            }
+            open = Some(key);
+        }
+        Ok(Self { answers })
+    }
+
+    /// Reads the manifest in `dir`, or an empty one if there isn't one yet.
+    pub fn load(dir: &Path) -> Result<Self, String> {
+        let path = dir.join(MANIFEST);
+        match fs::read_to_string(&path) {
+            Ok(text) => Self::parse(&text).map_err(|err| format!("{}: {err}", path.display())),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    pub fn get(&self, file: &str, part: Part) -> Option<&str> {
+        self.answers
+            .get(&(file.to_owned(), part))
+            .map(|answer| answer.as_str())
+    }
+
+    pub fn insert(&mut self, file: &str, part: Part, answer: String) {
+        self.answers.insert((file.to_owned(), part), answer);
+    }
+}
+
+impl std::fmt::Display for Manifest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for ((file, part), answer) in &self.answers {
+            if answer.contains('\n') {
+                writeln!(f, "{file} {part}:")?;
+                for line in answer.lines() {
+                    writeln!(f, "| {line}")?;
+                }
+            } else {
+                writeln!(f, "{file} {part}: {answer}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Where a day's inputs live. 2021 keeps some parts in their own `dayNa` and
+/// `dayNb` folders; later years have one `src/NN` folder per day.
+pub(crate) fn day_dir(root: &Path, year: u32, day: u32, part: Part) -> Option<PathBuf> {
+    let candidates = if year == 2021 {
+        let other = match part {
+            Part::A => Part::B,
+            Part::B => Part::A,
+        };
+        vec![
+            root.join(format!("2021/day{day}{part}")),
+            root.join(format!("2021/day{day}")),
+            root.join(format!("2021/day{day}{other}")),
+        ]
+    } else {
+        vec![root.join(format!("{year}/src/{day:02}"))]
+    };
+    candidates.into_iter().find(|dir| dir.is_dir())
+}
+
+/// The `sample*.txt` and `input*.txt` files in `dir`, in name order.
+pub(crate) fn input_files(dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+    let mut files: Vec<String> = entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| {
+            name.ends_with(".txt") && (name.starts_with("sample") || name.starts_with("input"))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// Runs `solve`, turning a panic or a parse error into an error message.
+pub(crate) fn run_quietly(solve: Solve, input: &str, part: Part) -> Result<Solved, String> {
+    panic::catch_unwind(AssertUnwindSafe(|| solve(input, part)))
+        .map_err(|payload| {
+            let msg = payload
+                .downcast_ref::<String>()
+                .cloned()
+                .or_else(|| payload.downcast_ref::<&str>().map(|msg| msg.to_string()))
+                .unwrap_or_else(|| "no message".to_owned());
+            format!("panic: {msg}")
+        })?
+        .map_err(|err| format!("malformed input: {err}"))
+}
+
+#[derive(Default)]
+struct Tally {
+    passed: usize,
+    failed: usize,
+    skipped: usize,
+    unrecorded: usize,
+}
+
+/// Runs every solution matching `year` and `day` against each of its inputs
+/// and compares the answers with the day's manifest. With `record`, answers
+/// missing from the manifest are written into it rather than reported.
+fn check_day(
+    root: &Path,
+    year: u32,
+    day: u32,
+    entries: &[&Entry],
+    record: bool,
+    tally: &mut Tally,
+) -> Result<(), String> {
+    let mut by_dir: BTreeMap<PathBuf, Vec<&Entry>> = BTreeMap::new();
+    for &entry in entries {
+        if let Some(dir) = day_dir(root, year, day, entry.1) {
+            by_dir.entry(dir).or_default().push(entry);
+        }
+    }
+
+    for (dir, entries) in by_dir {
+        let manifest_path = dir.join(MANIFEST);
+        let mut manifest = Manifest::load(&dir)?;
+        let mut changed = false;
+
+        for file in input_files(&dir) {
+            let input = fs::read_to_string(dir.join(&file))
+                .map_err(|err| format!("{}: {err}", dir.join(&file).display()))?;
+            for &&(_, part, solve) in &entries {
+                let label = format!("{year} {day:02}{part} {file}");
+                let expected = manifest.get(&file, part).map(|answer| answer.to_owned());
+                if expected.as_deref() == Some(SKIP) {
+                    println!("skip    {label}");
+                    tally.skipped += 1;
+                    continue;
+                }
+                let actual = run_quietly(solve, &input, part).map(|solved| solved.answer);
+                match (expected, actual) {
+                    (Some(expected), Ok(Some(actual))) if expected == actual.trim_end() => {
+                        println!("ok      {label}");
+                        tally.passed += 1;
+                    }
+                    (Some(expected), actual) => {
+                        let actual = match actual {
+                            Ok(Some(answer)) => answer,
+                            Ok(None) => "no answer".to_owned(),
+                            Err(msg) => msg,
+                        };
+                        println!("FAILED  {label}: expected {expected:?}, got {actual:?}");
+                        tally.failed += 1;
+                    }
+                    (None, Ok(Some(actual))) if record => {
+                        println!("record  {label}");
+                        manifest.insert(&file, part, actual.trim_end().to_owned());
+                        changed = true;
+                    }
+                    (None, _) => {
+                        println!("-       {label}: no expected answer");
+                        tally.unrecorded += 1;
+                    }
+                }
+            }
+        }
+
+        if changed {
+            fs::write(&manifest_path, manifest.to_string())
+                .map_err(|err| format!("{}: {err}", manifest_path.display()))?;
+        }
+    }
+    Ok(())
+}
+
+/// `advent check [--record] [<year> [<day>]]`
+pub fn check(args: &[String]) -> Result<(), String> {
+    let record = args.first().is_some_and(|arg| arg == "--record");
+    let args = if record { &args[1..] } else { args };
+    let days = select_days(args)?;
+
+    let root = repo_root();
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut tally = Tally::default();
+    let mut result = Ok(());
+    for ((year, day), entries) in days {
+        result = result.and(check_day(root, year, day, &entries, record, &mut tally));
+    }
+    panic::set_hook(default_hook);
+    result?;
+
+    println!(
+        "{} passed, {} failed, {} skipped, {} without an expected answer",
+        tally.passed, tally.failed, tally.skipped, tally.unrecorded
+    );
+    if tally.failed > 0 {
+        return Err("Some answers no longer match what was recorded".to_owned());
+    }
+    Ok(())
+}
+
+/// The checkout the `advent` crate was built from.
+pub(crate) fn repo_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+/// Parses an optional `[<year> [<day>]]` and gathers the matching solutions,
+/// grouped by year and day.
+pub(crate) fn select_days(
+    args: &[String],
+) -> Result<BTreeMap<(u32, u32), Vec<&'static Entry>>, String> {
+    let (year, day): (Option<u32>, Option<u32>) = match args {
+        [] => (None, None),
+        [year] => (Some(parse_arg(year, "year")?), None),
+        [year, day] => (Some(parse_arg(year, "year")?), Some(parse_arg(day, "day")?)),
+        _ => return Err(crate::USAGE.to_owned()),
+    };
+
+    let mut days: BTreeMap<(u32, u32), Vec<&Entry>> = BTreeMap::new();
+    for &(y, solutions) in YEARS {
+        if year.is_some_and(|year| year != y) {
+            continue;
+        }
+        for entry in solutions.iter() {
+            if day.is_none_or(|day| day == entry.0) {
+                days.entry((y, entry.0)).or_default().push(entry);
+            }
+        }
+    }
+    Ok(days)
+}
+
+pub(crate) fn parse_arg(arg: &str, what: &str) -> Result<u32, String> {
+    arg.parse()
+        .map_err(|_| format!("Expected a {what}, got {arg:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+input.txt a: 1234
+input.txt b:
+| #..#
+| ####
+sample.txt a: 7
+";
+
+    #[test]
+    fn test_manifest_round_trip() {
+        let manifest = Manifest::parse(SAMPLE).unwrap();
+        assert_eq!(manifest.get("input.txt", Part::A), Some("1234"));
+        assert_eq!(manifest.get("input.txt", Part::B), Some("#..#\n####"));
+        assert_eq!(manifest.get("sample.txt", Part::B), None);
+        assert_eq!(manifest.to_string(), SAMPLE);
+    }
+
+    #[test]
+    fn test_manifest_errors() {
+        assert!(Manifest::parse("| dangling").is_err());
+        assert!(Manifest::parse("input.txt c: 1").is_err());
+        assert!(Manifest::parse("input.txt a: 1\ninput.txt a: 2").is_err());
+    }
+}
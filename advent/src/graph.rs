@@ -0,0 +1,53 @@
+use aoc_viz::Graph;
+
+/// Draws a day's input as a graph.
+type Draw = fn(&str) -> Result<Graph, aoc::ParseError>;
+
+/// The days whose puzzles are about a graph, and how to draw each one.
+const GRAPHS: &[(u32, u32, Draw)] = &[
+    (2021, 12, aoc2021_day12::graph),
+    (2022, 16, aoc2022_a16::graph),
+    (2022, 21, aoc2022_b21::graph),
+    (2024, 23, aoc2024_b23::graph),
+    (2024, 24, aoc2024_b24::graph),
+    (2025, 11, aoc2025_b11::graph),
+];
+
+/// `advent graph <year> <day> [--mermaid] < input.txt`
+///
+/// Prints the day's input as a Graphviz DOT graph, or as a Mermaid
+/// flowchart with `--mermaid`, with the interesting part of the answer
+/// highlighted.
+pub fn graph(args: &[String]) -> Result<(), String> {
+    let (args, mermaid) = match args {
+        [rest @ .., flag] if flag == "--mermaid" => (rest, true),
+        _ => (args, false),
+    };
+    let [year, day] = args else {
+        return Err(crate::USAGE.to_owned());
+    };
+    let year = crate::check::parse_arg(year, "year")?;
+    let day = crate::check::parse_arg(day, "day")?;
+    let draw = GRAPHS
+        .iter()
+        .find(|&&(y, d, _)| y == year && d == day)
+        .map(|&(_, _, draw)| draw)
+        .ok_or_else(|| {
+            let days: Vec<String> = GRAPHS
+                .iter()
+                .map(|(year, day, _)| format!("{year} {day:02}"))
+                .collect();
+            format!(
+                "No graph for {year} day {day}. Try one of: {}",
+                days.join(", ")
+            )
+        })?;
+
+    let graph = draw(&aoc::read_stdin()).map_err(|err| format!("Malformed input: {err}"))?;
+    if mermaid {
+        print!("{}", graph.to_mermaid());
+    } else {
+        print!("{}", graph.to_dot());
+    }
+    Ok(())
+}
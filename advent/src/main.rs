@@ -0,0 +1,94 @@
+use aoc::Part;
+
+mod bench;
+mod check;
+mod graph;
+mod y2021;
+mod y2022;
+mod y2023;
+mod y2024;
+mod y2025;
+
+/// A registered solution: the day, the part it answers, and how to answer it.
+type Entry = (u32, Part, Solve);
+
+type Solve = fn(&str, Part) -> Result<aoc::Solved, aoc::ParseError>;
+
+const YEARS: &[(u32, &[Entry])] = &[
+    (2021, y2021::SOLUTIONS),
+    (2022, y2022::SOLUTIONS),
+    (2023, y2023::SOLUTIONS),
+    (2024, y2024::SOLUTIONS),
+    (2025, y2025::SOLUTIONS),
+];
+
+const USAGE: &str = "Usage:
+    advent run <year> <day> <part> < input.txt
+    advent check [--record] [<year> [<day>]]
+    advent bench [--runs <n>] [--json <path>] [--compare <path>] [<year> [<day>]]
+    advent graph <year> <day> [--mermaid] < input.txt
+    advent list
+
+Set AOC_VERBOSE=1 to have solutions report how they got their answers on
+stderr, such as how many nodes a search expanded.";
+
+fn find(year: u32, day: u32, part: Part) -> Option<Solve> {
+    YEARS
+        .iter()
+        .filter(|(y, _)| *y == year)
+        .flat_map(|(_, solutions)| solutions.iter())
+        .find(|(d, p, _)| *d == day && *p == part)
+        .map(|(_, _, solve)| *solve)
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let [year, day, part] = args else {
+        return Err(USAGE.to_owned());
+    };
+    let year: u32 = year
+        .parse()
+        .map_err(|_| format!("Expected a year, got {year:?}"))?;
+    let day: u32 = day
+        .parse()
+        .map_err(|_| format!("Expected a day, got {day:?}"))?;
+    let part: Part = part.parse()?;
+
+    let solve =
+        find(year, day, part).ok_or_else(|| format!("No solution for {year} day {day}{part}"))?;
+    let solved =
+        solve(&aoc::read_stdin(), part).map_err(|err| format!("Malformed input: {err}"))?;
+    match solved.answer {
+        Some(answer) => {
+            println!("{answer}");
+            Ok(())
+        }
+        None => Err(format!("{year} day {day}{part} found no answer")),
+    }
+}
+
+fn list() {
+    for (year, solutions) in YEARS {
+        for (day, part, _) in solutions.iter() {
+            println!("{year} {day:02}{part}");
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(|arg| arg.as_str()) {
+        Some("run") => run(&args[1..]),
+        Some("check") => check::check(&args[1..]),
+        Some("bench") => bench::bench(&args[1..]),
+        Some("graph") => graph::graph(&args[1..]),
+        Some("list") => {
+            list();
+            Ok(())
+        }
+        _ => Err(USAGE.to_owned()),
+    };
+    if let Err(err) = result {
+        eprintln!("{err}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,42 @@
+use aoc::{solve, Part};
+
+use crate::Entry;
+
+pub const SOLUTIONS: &[Entry] = &[
+    (1, Part::A, solve::<aoc2021_day1a::Day01>),
+    (1, Part::B, solve::<aoc2021_day1b::Day01>),
+    (2, Part::B, solve::<aoc2021_day2b::Day02>),
+    (3, Part::A, solve::<aoc2021_day3a::Day03>),
+    (3, Part::B, solve::<aoc2021_day3b::Day03>),
+    (5, Part::B, solve::<aoc2021_day5a::Day05>),
+    (6, Part::A, solve::<aoc2021_day6::Day06>),
+    (6, Part::B, solve::<aoc2021_day6::Day06>),
+    (7, Part::B, solve::<aoc2021_day7::Day07>),
+    (8, Part::B, solve::<aoc2021_day8::Day08>),
+    (9, Part::A, solve::<aoc2021_day9::Day09>),
+    (9, Part::B, solve::<aoc2021_day9::Day09>),
+    (10, Part::A, solve::<aoc2021_day10::Day10>),
+    (10, Part::B, solve::<aoc2021_day10::Day10>),
+    (11, Part::A, solve::<aoc2021_day11::Day11>),
+    (11, Part::B, solve::<aoc2021_day11::Day11>),
+    (12, Part::A, solve::<aoc2021_day12::Day12>),
+    (12, Part::B, solve::<aoc2021_day12::Day12>),
+    (13, Part::A, solve::<aoc2021_day13::Day13>),
+    (13, Part::B, solve::<aoc2021_day13::Day13>),
+    (14, Part::A, solve::<aoc2021_day14::Day14>),
+    (14, Part::B, solve::<aoc2021_day14::Day14>),
+    (15, Part::A, solve::<aoc2021_day15::Day15>),
+    (15, Part::B, solve::<aoc2021_day15::Day15>),
+    (16, Part::A, solve::<aoc2021_day16::Day16>),
+    (16, Part::B, solve::<aoc2021_day16::Day16>),
+    (17, Part::A, solve::<aoc2021_day17::Day17>),
+    (17, Part::B, solve::<aoc2021_day17::Day17>),
+    (18, Part::A, solve::<aoc2021_day18::Day18>),
+    (18, Part::B, solve::<aoc2021_day18::Day18>),
+    (19, Part::A, solve::<aoc2021_day19::Day19>),
+    (19, Part::B, solve::<aoc2021_day19::Day19>),
+    (20, Part::A, solve::<aoc2021_day20::Day20>),
+    (20, Part::B, solve::<aoc2021_day20::Day20>),
+    (21, Part::A, solve::<aoc2021_day21::Day21>),
+    (21, Part::B, solve::<aoc2021_day21::Day21>),
+];
@@ -0,0 +1,55 @@
+use aoc::{solve, Part};
+
+use crate::Entry;
+
+pub const SOLUTIONS: &[Entry] = &[
+    (1, Part::A, solve::<aoc2022_a01::Day01>),
+    (1, Part::B, solve::<aoc2022_b01::Day01>),
+    (2, Part::A, solve::<aoc2022_a02::Day02>),
+    (2, Part::B, solve::<aoc2022_b02::Day02>),
+    (3, Part::A, solve::<aoc2022_a03::Day03>),
+    (3, Part::B, solve::<aoc2022_b03::Day03>),
+    (4, Part::A, solve::<aoc2022_a04::Day04>),
+    (4, Part::B, solve::<aoc2022_b04::Day04>),
+    (5, Part::A, solve::<aoc2022_a05::Day05>),
+    (5, Part::B, solve::<aoc2022_b05::Day05>),
+    (6, Part::A, solve::<aoc2022_a06::Day06>),
+    (6, Part::B, solve::<aoc2022_b06::Day06>),
+    (7, Part::A, solve::<aoc2022_a07::Day07>),
+    (7, Part::B, solve::<aoc2022_b07::Day07>),
+    (8, Part::A, solve::<aoc2022_a08::Day08>),
+    (8, Part::B, solve::<aoc2022_b08::Day08>),
+    (9, Part::A, solve::<aoc2022_a09::Day09>),
+    (9, Part::B, solve::<aoc2022_b09::Day09>),
+    (10, Part::A, solve::<aoc2022_a10::Day10>),
+    (10, Part::B, solve::<aoc2022_b10::Day10>),
+    (11, Part::A, solve::<aoc2022_a11::Day11>),
+    (11, Part::B, solve::<aoc2022_b11::Day11>),
+    (12, Part::A, solve::<aoc2022_a12::Day12>),
+    (12, Part::B, solve::<aoc2022_b12::Day12>),
+    (13, Part::A, solve::<aoc2022_a13::Day13>),
+    (13, Part::B, solve::<aoc2022_b13::Day13>),
+    (14, Part::A, solve::<aoc2022_a14::Day14>),
+    (14, Part::B, solve::<aoc2022_b14::Day14>),
+    (15, Part::A, solve::<aoc2022_a15::Day15>),
+    (15, Part::B, solve::<aoc2022_b15::Day15>),
+    (16, Part::A, solve::<aoc2022_a16::Day16>),
+    (16, Part::B, solve::<aoc2022_b16::Day16>),
+    (17, Part::A, solve::<aoc2022_a17::Day17>),
+    (17, Part::B, solve::<aoc2022_b17::Day17>),
+    (18, Part::A, solve::<aoc2022_a18::Day18>),
+    (18, Part::B, solve::<aoc2022_b18::Day18>),
+    (19, Part::A, solve::<aoc2022_a19::Day19>),
+    (19, Part::B, solve::<aoc2022_b19::Day19>),
+    (20, Part::A, solve::<aoc2022_a20::Day20>),
+    (20, Part::B, solve::<aoc2022_b20::Day20>),
+    (21, Part::A, solve::<aoc2022_a21::Day21>),
+    (21, Part::B, solve::<aoc2022_b21::Day21>),
+    (22, Part::A, solve::<aoc2022_a22::Day22>),
+    (22, Part::B, solve::<aoc2022_b22::Day22>),
+    (23, Part::A, solve::<aoc2022_a23::Day23>),
+    (23, Part::B, solve::<aoc2022_b23::Day23>),
+    (24, Part::A, solve::<aoc2022_a24::Day24>),
+    (24, Part::B, solve::<aoc2022_b24::Day24>),
+    (25, Part::A, solve::<aoc2022_a25::Day25>),
+];
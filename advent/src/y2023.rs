@@ -0,0 +1,26 @@
+use aoc::{solve, Part};
+
+use crate::Entry;
+
+pub const SOLUTIONS: &[Entry] = &[
+    (1, Part::A, solve::<aoc2023_a01::Day01>),
+    (1, Part::B, solve::<aoc2023_b01::Day01>),
+    (2, Part::A, solve::<aoc2023_a02::Day02>),
+    (2, Part::B, solve::<aoc2023_b02::Day02>),
+    (3, Part::A, solve::<aoc2023_a03::Day03>),
+    (3, Part::B, solve::<aoc2023_b03::Day03>),
+    (4, Part::A, solve::<aoc2023_a04::Day04>),
+    (4, Part::B, solve::<aoc2023_b04::Day04>),
+    (5, Part::A, solve::<aoc2023_a05::Day05>),
+    (5, Part::B, solve::<aoc2023_b05::Day05>),
+    (6, Part::A, solve::<aoc2023_a06::Day06>),
+    (6, Part::B, solve::<aoc2023_b06::Day06>),
+    (7, Part::A, solve::<aoc2023_a07::Day07>),
+    (7, Part::B, solve::<aoc2023_b07::Day07>),
+    (8, Part::A, solve::<aoc2023_a08::Day08>),
+    (8, Part::B, solve::<aoc2023_b08::Day08>),
+    (9, Part::A, solve::<aoc2023_a09::Day09>),
+    (9, Part::B, solve::<aoc2023_b09::Day09>),
+    (10, Part::A, solve::<aoc2023_a10::Day10>),
+    (10, Part::B, solve::<aoc2023_b10::Day10>),
+];
@@ -0,0 +1,29 @@
+use aoc::{solve, Part};
+
+use crate::Entry;
+
+pub const SOLUTIONS: &[Entry] = &[
+    (1, Part::A, solve::<aoc2025_a01::Day01>),
+    (1, Part::B, solve::<aoc2025_b01::Day01>),
+    (2, Part::A, solve::<aoc2025_a02::Day02>),
+    (2, Part::B, solve::<aoc2025_b02::Day02>),
+    (3, Part::A, solve::<aoc2025_a03::Day03>),
+    (3, Part::B, solve::<aoc2025_b03::Day03>),
+    (4, Part::A, solve::<aoc2025_a04::Day04>),
+    (4, Part::B, solve::<aoc2025_b04::Day04>),
+    (5, Part::A, solve::<aoc2025_a05::Day05>),
+    (5, Part::B, solve::<aoc2025_b05::Day05>),
+    (6, Part::A, solve::<aoc2025_a06::Day06>),
+    (6, Part::B, solve::<aoc2025_b06::Day06>),
+    (7, Part::A, solve::<aoc2025_a07::Day07>),
+    (7, Part::B, solve::<aoc2025_b07::Day07>),
+    (8, Part::A, solve::<aoc2025_a08::Day08>),
+    (8, Part::B, solve::<aoc2025_b08::Day08>),
+    (9, Part::A, solve::<aoc2025_a09::Day09>),
+    (9, Part::B, solve::<aoc2025_b09::Day09>),
+    (10, Part::A, solve::<aoc2025_a10::Day10>),
+    (10, Part::B, solve::<aoc2025_b10::Day10>),
+    (11, Part::A, solve::<aoc2025_a11::Day11>),
+    (11, Part::B, solve::<aoc2025_b11::Day11>),
+    (12, Part::A, solve::<aoc2025_a12::Day12>),
+];
@@ -0,0 +1,141 @@
+use std::{
+    io::Read,
+    time::{Duration, Instant},
+};
+
+pub use aoc_parse::Error as ParseError;
+
+/// Which half of a day's puzzle to answer.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl std::str::FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" | "A" | "1" => Ok(Self::A),
+            "b" | "B" | "2" => Ok(Self::B),
+            _ => Err(format!("Expected a part (a or b), got {s:?}")),
+        }
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::A => write!(f, "a"),
+            Self::B => write!(f, "b"),
+        }
+    }
+}
+
+/// A puzzle solution. The input is parsed once, and each part is answered
+/// from the parsed form.
+///
+/// Most days are split into an `aNN` and a `bNN` crate, so a solution only
+/// needs to answer the parts it knows about; the rest default to `None`.
+///
+/// Days that read their input with `aoc_parse` report malformed input from
+/// `try_parse` with its line and column; the rest can only panic on it.
+pub trait Solution {
+    type Input;
+
+    fn try_parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Parses the input, panicking if it's malformed.
+    fn parse(input: &str) -> Self::Input {
+        Self::try_parse(input).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn part_a(_input: &Self::Input) -> Option<String> {
+        None
+    }
+
+    fn part_b(_input: &Self::Input) -> Option<String> {
+        None
+    }
+}
+
+/// The answer to a single part, along with how long it took to parse the
+/// input and then to answer the part.
+#[derive(Clone, Debug)]
+pub struct Solved {
+    pub answer: Option<String>,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// Parses `input` and answers a single part.
+pub fn solve<S: Solution>(input: &str, part: Part) -> Result<Solved, ParseError> {
+    let start = Instant::now();
+    let input = S::try_parse(input)?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        Part::A => S::part_a(&input),
+        Part::B => S::part_b(&input),
+    };
+    let solve_time = start.elapsed();
+
+    Ok(Solved {
+        answer,
+        parse_time,
+        solve_time,
+    })
+}
+
+/// Parses `input` and prints the answer to every part `S` implements.
+pub fn run<S: Solution>(input: &str) {
+    let input = match S::try_parse(input) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Malformed input: {err}");
+            std::process::exit(1);
+        }
+    };
+    if let Some(answer) = S::part_a(&input) {
+        println!("{answer}");
+    }
+    if let Some(answer) = S::part_b(&input) {
+        println!("{answer}");
+    }
+}
+
+/// Whether solutions should report how they got their answers, such as how
+/// much searching they did, on stderr. Off unless the `AOC_VERBOSE`
+/// environment variable is set, so that normally the answer is all a
+/// solution prints.
+pub fn verbose() -> bool {
+    std::env::var_os("AOC_VERBOSE").is_some()
+}
+
+/// Maps `f` over `items`, spreading the work across threads when the
+/// `parallel` feature is on. The results come back in the same order as
+/// `items` either way, so the answer doesn't depend on the feature.
+pub fn par_map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        items.par_iter().map(f).collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        items.iter().map(f).collect()
+    }
+}
+
+pub fn read_stdin() -> String {
+    let mut input = String::new();
+    std::io::stdin().read_to_string(&mut input).unwrap();
+    input
+}
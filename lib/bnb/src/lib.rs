@@ -0,0 +1,362 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+    fmt,
+    hash::Hash,
+};
+
+/// A maximisation problem to solve by branch and bound.
+///
+/// Every node is a partial solution that's worth [`Problem::score`] as it
+/// stands, and [`Problem::successors`] are the ways of taking it further. A
+/// node whose [`Problem::bound`] is no better than the best score found so far
+/// can't lead anywhere better, so it's never expanded.
+pub trait Problem {
+    type Node: Clone;
+    /// What nodes have to share for [`Problem::dominates`] to compare them.
+    type Key: Eq + Hash;
+
+    fn successors(&self, node: &Self::Node) -> Vec<Self::Node>;
+
+    /// What the node is worth if it's taken no further.
+    fn score(&self, node: &Self::Node) -> i64;
+
+    /// An upper bound on the score of the node and everything that can be
+    /// reached from it. If it's ever too low, the search can miss the best
+    /// score.
+    fn bound(&self, node: &Self::Node) -> i64;
+
+    /// The node's key for dominance pruning, or `None`, the default, to
+    /// never prune it that way.
+    fn key(&self, _node: &Self::Node) -> Option<Self::Key> {
+        None
+    }
+
+    /// Whether `a` is sure to do at least as well as `b`, for nodes with the
+    /// same key. A node dominated by one seen before is dropped.
+    fn dominates(&self, _a: &Self::Node, _b: &Self::Node) -> bool {
+        false
+    }
+}
+
+/// The order to expand nodes in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Strategy {
+    /// The node with the highest bound first. Once that bound is beaten,
+    /// the search is over.
+    BestFirst,
+    /// The most recent node first, trying the last of a node's successors
+    /// first. Keeps only one path's worth of nodes around, and finds good
+    /// scores to prune by quickly if the successors are in a good order.
+    DepthFirst,
+}
+
+/// How much work a search did.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Stats {
+    /// Nodes whose successors were generated.
+    pub expanded: usize,
+    /// Nodes dropped because their bound couldn't beat the best score.
+    pub bounded: usize,
+    /// Nodes dropped because a node seen before dominated them.
+    pub dominated: usize,
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} expanded, {} pruned by bound, {} dominated",
+            self.expanded, self.bounded, self.dominated
+        )
+    }
+}
+
+/// The best node a search found.
+#[derive(Clone, Debug)]
+pub struct Best<N> {
+    pub node: N,
+    pub score: i64,
+    pub stats: Stats,
+}
+
+/// A node waiting to be expanded, ordered by its bound alone.
+struct Pending<N> {
+    bound: i64,
+    node: N,
+}
+
+impl<N> PartialEq for Pending<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bound == other.bound
+    }
+}
+
+impl<N> Eq for Pending<N> {}
+
+impl<N> PartialOrd for Pending<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for Pending<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bound.cmp(&other.bound)
+    }
+}
+
+enum Frontier<N> {
+    Heap(BinaryHeap<Pending<N>>),
+    Stack(Vec<Pending<N>>),
+}
+
+impl<N> Frontier<N> {
+    fn push(&mut self, pending: Pending<N>) {
+        match self {
+            Self::Heap(heap) => heap.push(pending),
+            Self::Stack(stack) => stack.push(pending),
+        }
+    }
+
+    fn pop(&mut self) -> Option<Pending<N>> {
+        match self {
+            Self::Heap(heap) => heap.pop(),
+            Self::Stack(stack) => stack.pop(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::Heap(heap) => heap.len(),
+            Self::Stack(stack) => stack.len(),
+        }
+    }
+}
+
+/// The nodes seen so far that no other node with the same key dominates.
+struct Seen<P: Problem> {
+    fronts: HashMap<P::Key, Vec<P::Node>>,
+}
+
+impl<P: Problem> Seen<P> {
+    /// Records `node`, unless something already seen dominates it.
+    fn insert(&mut self, problem: &P, node: &P::Node) -> bool {
+        let Some(key) = problem.key(node) else {
+            return true;
+        };
+        let front = self.fronts.entry(key).or_default();
+        if front.iter().any(|seen| problem.dominates(seen, node)) {
+            return false;
+        }
+        front.retain(|seen| !problem.dominates(node, seen));
+        front.push(node.clone());
+        true
+    }
+}
+
+/// Searches everything reachable from `root` for the node with the highest
+/// score. Ties go to whichever node was found first.
+pub fn search<P: Problem>(problem: &P, root: P::Node, strategy: Strategy) -> Best<P::Node> {
+    let mut stats = Stats::default();
+    let mut seen = Seen::<P> {
+        fronts: HashMap::new(),
+    };
+    let mut best_score = problem.score(&root);
+    let mut best = root.clone();
+    seen.insert(problem, &root);
+    let mut frontier = match strategy {
+        Strategy::BestFirst => Frontier::Heap(BinaryHeap::new()),
+        Strategy::DepthFirst => Frontier::Stack(vec![]),
+    };
+    frontier.push(Pending {
+        bound: problem.bound(&root),
+        node: root,
+    });
+
+    while let Some(Pending { bound, node }) = frontier.pop() {
+        if bound <= best_score {
+            stats.bounded += 1;
+            if strategy == Strategy::BestFirst {
+                // Nothing left has a higher bound.
+                stats.bounded += frontier.len();
+                break;
+            }
+            continue;
+        }
+        stats.expanded += 1;
+        for next in problem.successors(&node) {
+            let score = problem.score(&next);
+            if score > best_score {
+                best_score = score;
+                best = next.clone();
+            }
+            let bound = problem.bound(&next);
+            if bound <= best_score {
+                stats.bounded += 1;
+            } else if !seen.insert(problem, &next) {
+                stats.dominated += 1;
+            } else {
+                frontier.push(Pending { bound, node: next });
+            }
+        }
+    }
+
+    Best {
+        node: best,
+        score: best_score,
+        stats,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0/1 knapsack: which items to take, as `(weight, value)`, without going
+    /// over the capacity.
+    struct Knapsack {
+        items: Vec<(i64, i64)>,
+        capacity: i64,
+    }
+
+    /// How many items have been decided on, the weight and value of the
+    /// ones taken, and which ones they were.
+    #[derive(Clone, Debug)]
+    struct Packed {
+        next: usize,
+        weight: i64,
+        value: i64,
+        taken: Vec<usize>,
+    }
+
+    impl Problem for Knapsack {
+        type Node = Packed;
+        type Key = (usize, i64);
+
+        fn successors(&self, node: &Packed) -> Vec<Packed> {
+            let Some(&(weight, value)) = self.items.get(node.next) else {
+                return vec![];
+            };
+            let mut successors = vec![Packed {
+                next: node.next + 1,
+                ..node.clone()
+            }];
+            if node.weight + weight <= self.capacity {
+                let mut taken = node.taken.clone();
+                taken.push(node.next);
+                successors.push(Packed {
+                    next: node.next + 1,
+                    weight: node.weight + weight,
+                    value: node.value + value,
+                    taken,
+                });
+            }
+            successors
+        }
+
+        fn score(&self, node: &Packed) -> i64 {
+            node.value
+        }
+
+        /// Every item that's left, whether or not it fits.
+        fn bound(&self, node: &Packed) -> i64 {
+            node.value
+                + self.items[node.next..]
+                    .iter()
+                    .map(|&(_, value)| value)
+                    .sum::<i64>()
+        }
+
+        fn key(&self, node: &Packed) -> Option<(usize, i64)> {
+            Some((node.next, node.weight))
+        }
+
+        fn dominates(&self, a: &Packed, b: &Packed) -> bool {
+            a.value >= b.value
+        }
+    }
+
+    fn brute_force(knapsack: &Knapsack) -> i64 {
+        let n = knapsack.items.len();
+        (0..1u32 << n)
+            .filter_map(|set| {
+                let (weight, value) = (0..n)
+                    .filter(|i| set & (1 << i) != 0)
+                    .map(|i| knapsack.items[i])
+                    .fold((0, 0), |(w, v), (iw, iv)| (w + iw, v + iv));
+                (weight <= knapsack.capacity).then_some(value)
+            })
+            .max()
+            .unwrap()
+    }
+
+    fn root() -> Packed {
+        Packed {
+            next: 0,
+            weight: 0,
+            value: 0,
+            taken: vec![],
+        }
+    }
+
+    #[test]
+    fn test_knapsack() {
+        let knapsack = Knapsack {
+            items: vec![(12, 4), (2, 2), (1, 1), (1, 2), (4, 10)],
+            capacity: 15,
+        };
+        for strategy in [Strategy::BestFirst, Strategy::DepthFirst] {
+            let best = search(&knapsack, root(), strategy);
+            assert_eq!(best.score, 15);
+            assert_eq!(best.node.taken, [1, 2, 3, 4]);
+            assert!(best.stats.expanded > 0);
+        }
+    }
+
+    #[test]
+    fn test_against_brute_force() {
+        let mut seed = 19u64;
+        let mut random = |limit: u64| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed % limit) as i64 + 1
+        };
+        for _ in 0..50 {
+            let items: Vec<(i64, i64)> = (0..12).map(|_| (random(20), random(30))).collect();
+            let knapsack = Knapsack {
+                items,
+                capacity: random(80),
+            };
+            let expected = brute_force(&knapsack);
+            let best_first = search(&knapsack, root(), Strategy::BestFirst);
+            let depth_first = search(&knapsack, root(), Strategy::DepthFirst);
+            assert_eq!(best_first.score, expected);
+            assert_eq!(depth_first.score, expected);
+        }
+    }
+
+    #[test]
+    fn test_pruning() {
+        // Many items of the same weight and value give many equal ways of
+        // reaching each weight, and all but one of them are dominated.
+        let knapsack = Knapsack {
+            items: vec![(1, 1); 16],
+            capacity: 8,
+        };
+        let best = search(&knapsack, root(), Strategy::DepthFirst);
+        assert_eq!(best.score, 8);
+        assert!(best.stats.dominated > 0);
+        assert!(best.stats.expanded < 200, "{}", best.stats);
+        assert_eq!(
+            Stats {
+                expanded: 3,
+                bounded: 2,
+                dominated: 1
+            }
+            .to_string(),
+            "3 expanded, 2 pruned by bound, 1 dominated"
+        );
+    }
+}
@@ -0,0 +1,287 @@
+use std::ops::ControlFlow;
+
+mod polyomino;
+
+pub use polyomino::Polyomino;
+
+/// An exact cover problem, solved with Knuth's Algorithm X using dancing
+/// links.
+///
+/// Each row is a choice that covers some of the columns. A solution is a set
+/// of rows that covers every primary column exactly once and every secondary
+/// column at most once. Columns `0..primary` are the primary ones, and the
+/// secondary ones follow them.
+///
+/// Searching unlinks and relinks the rows in place, so it needs `&mut self`,
+/// but leaves the problem as it found it.
+#[derive(Clone, Debug)]
+pub struct ExactCover {
+    columns: usize,
+    rows: usize,
+    // Node 0 is the root, nodes `1..=columns` are the column headers, and
+    // the rest are the cells of the rows.
+    left: Vec<usize>,
+    right: Vec<usize>,
+    up: Vec<usize>,
+    down: Vec<usize>,
+    /// The header of each node's column.
+    column: Vec<usize>,
+    /// The row each node belongs to.
+    row: Vec<usize>,
+    /// How many rows are still linked into each column, by header.
+    size: Vec<usize>,
+}
+
+impl ExactCover {
+    pub fn new(primary: usize, secondary: usize) -> Self {
+        let columns = primary + secondary;
+        let nodes = columns + 1;
+        let mut cover = Self {
+            columns,
+            rows: 0,
+            left: (0..nodes).collect(),
+            right: (0..nodes).collect(),
+            up: (0..nodes).collect(),
+            down: (0..nodes).collect(),
+            column: (0..nodes).collect(),
+            row: vec![usize::MAX; nodes],
+            size: vec![0; nodes],
+        };
+        // Only the primary columns are linked to the root, since the search
+        // is done once they're all covered. The secondary ones link only to
+        // themselves.
+        for header in 1..=primary {
+            cover.left[header] = header - 1;
+            cover.right[header - 1] = header;
+        }
+        cover.left[0] = primary;
+        cover.right[primary] = 0;
+        cover
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Adds a row covering `columns`, and returns its index.
+    ///
+    /// Panics if a column is out of range or appears twice.
+    pub fn add_row(&mut self, columns: &[usize]) -> usize {
+        let row = self.rows;
+        self.rows += 1;
+        let first = self.left.len();
+        for (i, &col) in columns.iter().enumerate() {
+            assert!(
+                col < self.columns,
+                "Column {col} is out of range for {} columns",
+                self.columns
+            );
+            assert!(
+                !columns[..i].contains(&col),
+                "Column {col} appears twice in row {row}"
+            );
+            let header = col + 1;
+            let node = self.left.len();
+            let last = self.up[header];
+            self.up.push(last);
+            self.down.push(header);
+            self.down[last] = node;
+            self.up[header] = node;
+            self.left.push(if i == 0 { node } else { node - 1 });
+            self.right.push(first);
+            if i > 0 {
+                self.right[node - 1] = node;
+            }
+            self.left[first] = node;
+            self.column.push(header);
+            self.row.push(row);
+            self.size[header] += 1;
+        }
+        row
+    }
+
+    /// Removes a column from the header list, and every row that uses it
+    /// from the other columns.
+    fn cover(&mut self, header: usize) {
+        let (l, r) = (self.left[header], self.right[header]);
+        self.right[l] = r;
+        self.left[r] = l;
+        let mut i = self.down[header];
+        while i != header {
+            let mut j = self.right[i];
+            while j != i {
+                let (u, d) = (self.up[j], self.down[j]);
+                self.down[u] = d;
+                self.up[d] = u;
+                self.size[self.column[j]] -= 1;
+                j = self.right[j];
+            }
+            i = self.down[i];
+        }
+    }
+
+    /// Undoes [`cover`](Self::cover), in exactly the reverse order.
+    fn uncover(&mut self, header: usize) {
+        let mut i = self.up[header];
+        while i != header {
+            let mut j = self.left[i];
+            while j != i {
+                self.size[self.column[j]] += 1;
+                let (u, d) = (self.up[j], self.down[j]);
+                self.down[u] = j;
+                self.up[d] = j;
+                j = self.left[j];
+            }
+            i = self.up[i];
+        }
+        let (l, r) = (self.left[header], self.right[header]);
+        self.right[l] = header;
+        self.left[r] = header;
+    }
+
+    /// The uncovered primary column with the fewest rows left, if any.
+    fn choose(&self) -> Option<usize> {
+        let mut best = None;
+        let mut header = self.right[0];
+        while header != 0 {
+            if best.is_none_or(|best| self.size[header] < self.size[best]) {
+                best = Some(header);
+            }
+            header = self.right[header];
+        }
+        best
+    }
+
+    fn search<B>(
+        &mut self,
+        chosen: &mut Vec<usize>,
+        visit: &mut impl FnMut(&[usize]) -> ControlFlow<B>,
+    ) -> ControlFlow<B> {
+        let Some(header) = self.choose() else {
+            return visit(chosen);
+        };
+        if self.size[header] == 0 {
+            return ControlFlow::Continue(());
+        }
+        self.cover(header);
+        let mut i = self.down[header];
+        let mut flow = ControlFlow::Continue(());
+        while i != header {
+            chosen.push(self.row[i]);
+            let mut j = self.right[i];
+            while j != i {
+                self.cover(self.column[j]);
+                j = self.right[j];
+            }
+            flow = self.search(chosen, visit);
+            let mut j = self.left[i];
+            while j != i {
+                self.uncover(self.column[j]);
+                j = self.left[j];
+            }
+            chosen.pop();
+            if flow.is_break() {
+                break;
+            }
+            i = self.down[i];
+        }
+        self.uncover(header);
+        flow
+    }
+
+    /// Calls `visit` with the rows of each solution in turn, until it
+    /// breaks.
+    pub fn solve<B>(&mut self, mut visit: impl FnMut(&[usize]) -> ControlFlow<B>) -> Option<B> {
+        match self.search(&mut vec![], &mut visit) {
+            ControlFlow::Break(value) => Some(value),
+            ControlFlow::Continue(()) => None,
+        }
+    }
+
+    /// The rows of the first solution found, stopping there.
+    pub fn first(&mut self) -> Option<Vec<usize>> {
+        self.solve(|rows| ControlFlow::Break(rows.to_vec()))
+    }
+
+    /// How many solutions there are.
+    pub fn count(&mut self) -> usize {
+        let mut count = 0;
+        self.solve(|_| {
+            count += 1;
+            ControlFlow::<()>::Continue(())
+        });
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Knuth's example from the dancing links paper.
+    fn knuth() -> ExactCover {
+        let mut cover = ExactCover::new(7, 0);
+        for row in [
+            &[2, 4, 5][..],
+            &[0, 3, 6],
+            &[1, 2, 5],
+            &[0, 3],
+            &[1, 6],
+            &[3, 4, 6],
+        ] {
+            cover.add_row(row);
+        }
+        cover
+    }
+
+    #[test]
+    fn test_first() {
+        let mut cover = knuth();
+        let mut rows = cover.first().unwrap();
+        rows.sort();
+        assert_eq!(rows, [0, 3, 4]);
+        assert_eq!(cover.count(), 1);
+        // Searching leaves the problem as it was.
+        assert_eq!(cover.first().map(|rows| rows.len()), Some(3));
+    }
+
+    #[test]
+    fn test_secondary() {
+        // Cover both primary columns; the secondary column keeps rows 0 and
+        // 1 from both being picked.
+        let mut cover = ExactCover::new(2, 1);
+        cover.add_row(&[0, 2]);
+        cover.add_row(&[1, 2]);
+        cover.add_row(&[1]);
+        cover.add_row(&[0]);
+        let mut solutions = vec![];
+        cover.solve(|rows| {
+            let mut rows = rows.to_vec();
+            rows.sort();
+            solutions.push(rows);
+            ControlFlow::<()>::Continue(())
+        });
+        solutions.sort();
+        assert_eq!(solutions, [vec![0, 2], vec![1, 3], vec![2, 3]]);
+    }
+
+    #[test]
+    fn test_count() {
+        // Dominoes tiling a 2x4 board: one column per cell.
+        let mut cover = ExactCover::new(8, 0);
+        for r in 0..2 {
+            for c in 0..4 {
+                let cell = r * 4 + c;
+                if c + 1 < 4 {
+                    cover.add_row(&[cell, cell + 1]);
+                }
+                if r + 1 < 2 {
+                    cover.add_row(&[cell, cell + 4]);
+                }
+            }
+        }
+        assert_eq!(cover.count(), 5);
+        assert_eq!(ExactCover::new(1, 0).first(), None);
+        assert_eq!(ExactCover::new(0, 3).count(), 1);
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::BTreeSet;
+
+/// A shape made of square cells, as one bitmask per row with bit `c` set
+/// for a cell in column `c`. It's always shifted up and left as far as it
+/// goes, so equal shapes compare equal however they were built.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Polyomino {
+    rows: Vec<u64>,
+}
+
+impl Polyomino {
+    /// The shape with a cell at each `(row, col)`.
+    ///
+    /// Panics if the shape is more than 64 cells wide.
+    pub fn new(cells: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        let cells: Vec<(usize, usize)> = cells.into_iter().collect();
+        let top = cells.iter().map(|&(r, _)| r).min().unwrap_or(0);
+        let left = cells.iter().map(|&(_, c)| c).min().unwrap_or(0);
+        let mut rows = vec![];
+        for (r, c) in cells {
+            let (r, c) = (r - top, c - left);
+            assert!(c < 64, "Polyominoes can be at most 64 cells wide");
+            if rows.len() <= r {
+                rows.resize(r + 1, 0);
+            }
+            rows[r] |= 1 << c;
+        }
+        Self { rows }
+    }
+
+    /// Reads a shape drawn with `#` for its cells and anything else for
+    /// gaps, one line per row.
+    pub fn from_picture(picture: &str) -> Self {
+        Self::new(picture.lines().enumerate().flat_map(|(r, line)| {
+            line.bytes()
+                .enumerate()
+                .filter(|&(_, b)| b == b'#')
+                .map(move |(c, _)| (r, c))
+        }))
+    }
+
+    /// One bitmask per row, top to bottom.
+    pub fn rows(&self) -> &[u64] {
+        &self.rows
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn width(&self) -> usize {
+        let all = self.rows.iter().fold(0, |acc, row| acc | row);
+        (u64::BITS - all.leading_zeros()) as usize
+    }
+
+    pub fn area(&self) -> usize {
+        self.rows.iter().map(|row| row.count_ones() as usize).sum()
+    }
+
+    /// The cells as `(row, col)`, in reading order.
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.rows.iter().enumerate().flat_map(|(r, &row)| {
+            (0..64)
+                .filter(move |c| (row >> c) & 1 == 1)
+                .map(move |c| (r, c))
+        })
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        let height = self.height();
+        Self::new(self.cells().map(|(r, c)| (c, height - 1 - r)))
+    }
+
+    /// The mirror image, flipped left to right.
+    pub fn reflect(&self) -> Self {
+        let width = self.width();
+        Self::new(self.cells().map(|(r, c)| (r, width - 1 - c)))
+    }
+
+    /// Every distinct way of turning the shape around and over.
+    pub fn orientations(&self) -> Vec<Self> {
+        let mut all = BTreeSet::new();
+        for mut shape in [self.clone(), self.reflect()] {
+            for _ in 0..4 {
+                shape = shape.rotate_clockwise();
+                all.insert(shape.clone());
+            }
+        }
+        all.into_iter().collect()
+    }
+
+    /// Every way of putting the shape down on a board `width` cells wide and
+    /// `height` tall, without turning it. Each is the cells it covers,
+    /// numbered `row * width + col` in reading order, ready to be a row of
+    /// an [`ExactCover`](crate::ExactCover).
+    pub fn placements(&self, width: usize, height: usize) -> Vec<Vec<usize>> {
+        let (rows, cols) = (self.height(), self.width());
+        if rows > height || cols > width {
+            return vec![];
+        }
+        let cells: Vec<(usize, usize)> = self.cells().collect();
+        let mut all = vec![];
+        for top in 0..=height - rows {
+            for left in 0..=width - cols {
+                all.push(
+                    cells
+                        .iter()
+                        .map(|&(r, c)| (top + r) * width + left + c)
+                        .collect(),
+                );
+            }
+        }
+        all
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_orientations() {
+        let l = Polyomino::from_picture("#.\n#.\n##");
+        assert_eq!(l.rows(), [0b01, 0b01, 0b11]);
+        assert_eq!((l.height(), l.width(), l.area()), (3, 2, 4));
+        assert_eq!(l.rotate_clockwise(), Polyomino::from_picture("###\n#.."));
+        assert_eq!(l.reflect(), Polyomino::from_picture(".#\n.#\n##"));
+        assert_eq!(l.orientations().len(), 8);
+        assert_eq!(Polyomino::from_picture("##\n##").orientations().len(), 1);
+        assert_eq!(Polyomino::from_picture("###\n.#.").orientations().len(), 4);
+    }
+
+    #[test]
+    fn test_placements() {
+        let corner = Polyomino::from_picture(".#\n##");
+        assert_eq!(corner.placements(3, 2), [vec![1, 3, 4], vec![2, 4, 5]]);
+        assert!(corner.placements(1, 2).is_empty());
+        // Shifted shapes are the same shape.
+        assert_eq!(Polyomino::new([(5, 7), (6, 6), (6, 7)]), corner);
+    }
+}
@@ -0,0 +1,354 @@
+use std::fmt;
+
+use aoc_grid::{Dir, Grid, Pos};
+
+pub mod matrix;
+
+use matrix::{matmul, transpose, vecmul, Rot3, Vec3, IDENTITY, X_270, X_90, Y_270, Y_90};
+
+/// Why a flat map couldn't be folded into a cube.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum NetError {
+    /// The number of cells isn't six times a square.
+    CellCount(usize),
+    /// The face tile with this top-left corner is only partly filled in.
+    Ragged(Pos),
+    /// The tiles don't all touch, or there aren't six of them.
+    NotConnected,
+    /// Two tiles fold onto the same side of the cube.
+    Overlap(Pos, Pos),
+}
+
+impl fmt::Display for NetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CellCount(cells) => {
+                write!(f, "{cells} cells can't cover the six faces of a cube")
+            }
+            Self::Ragged(pos) => write!(f, "the face at {pos:?} is only partly filled in"),
+            Self::NotConnected => write!(f, "the net isn't six connected faces"),
+            Self::Overlap(a, b) => write!(f, "the faces at {a:?} and {b:?} fold onto each other"),
+        }
+    }
+}
+
+impl std::error::Error for NetError {}
+
+/// One face of the folded cube.
+#[derive(Clone, Copy, Debug)]
+struct Face {
+    /// The face's top-left cell on the net.
+    origin: Pos,
+    /// Takes the face's own axes (right along the net, down along the net,
+    /// and out of the cube) to the cube's axes.
+    frame: Rot3,
+}
+
+impl Face {
+    fn normal(&self) -> Vec3 {
+        vecmul(self.frame, [0, 0, 1])
+    }
+}
+
+/// The direction `dir` points across the net, as a face-local vector.
+fn local(dir: Dir) -> Vec3 {
+    let (dx, dy) = dir.delta();
+    [dx as i64, dy as i64, 0]
+}
+
+fn from_local(v: Vec3) -> Dir {
+    Dir::ALL
+        .into_iter()
+        .find(|&dir| local(dir) == v)
+        .unwrap_or_else(|| panic!("{v:?} doesn't lie flat on the face"))
+}
+
+fn neg([x, y, z]: Vec3) -> Vec3 {
+    [-x, -y, -z]
+}
+
+fn add([a, b, c]: Vec3, [x, y, z]: Vec3) -> Vec3 {
+    [a + x, b + y, c + z]
+}
+
+/// A flat net of six square faces, folded up into a cube.
+///
+/// Any of the eleven nets works, at any face size. Folding the net works out
+/// which edges meet, so walking off one face leads onto the right cell of
+/// the face it's glued to.
+#[derive(Clone, Debug)]
+pub struct Cube {
+    size: usize,
+    faces: Vec<Face>,
+    /// Which face each cell of the net belongs to.
+    face_of: Grid<Option<usize>>,
+}
+
+impl Cube {
+    /// Folds a net drawn with `b' '` for the empty space around it. Any
+    /// other byte is part of a face.
+    pub fn new(net: &Grid<u8>) -> Result<Self, NetError> {
+        let cells = net.iter().filter(|&(_, &c)| c != b' ').count();
+        let size = (1..=cells)
+            .take_while(|size| 6 * size * size <= cells)
+            .find(|size| 6 * size * size == cells)
+            .ok_or(NetError::CellCount(cells))?;
+
+        let filled = |(x, y): Pos| net.get((x, y)).is_some_and(|&c| c != b' ');
+        let (tiles_x, tiles_y) = (net.width().div_ceil(size), net.height().div_ceil(size));
+        let mut tiles = Grid::new(tiles_x, tiles_y, false);
+        for tile in tiles.positions().collect::<Vec<_>>() {
+            let origin = (tile.0 * size, tile.1 * size);
+            let count = (0..size * size)
+                .filter(|i| filled((origin.0 + i % size, origin.1 + i / size)))
+                .count();
+            if count != 0 && count != size * size {
+                return Err(NetError::Ragged(origin));
+            }
+            tiles[tile] = count != 0;
+        }
+
+        // Walk across the net from the first tile, rolling the cube over
+        // each edge that's crossed.
+        let start = tiles.find(&true).ok_or(NetError::NotConnected)?;
+        let mut frames: Grid<Option<Rot3>> = Grid::new(tiles_x, tiles_y, None);
+        frames[start] = Some(IDENTITY);
+        let mut stack = vec![start];
+        while let Some(tile) = stack.pop() {
+            let frame = frames[tile].unwrap();
+            for dir in Dir::ALL {
+                let Some(next) = tiles.step(tile, dir) else {
+                    continue;
+                };
+                if !tiles[next] || frames[next].is_some() {
+                    continue;
+                }
+                let roll = match dir {
+                    Dir::E => Y_90,
+                    Dir::S => X_270,
+                    Dir::W => Y_270,
+                    Dir::N => X_90,
+                };
+                frames[next] = Some(matmul(frame, roll));
+                stack.push(next);
+            }
+        }
+
+        let mut faces: Vec<Face> = vec![];
+        let mut face_of = Grid::new(net.width(), net.height(), None);
+        for (tile, frame) in frames.iter() {
+            if tiles[tile] && frame.is_none() {
+                return Err(NetError::NotConnected);
+            }
+            let Some(frame) = *frame else {
+                continue;
+            };
+            let face = Face {
+                origin: (tile.0 * size, tile.1 * size),
+                frame,
+            };
+            if let Some(other) = faces.iter().find(|other| other.normal() == face.normal()) {
+                return Err(NetError::Overlap(other.origin, face.origin));
+            }
+            for i in 0..size * size {
+                face_of.set(
+                    (face.origin.0 + i % size, face.origin.1 + i / size),
+                    Some(faces.len()),
+                );
+            }
+            faces.push(face);
+        }
+        if faces.len() != 6 {
+            return Err(NetError::NotConnected);
+        }
+        Ok(Self {
+            size,
+            faces,
+            face_of,
+        })
+    }
+
+    /// The length of each face's side.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Each face's top-left cell on the net.
+    pub fn faces(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.faces.iter().map(|face| face.origin)
+    }
+
+    /// Which face `pos` is on, numbered in the order of [`Cube::faces`].
+    pub fn face_of(&self, pos: Pos) -> Option<usize> {
+        self.face_of.get(pos).copied().flatten()
+    }
+
+    /// The face across `face`'s edge in direction `dir`, and which way
+    /// someone crossing that edge faces once they're on it.
+    pub fn neighbor(&self, face: usize, dir: Dir) -> (usize, Dir) {
+        let from = &self.faces[face];
+        let heading = vecmul(from.frame, local(dir));
+        let to = self.face_with_normal(heading);
+        let facing = vecmul(transpose(self.faces[to].frame), neg(from.normal()));
+        (to, from_local(facing))
+    }
+
+    fn face_with_normal(&self, normal: Vec3) -> usize {
+        self.faces
+            .iter()
+            .position(|face| face.normal() == normal)
+            .unwrap()
+    }
+
+    /// The cell one step from `pos` in direction `facing`, and which way
+    /// you're facing once you get there. Stepping off the edge of a face
+    /// folds over onto the face glued to that edge.
+    pub fn wrap(&self, pos: Pos, facing: Dir) -> (Pos, Dir) {
+        let face = self
+            .face_of(pos)
+            .unwrap_or_else(|| panic!("{pos:?} isn't on the cube"));
+        if let Some(next) = self.face_of.step(pos, facing) {
+            if self.face_of(next).is_some() {
+                return (next, facing);
+            }
+        }
+
+        // Work in half-cells centred on the cube, so that every cell centre
+        // has integer coordinates and each face lies at distance `size`.
+        let (from, size) = (self.faces[face], self.size as i64);
+        let centred = |v: usize, origin: usize| 2 * (v - origin) as i64 - size + 1;
+        let centre = vecmul(
+            from.frame,
+            [
+                centred(pos.0, from.origin.0),
+                centred(pos.1, from.origin.1),
+                size,
+            ],
+        );
+        let heading = vecmul(from.frame, local(facing));
+        // Going over the edge, the walker moves one half-cell onwards and
+        // then one half-cell down the side of the cube.
+        let next = add(add(centre, heading), neg(from.normal()));
+
+        let to = self.faces[self.face_with_normal(heading)];
+        let [u, v, _] = vecmul(transpose(to.frame), next);
+        let uncentred = |u: i64, origin: usize| origin + ((u + size - 1) / 2) as usize;
+        let pos = (uncentred(u, to.origin.0), uncentred(v, to.origin.1));
+        let facing = vecmul(transpose(to.frame), neg(from.normal()));
+        (pos, from_local(facing))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// All eleven nets, one `#` per face.
+    const NETS: [&str; 11] = [
+        "#...\n####\n#...",
+        "#...\n####\n.#..",
+        "#...\n####\n..#.",
+        "#...\n####\n...#",
+        ".#..\n####\n.#..",
+        ".#..\n####\n..#.",
+        "##..\n.###\n.#..",
+        "##..\n.###\n..#.",
+        "##..\n.###\n...#",
+        "##..\n.##.\n..##",
+        "###..\n..###",
+    ];
+
+    /// Blows each `#` up into a `size` by `size` face.
+    fn scale(net: &str, size: usize) -> Grid<u8> {
+        let tiles = Grid::parse(net).unwrap();
+        Grid::from_fn(
+            tiles.width() * size,
+            tiles.height() * size,
+            |(x, y)| match tiles[(x / size, y / size)] {
+                b'#' => b'.',
+                _ => b' ',
+            },
+        )
+    }
+
+    #[test]
+    fn test_every_net_round_trips() {
+        for net in NETS {
+            for size in 1..=4 {
+                let cube = Cube::new(&scale(net, size)).unwrap();
+                assert_eq!(cube.size(), size);
+                for pos in cube
+                    .face_of
+                    .positions()
+                    .filter(|&pos| cube.face_of(pos).is_some())
+                {
+                    for dir in Dir::ALL {
+                        // Stepping forward and then back again returns to
+                        // where you started.
+                        let (next, facing) = cube.wrap(pos, dir);
+                        let (back, facing) = cube.wrap(next, facing.reverse());
+                        assert_eq!((back, facing), (pos, dir.reverse()), "{net}");
+
+                        // And walking four faces' worth goes all the way
+                        // around the cube.
+                        let (mut at, mut facing) = (pos, dir);
+                        for _ in 0..4 * size {
+                            (at, facing) = cube.wrap(at, facing);
+                        }
+                        assert_eq!((at, facing), (pos, dir), "{net}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_adjacency() {
+        for net in NETS {
+            let cube = Cube::new(&scale(net, 1)).unwrap();
+            for face in 0..6 {
+                let mut neighbors: Vec<usize> =
+                    Dir::ALL.map(|dir| cube.neighbor(face, dir).0).to_vec();
+                assert!(!neighbors.contains(&face));
+                neighbors.sort();
+                neighbors.dedup();
+                assert_eq!(neighbors.len(), 4, "{net}");
+                for dir in Dir::ALL {
+                    let (other, facing) = cube.neighbor(face, dir);
+                    let (back, _) = cube.neighbor(other, facing.reverse());
+                    assert_eq!(back, face);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_puzzle_example() {
+        let cube = Cube::new(&scale("..#.\n###.\n..##", 4)).unwrap();
+        // The examples from the puzzle text, walking off the edges of A and
+        // D.
+        assert_eq!(cube.wrap((11, 5), Dir::E), ((14, 8), Dir::S));
+        assert_eq!(cube.wrap((10, 11), Dir::S), ((1, 7), Dir::N));
+        assert_eq!(cube.wrap((6, 4), Dir::N), ((8, 2), Dir::E));
+    }
+
+    #[test]
+    fn test_bad_nets() {
+        assert!(matches!(
+            Cube::new(&scale("####\n##..", 2)),
+            Err(NetError::Overlap(..))
+        ));
+        assert_eq!(
+            Cube::new(&scale("###.\n...#\n..##", 1)).unwrap_err(),
+            NetError::NotConnected
+        );
+        let mut ragged = scale(NETS[0], 2);
+        ragged[(0, 0)] = b' ';
+        ragged[(2, 0)] = b'.';
+        assert_eq!(Cube::new(&ragged).unwrap_err(), NetError::Ragged((0, 0)));
+        assert_eq!(
+            Cube::new(&Grid::parse("..").unwrap()).unwrap_err(),
+            NetError::CellCount(2)
+        );
+    }
+}
@@ -0,0 +1,294 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// A simulation that has to be run for far more steps than is practical,
+/// but which settles into a loop along the way.
+pub trait Simulation {
+    /// The part of the state that decides how the simulation carries on.
+    /// A key that leaves something out can repeat by coincidence, which is
+    /// what [`Cycle::periods`] guards against.
+    type Key: Eq + Hash;
+
+    fn key(&self) -> Self::Key;
+
+    /// The running total to extrapolate, such as the height of a tower.
+    fn metric(&self) -> i64;
+
+    fn step(&mut self);
+}
+
+/// A loop found in a simulation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    /// The step the loop was first seen to start at.
+    pub start: usize,
+    /// How many steps it takes to come round again.
+    pub period: usize,
+    /// How much the metric grows each time round.
+    pub gain: i64,
+    /// How many times in a row the loop came round with the same keys and
+    /// the same gain. The more times, the less likely that the repeat was a
+    /// coincidence of an incomplete key.
+    pub periods: usize,
+}
+
+impl Cycle {
+    /// The metric after `target` steps, given its value at each step of the
+    /// first period, starting from [`Cycle::start`].
+    fn extrapolate(&self, window: &[i64], target: usize) -> i64 {
+        let laps = (target - self.start) / self.period;
+        let offset = (target - self.start) % self.period;
+        window[offset] + laps as i64 * self.gain
+    }
+}
+
+/// The metric after the target number of steps.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Extrapolated {
+    pub value: i64,
+    /// The loop that was skipped over, or `None` if the simulation was
+    /// simply run to the target.
+    pub cycle: Option<Cycle>,
+}
+
+/// Runs `sim` for `target` steps without looking for a loop.
+fn run<S: Simulation>(mut sim: S, target: usize) -> Extrapolated {
+    for _ in 0..target {
+        sim.step();
+    }
+    Extrapolated {
+        value: sim.metric(),
+        cycle: None,
+    }
+}
+
+/// Whether the last `periods + 1` sightings of a key are evenly spaced, with
+/// the metric growing by the same amount between each.
+fn repeating(sightings: &[usize], metrics: &[i64], periods: usize) -> Option<Cycle> {
+    if sightings.len() <= periods {
+        return None;
+    }
+    let recent = &sightings[sightings.len() - periods - 1..];
+    let period = recent[1] - recent[0];
+    let gain = metrics[recent[1]] - metrics[recent[0]];
+    recent
+        .windows(2)
+        .all(|pair| pair[1] - pair[0] == period && metrics[pair[1]] - metrics[pair[0]] == gain)
+        .then_some(Cycle {
+            start: recent[0],
+            period,
+            gain,
+            periods,
+        })
+}
+
+/// The metric after `target` steps of `sim`, skipping ahead once the same
+/// key has come round `periods` times in a row at an even spacing, with the
+/// metric growing by the same amount each time.
+///
+/// Every key seen is remembered, along with the metric at every step. With
+/// a key that captures the whole state, `periods` can be 1.
+pub fn extrapolate<S: Simulation>(mut sim: S, target: usize, periods: usize) -> Extrapolated {
+    let periods = periods.max(1);
+    let mut metrics = vec![];
+    let mut seen: HashMap<S::Key, Vec<usize>> = HashMap::new();
+    let mut step = 0;
+    loop {
+        metrics.push(sim.metric());
+        if step == target {
+            return Extrapolated {
+                value: metrics[step],
+                cycle: None,
+            };
+        }
+        let sightings = seen.entry(sim.key()).or_default();
+        sightings.push(step);
+        if let Some(cycle) = repeating(sightings, &metrics, periods) {
+            return Extrapolated {
+                value: cycle.extrapolate(&metrics[cycle.start..], target),
+                cycle: Some(cycle),
+            };
+        }
+        sim.step();
+        step += 1;
+    }
+}
+
+/// The metric after `target` steps of `sim`, finding the loop with Brent's
+/// algorithm. That only ever holds two copies of the simulation, rather than
+/// every key seen, but it trusts the first repeat it finds. After the loop
+/// is found, it's followed for up to `periods` times round to check that the
+/// keys and gain hold up, and [`Cycle::periods`] says how many times they
+/// did.
+pub fn extrapolate_brent<S: Simulation + Clone>(
+    sim: S,
+    target: usize,
+    periods: usize,
+) -> Extrapolated {
+    // The hare runs ahead, and the tortoise jumps to meet it at each power of
+    // two, until the hare comes back round to where the tortoise is waiting.
+    let (mut power, mut period) = (1, 1);
+    let mut tortoise = sim.key();
+    let mut hare = sim.clone();
+    hare.step();
+    let mut hare_steps = 1;
+    while hare.key() != tortoise {
+        if hare_steps >= target {
+            return run(sim, target);
+        }
+        if power == period {
+            tortoise = hare.key();
+            power *= 2;
+            period = 0;
+        }
+        hare.step();
+        hare_steps += 1;
+        period += 1;
+    }
+
+    // With the hare a period ahead, they first meet where the loop starts.
+    let mut tortoise = sim.clone();
+    let mut hare = sim.clone();
+    for _ in 0..period {
+        hare.step();
+    }
+    let mut start = 0;
+    while tortoise.key() != hare.key() {
+        tortoise.step();
+        hare.step();
+        start += 1;
+    }
+    if target < start + period {
+        return run(sim, target);
+    }
+
+    let (mut keys, mut window) = (vec![], vec![]);
+    for _ in 0..period {
+        keys.push(tortoise.key());
+        window.push(tortoise.metric());
+        tortoise.step();
+    }
+    let gain = tortoise.metric() - window[0];
+    let mut seen = 1;
+    'confirm: while seen < periods {
+        for (key, &metric) in keys.iter().zip(&window) {
+            if tortoise.key() != *key || tortoise.metric() != metric + seen as i64 * gain {
+                break 'confirm;
+            }
+            tortoise.step();
+        }
+        seen += 1;
+    }
+
+    let cycle = Cycle {
+        start,
+        period,
+        gain,
+        periods: seen,
+    };
+    Extrapolated {
+        value: cycle.extrapolate(&window, target),
+        cycle: Some(cycle),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Squares and increments a number mod 1000, keeping a running total.
+    #[derive(Clone)]
+    struct Squares {
+        x: u64,
+        total: i64,
+    }
+
+    impl Simulation for Squares {
+        type Key = u64;
+
+        fn key(&self) -> u64 {
+            self.x
+        }
+
+        fn metric(&self) -> i64 {
+            self.total
+        }
+
+        fn step(&mut self) {
+            self.x = (self.x * self.x + 1) % 1000;
+            self.total += self.x as i64;
+        }
+    }
+
+    /// Counts up forever, but only shows the last digit.
+    #[derive(Clone)]
+    struct Odometer {
+        n: i64,
+    }
+
+    impl Simulation for Odometer {
+        type Key = i64;
+
+        fn key(&self) -> i64 {
+            self.n % 10
+        }
+
+        fn metric(&self) -> i64 {
+            // Jumps by an extra 5 at 15, so the first lap isn't like the rest.
+            self.n + if self.n >= 15 { 5 } else { 0 }
+        }
+
+        fn step(&mut self) {
+            self.n += 1;
+        }
+    }
+
+    fn squares() -> Squares {
+        Squares { x: 3, total: 0 }
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        for target in [0, 1, 5, 50, 200, 1234] {
+            let expected = run(squares(), target).value;
+            assert_eq!(extrapolate(squares(), target, 1).value, expected);
+            assert_eq!(extrapolate(squares(), target, 3).value, expected);
+            assert_eq!(extrapolate_brent(squares(), target, 3).value, expected);
+        }
+    }
+
+    #[test]
+    fn test_same_cycle() {
+        let hashed = extrapolate(squares(), 1_000_000_000_000, 1).cycle.unwrap();
+        let brent = extrapolate_brent(squares(), 1_000_000_000_000, 1)
+            .cycle
+            .unwrap();
+        assert_eq!(hashed, brent);
+        assert!(hashed.period > 1);
+    }
+
+    #[test]
+    fn test_short_target() {
+        assert_eq!(
+            extrapolate(Odometer { n: 0 }, 3, 1),
+            Extrapolated {
+                value: 3,
+                cycle: None
+            }
+        );
+        assert_eq!(extrapolate_brent(Odometer { n: 0 }, 3, 1).cycle, None);
+    }
+
+    #[test]
+    fn test_periods() {
+        // One lap isn't enough to see past the jump.
+        let hasty = extrapolate(Odometer { n: 0 }, 1000, 1);
+        assert_eq!(hasty.value, 1000);
+        let careful = extrapolate(Odometer { n: 0 }, 1000, 2);
+        assert_eq!(careful.value, 1005);
+        assert_eq!(careful.cycle.unwrap().periods, 2);
+
+        let brent = extrapolate_brent(Odometer { n: 0 }, 1000, 3).cycle.unwrap();
+        assert_eq!((brent.start, brent.period, brent.gain), (0, 10, 10));
+        assert_eq!(brent.periods, 1);
+    }
+}
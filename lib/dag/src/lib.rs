@@ -0,0 +1,344 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt,
+    hash::Hash,
+};
+
+/// A loop of dependencies, which leaves no order to put its nodes in. Each
+/// node depends on the one after it, and the last on the first.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cycle<N>(pub Vec<N>);
+
+impl<N: fmt::Display> fmt::Display for Cycle<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for node in &self.0 {
+            write!(f, "{node} -> ")?;
+        }
+        match self.0.first() {
+            Some(first) => write!(f, "{first}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<N: fmt::Debug + fmt::Display> std::error::Error for Cycle<N> {}
+
+/// The nodes to sort, numbered in the order they were given, and which of
+/// them each one depends on.
+struct Indexed<N> {
+    nodes: Vec<N>,
+    deps: Vec<Vec<usize>>,
+}
+
+impl<N: Clone + Eq + Hash> Indexed<N> {
+    fn new<I: IntoIterator<Item = N>>(
+        nodes: impl IntoIterator<Item = N>,
+        mut deps: impl FnMut(&N) -> I,
+    ) -> Self {
+        let mut index = HashMap::new();
+        let mut unique = vec![];
+        for node in nodes {
+            index.entry(node.clone()).or_insert_with(|| {
+                unique.push(node);
+                unique.len() - 1
+            });
+        }
+        let deps = unique
+            .iter()
+            .map(|node| {
+                deps(node)
+                    .into_iter()
+                    .filter_map(|dep| index.get(&dep).copied())
+                    .collect()
+            })
+            .collect();
+        Self {
+            nodes: unique,
+            deps,
+        }
+    }
+
+    fn cycle(&self, indices: &[usize]) -> Cycle<N> {
+        Cycle(indices.iter().map(|&i| self.nodes[i].clone()).collect())
+    }
+
+    fn sorted(&self, order: Vec<usize>) -> Vec<N> {
+        order.into_iter().map(|i| self.nodes[i].clone()).collect()
+    }
+}
+
+/// Sorts `nodes` so that each comes after everything it depends on, using
+/// Kahn's algorithm: repeatedly take the nodes with nothing left to wait
+/// for. Ties keep the order the nodes were given in.
+///
+/// Only dependencies that are themselves among `nodes` count. Anything else
+/// `deps` returns is taken to be available already.
+pub fn kahn<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    deps: impl FnMut(&N) -> I,
+) -> Result<Vec<N>, Cycle<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let graph = Indexed::new(nodes, deps);
+    let mut waiting: Vec<usize> = graph.deps.iter().map(Vec::len).collect();
+    let mut dependents = vec![vec![]; graph.nodes.len()];
+    for (i, deps) in graph.deps.iter().enumerate() {
+        for &dep in deps {
+            dependents[dep].push(i);
+        }
+    }
+
+    let mut ready: VecDeque<usize> = (0..graph.nodes.len())
+        .filter(|&i| waiting[i] == 0)
+        .collect();
+    let mut order = vec![];
+    while let Some(i) = ready.pop_front() {
+        order.push(i);
+        for &j in &dependents[i] {
+            waiting[j] -= 1;
+            if waiting[j] == 0 {
+                ready.push_back(j);
+            }
+        }
+    }
+
+    let Some(stuck) = (0..graph.nodes.len()).find(|&i| waiting[i] > 0) else {
+        return Ok(graph.sorted(order));
+    };
+    // Every node still waiting is waiting on another one that's stuck, so
+    // following them round must eventually come back on itself.
+    let mut path = vec![stuck];
+    let mut on_path = HashMap::from([(stuck, 0)]);
+    loop {
+        let last = *path.last().unwrap();
+        let next = *graph.deps[last]
+            .iter()
+            .find(|&&dep| waiting[dep] > 0)
+            .unwrap();
+        if let Some(&start) = on_path.get(&next) {
+            return Err(graph.cycle(&path[start..]));
+        }
+        on_path.insert(next, path.len());
+        path.push(next);
+    }
+}
+
+/// Sorts `nodes` so that each comes after everything it depends on, by
+/// depth-first search: each node is placed as soon as all of its
+/// dependencies have been. Follows the same rules as [`kahn`], but may put
+/// the nodes in a different order.
+pub fn dfs<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    deps: impl FnMut(&N) -> I,
+) -> Result<Vec<N>, Cycle<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    enum Mark {
+        Visiting,
+        Done,
+    }
+
+    let graph = Indexed::new(nodes, deps);
+    let mut marks = vec![None; graph.nodes.len()];
+    let mut order = vec![];
+    for root in 0..graph.nodes.len() {
+        if marks[root].is_some() {
+            continue;
+        }
+        marks[root] = Some(Mark::Visiting);
+        // Each node being visited, and how many of its dependencies have
+        // been looked at so far.
+        let mut stack = vec![(root, 0)];
+        while let Some((i, next)) = stack.last_mut() {
+            let Some(&dep) = graph.deps[*i].get(*next) else {
+                marks[*i] = Some(Mark::Done);
+                order.push(*i);
+                stack.pop();
+                continue;
+            };
+            *next += 1;
+            match marks[dep] {
+                None => {
+                    marks[dep] = Some(Mark::Visiting);
+                    stack.push((dep, 0));
+                }
+                Some(Mark::Visiting) => {
+                    let start = stack.iter().position(|&(j, _)| j == dep).unwrap();
+                    let path: Vec<usize> = stack[start..].iter().map(|&(j, _)| j).collect();
+                    return Err(graph.cycle(&path));
+                }
+                Some(Mark::Done) => {}
+            }
+        }
+    }
+    Ok(graph.sorted(order))
+}
+
+/// Why a node couldn't be evaluated.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EvalError<N> {
+    Cycle(Cycle<N>),
+    /// The node has neither a gate nor a known value.
+    Missing(N),
+}
+
+impl<N: fmt::Display> fmt::Display for EvalError<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cycle(cycle) => write!(f, "{cycle} depend on each other"),
+            Self::Missing(node) => write!(f, "nothing gives {node} a value"),
+        }
+    }
+}
+
+impl<N: fmt::Debug + fmt::Display> std::error::Error for EvalError<N> {}
+
+/// Evaluates nodes of a circuit, where each node is either an input with a
+/// known value or the output of a gate, `node → (op, inputs)`. Every value
+/// worked out is remembered, so each gate is only evaluated once however
+/// many nodes are asked for.
+pub struct Evaluator<'a, N, Op, V> {
+    gates: &'a HashMap<N, (Op, Vec<N>)>,
+    values: HashMap<N, V>,
+}
+
+impl<'a, N, Op, V> Evaluator<'a, N, Op, V>
+where
+    N: Clone + Eq + Hash,
+    V: Clone,
+{
+    pub fn new(gates: &'a HashMap<N, (Op, Vec<N>)>, inputs: HashMap<N, V>) -> Self {
+        Self {
+            gates,
+            values: inputs,
+        }
+    }
+
+    /// The value of `node`, working out whatever it depends on by calling
+    /// `apply` with each gate's op and the values of its inputs.
+    pub fn eval(
+        &mut self,
+        node: &N,
+        mut apply: impl FnMut(&Op, &[V]) -> V,
+    ) -> Result<V, EvalError<N>> {
+        let inputs = |node: &N| match self.gates.get(node) {
+            Some((_, inputs)) if !self.values.contains_key(node) => inputs.clone(),
+            _ => vec![],
+        };
+        let mut needed = vec![node.clone()];
+        let mut seen = HashSet::from([node.clone()]);
+        let mut stack = vec![node.clone()];
+        while let Some(node) = stack.pop() {
+            for input in inputs(&node) {
+                if seen.insert(input.clone()) {
+                    needed.push(input.clone());
+                    stack.push(input);
+                }
+            }
+        }
+
+        for node in dfs(needed, inputs).map_err(EvalError::Cycle)? {
+            if self.values.contains_key(&node) {
+                continue;
+            }
+            let (op, inputs) = self
+                .gates
+                .get(&node)
+                .ok_or_else(|| EvalError::Missing(node.clone()))?;
+            let args: Vec<V> = inputs
+                .iter()
+                .map(|input| self.values[input].clone())
+                .collect();
+            let value = apply(op, &args);
+            self.values.insert(node, value);
+        }
+        Ok(self.values[node].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Each letter depends on the letters after the colon.
+    fn deps(spec: &'static str) -> impl FnMut(&char) -> Vec<char> {
+        move |node| {
+            spec.lines()
+                .find(|line| line.starts_with(*node))
+                .map(|line| line[2..].chars().collect())
+                .unwrap_or_default()
+        }
+    }
+
+    /// Whether every node comes after all of its dependencies.
+    fn is_sorted(order: &[char], spec: &'static str) -> bool {
+        let mut deps = deps(spec);
+        order.iter().enumerate().all(|(i, node)| {
+            deps(node)
+                .iter()
+                .all(|dep| !order.contains(dep) || order[..i].contains(dep))
+        })
+    }
+
+    const DIAMOND: &str = "a:bc\nb:d\nc:d\nd:";
+
+    #[test]
+    fn test_kahn() {
+        let order = kahn("abcd".chars(), deps(DIAMOND)).unwrap();
+        assert_eq!(order, ['d', 'b', 'c', 'a']);
+        assert_eq!(kahn("ca".chars(), deps(DIAMOND)).unwrap(), ['c', 'a']);
+    }
+
+    #[test]
+    fn test_dfs() {
+        let order = dfs("abcd".chars(), deps(DIAMOND)).unwrap();
+        assert!(is_sorted(&order, DIAMOND));
+        assert_eq!(order.len(), 4);
+        assert_eq!(dfs("aab".chars(), deps(DIAMOND)).unwrap(), ['b', 'a']);
+    }
+
+    #[test]
+    fn test_cycle() {
+        let spec = "a:b\nb:c\nc:ad\nd:";
+        let mut cycle = kahn("abcd".chars(), deps(spec)).unwrap_err().0;
+        cycle.sort();
+        assert_eq!(cycle, ['a', 'b', 'c']);
+        assert_eq!(
+            dfs("abcd".chars(), deps(spec)).unwrap_err().to_string(),
+            "a -> b -> c -> a"
+        );
+        assert_eq!(dfs("a".chars(), deps("a:a")), Err(Cycle(vec!['a'])));
+    }
+
+    #[test]
+    fn test_evaluator() {
+        let gates = HashMap::from([
+            ("sum", ('+', vec!["x", "y"])),
+            ("double", ('+', vec!["sum", "sum"])),
+            ("bad", ('+', vec!["x", "z"])),
+            ("loop", ('+', vec!["loop"])),
+        ]);
+        let mut calls = 0;
+        let mut evaluator = Evaluator::new(&gates, HashMap::from([("x", 2), ("y", 3)]));
+        let mut apply = |_: &char, args: &[i64]| {
+            calls += 1;
+            args.iter().sum()
+        };
+        assert_eq!(evaluator.eval(&"double", &mut apply), Ok(10));
+        assert_eq!(evaluator.eval(&"sum", &mut apply), Ok(5));
+        assert_eq!(
+            evaluator.eval(&"bad", &mut apply),
+            Err(EvalError::Missing("z"))
+        );
+        assert_eq!(
+            evaluator.eval(&"loop", &mut apply),
+            Err(EvalError::Cycle(Cycle(vec!["loop"])))
+        );
+        assert_eq!(calls, 2);
+    }
+}
@@ -0,0 +1,429 @@
+use std::fmt;
+
+use aoc_parse::{Error, Section};
+
+/// A cell's `(x, y)` coordinates, with the origin at the top-left.
+pub type Pos = (usize, usize);
+
+/// One of the four cardinal directions, with north pointing up the screen.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Dir {
+    N = 0,
+    E = 1,
+    S = 2,
+    W = 3,
+}
+
+impl Dir {
+    pub const ALL: [Dir; 4] = [Dir::N, Dir::E, Dir::S, Dir::W];
+
+    pub fn cw(self) -> Self {
+        match self {
+            Self::N => Self::E,
+            Self::E => Self::S,
+            Self::S => Self::W,
+            Self::W => Self::N,
+        }
+    }
+
+    pub fn ccw(self) -> Self {
+        match self {
+            Self::N => Self::W,
+            Self::W => Self::S,
+            Self::S => Self::E,
+            Self::E => Self::N,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        self.cw().cw()
+    }
+
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Self::N => (0, -1),
+            Self::E => (1, 0),
+            Self::S => (0, 1),
+            Self::W => (-1, 0),
+        }
+    }
+
+    /// Parses an arrow (`^>v<`, with `V` for down too), a compass letter
+    /// (`NESW`) or an up, right, down or left letter (`URDL`).
+    pub fn from_byte(c: u8) -> Option<Self> {
+        match c {
+            b'^' | b'N' | b'U' => Some(Self::N),
+            b'>' | b'E' | b'R' => Some(Self::E),
+            b'v' | b'V' | b'S' | b'D' => Some(Self::S),
+            b'<' | b'W' | b'L' => Some(Self::W),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> u8 {
+        match self {
+            Self::N => b'^',
+            Self::E => b'>',
+            Self::S => b'v',
+            Self::W => b'<',
+        }
+    }
+}
+
+/// One of the eight compass directions, for puzzles that look diagonally.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Dir8 {
+    N = 0,
+    NE = 1,
+    E = 2,
+    SE = 3,
+    S = 4,
+    SW = 5,
+    W = 6,
+    NW = 7,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::N,
+        Dir8::NE,
+        Dir8::E,
+        Dir8::SE,
+        Dir8::S,
+        Dir8::SW,
+        Dir8::W,
+        Dir8::NW,
+    ];
+
+    pub fn cw(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn ccw(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Self::N => (0, -1),
+            Self::NE => (1, -1),
+            Self::E => (1, 0),
+            Self::SE => (1, 1),
+            Self::S => (0, 1),
+            Self::SW => (-1, 1),
+            Self::W => (-1, 0),
+            Self::NW => (-1, -1),
+        }
+    }
+}
+
+impl From<Dir> for Dir8 {
+    fn from(dir: Dir) -> Self {
+        match dir {
+            Dir::N => Self::N,
+            Dir::E => Self::E,
+            Dir::S => Self::S,
+            Dir::W => Self::W,
+        }
+    }
+}
+
+/// A dense, rectangular 2D grid stored in row-major order.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T = u8> {
+    data: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn from_vec(width: usize, data: Vec<T>) -> Self {
+        assert!(
+            width > 0 && data.len().is_multiple_of(width),
+            "{} cells can't be split into rows of {width}",
+            data.len()
+        );
+        let height = data.len() / width;
+        Self {
+            data,
+            width,
+            height,
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let mut data = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                data.push(f((x, y)));
+            }
+        }
+        Self {
+            data,
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.in_bounds(pos) {
+            Some(&self.data[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.in_bounds(pos) {
+            Some(&mut self.data[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    /// Overwrites the cell at `pos`, returning `false` if it's off the grid.
+    pub fn set(&mut self, pos: Pos, value: T) -> bool {
+        match self.get_mut(pos) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The position `delta` away from `pos`, if that's still on the grid.
+    pub fn offset(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        if self.in_bounds(pos) {
+            Some(pos)
+        } else {
+            None
+        }
+    }
+
+    pub fn step(&self, pos: Pos, dir: Dir) -> Option<Pos> {
+        self.offset(pos, dir.delta())
+    }
+
+    /// The on-grid positions orthogonally adjacent to `pos`.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// The on-grid positions orthogonally or diagonally adjacent to `pos`.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |dir| self.offset(pos, dir.delta()))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.data.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| &self.data[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            data: self.data.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// The first cell in reading order holding `value`.
+    pub fn find(&self, value: &T) -> Option<Pos> {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Pos> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            data: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl Grid<u8> {
+    /// Parses the puzzle text one line per row. Every row must be the same
+    /// length, and the grid can't have blank lines in it, though it can
+    /// have them around it.
+    pub fn parse(input: &str) -> aoc_parse::Result<Self> {
+        let [section] = aoc_parse::split_sections(input)?;
+        Self::parse_section(&section)
+    }
+
+    /// Parses a section of the input one line per row, pointing any error
+    /// at the first row that's the wrong length.
+    pub fn parse_section(section: &Section) -> aoc_parse::Result<Self> {
+        let mut data = vec![];
+        let mut width = None;
+        for cursor in section.lines() {
+            let row = cursor.rest().as_bytes();
+            match width {
+                None if row.is_empty() => return Err(cursor.error("expected a grid row")),
+                None => width = Some(row.len()),
+                Some(width) if row.len() != width => {
+                    return Err(Error::new(
+                        cursor.line_no(),
+                        width.min(row.len()) + 1,
+                        format!("expected a row of {width} cells, found {}", row.len()),
+                    ))
+                }
+                Some(_) => {}
+            }
+            data.extend_from_slice(row);
+        }
+        match width {
+            Some(width) => Ok(Self::from_vec(width, data)),
+            None => Err(section.error("expected a grid")),
+        }
+    }
+
+    /// Parses the puzzle text one line per row, padding short rows on the
+    /// right with `fill`.
+    pub fn parse_padded(input: &str, fill: u8) -> aoc_parse::Result<Self> {
+        let width = input.lines().map(|line| line.len()).max().unwrap_or(0);
+        if width == 0 {
+            return Err(Error::new(1, 1, "expected a grid"));
+        }
+        let mut data = vec![];
+        for line in input.lines() {
+            data.extend_from_slice(line.as_bytes());
+            data.resize(data.len() + width - line.len(), fill);
+        }
+        Ok(Self::from_vec(width, data))
+    }
+}
+
+impl<T> std::ops::Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the grid"))
+    }
+}
+
+impl<T> std::ops::IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the grid"))
+    }
+}
+
+impl fmt::Display for Grid<u8> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", String::from_utf8_lossy(row))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "#.S\n...\nE.#\n";
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = Grid::parse(SAMPLE).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[(2, 0)], b'S');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), SAMPLE);
+
+        let empty = Grid::new(0, 2, b'.');
+        assert_eq!(empty.rows().collect::<Vec<_>>(), [[], []]);
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = Grid::parse(SAMPLE).unwrap();
+        assert_eq!(grid.find(&b'S'), Some((2, 0)));
+        assert_eq!(grid.find(&b'E'), Some((0, 2)));
+        assert_eq!(grid.find_all(&b'#').collect::<Vec<_>>(), [(0, 0), (2, 2)]);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::parse(SAMPLE).unwrap();
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbors8((2, 2)).count(), 3);
+    }
+
+    #[test]
+    fn test_turns() {
+        for dir in Dir::ALL {
+            assert_eq!(dir.cw().ccw(), dir);
+            assert_eq!(dir.reverse().reverse(), dir);
+            assert_eq!(Dir8::from(dir).cw().cw(), Dir8::from(dir.cw()));
+        }
+    }
+
+    #[test]
+    fn test_parse_padded() {
+        let grid = Grid::parse_padded("  ..\n.\n", b' ').unwrap();
+        assert_eq!(grid.to_string(), "  ..\n.   \n");
+        assert!(Grid::parse_padded("\n", b' ').is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = |input| Grid::parse(input).unwrap_err().to_string();
+        assert_eq!(
+            err("#..\n#.\n"),
+            "line 2, column 3: expected a row of 3 cells, found 2"
+        );
+        assert_eq!(
+            err("\n#.\n.#\n#..\n"),
+            "line 4, column 3: expected a row of 2 cells, found 3"
+        );
+        assert_eq!(
+            err(""),
+            "line 1, column 1: expected 1 blank-line-separated sections, found 0"
+        );
+        assert!(Grid::parse("#.\n\n.#\n").is_err());
+    }
+}
@@ -0,0 +1,231 @@
+use std::ops::{Add, Range, Sub};
+
+mod map;
+
+pub use map::RangeMap;
+
+/// A set of integers, stored as the ranges they make up.
+///
+/// The ranges are half-open, like Rust's, and kept sorted and coalesced: none
+/// of them is empty, and no two overlap or even touch. So a set has only one
+/// way of being stored, however it was built.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { ranges: vec![] }
+    }
+
+    /// The ranges making up the set, in order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The smallest value in the set.
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges.get(i).is_some_and(|range| range.start <= value)
+    }
+
+    /// Adds every value in `range`, merging it with any ranges it overlaps
+    /// or touches.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let lo = self.ranges.partition_point(|r| r.end < range.start);
+        let hi = self.ranges.partition_point(|r| r.start <= range.end);
+        let merged = if lo < hi {
+            range.start.min(self.ranges[lo].start)..range.end.max(self.ranges[hi - 1].end)
+        } else {
+            range
+        };
+        self.ranges.splice(lo..hi, [merged]);
+    }
+
+    /// Coalesces ranges that are already sorted by their starts.
+    fn from_sorted(ranges: impl IntoIterator<Item = Range<T>>) -> Self {
+        let mut set = Self::new();
+        for range in ranges {
+            if range.is_empty() {
+                continue;
+            }
+            match set.ranges.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => set.ranges.push(range),
+            }
+        }
+        set
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut ranges: Vec<Range<T>> = self.ranges.iter().chain(&other.ranges).cloned().collect();
+        ranges.sort_by_key(|range| range.start);
+        Self::from_sorted(ranges)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            // Whichever ends first can't overlap anything further on.
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The values in `self` but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut j = 0;
+        for range in &self.ranges {
+            while j < other.ranges.len() && other.ranges[j].end <= range.start {
+                j += 1;
+            }
+            let mut start = range.start;
+            for cut in other.ranges[j..]
+                .iter()
+                .take_while(|cut| cut.start < range.end)
+            {
+                if start < cut.start {
+                    ranges.push(start..cut.start);
+                }
+                start = start.max(cut.end);
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        Self { ranges }
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).is_empty()
+    }
+}
+
+impl<T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T>> IntervalSet<T> {
+    /// How many values are in the set.
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::default(), |len, range| len + (range.end - range.start))
+    }
+}
+
+impl<T: Copy + Ord> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::from_sorted([range])
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut ranges: Vec<Range<T>> = iter.into_iter().collect();
+        ranges.sort_by_key(|range| range.start);
+        Self::from_sorted(ranges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coalesce() {
+        let set: IntervalSet<i64> = [10..15, 3..6, 16..21, 12..19, 6..8, 30..30]
+            .into_iter()
+            .collect();
+        assert_eq!(set.ranges(), [3..8, 10..21]);
+        assert_eq!(set.len(), 16);
+        assert_eq!(set.min(), Some(3));
+        assert!(set.contains(7) && set.contains(10) && set.contains(20));
+        assert!(!set.contains(8) && !set.contains(21) && !set.contains(2));
+
+        let mut inserted = IntervalSet::new();
+        for range in [10..15, 3..6, 16..21, 12..19, 6..8, 30..30] {
+            inserted.insert(range);
+        }
+        assert_eq!(inserted, set);
+        inserted.insert(8..10);
+        assert_eq!(inserted, IntervalSet::from(3..21));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a: IntervalSet<u64> = [0..10, 20..30].into_iter().collect();
+        let b: IntervalSet<u64> = [5..25, 28..40].into_iter().collect();
+        assert_eq!(a.union(&b), IntervalSet::from(0..40));
+        assert_eq!(a.intersection(&b).ranges(), [5..10, 20..25, 28..30]);
+        assert_eq!(a.difference(&b).ranges(), [0..5, 25..28]);
+        assert_eq!(b.difference(&a).ranges(), [10..20, 30..40]);
+        assert!(IntervalSet::from(22..24).is_subset(&a));
+        assert!(!IntervalSet::from(8..12).is_subset(&a));
+        assert!(IntervalSet::from(10..20).is_disjoint(&a));
+        assert!(IntervalSet::<u64>::new().is_subset(&a));
+    }
+
+    #[test]
+    fn test_against_bits() {
+        // Every pair of sets of 0..12 made from up to three ranges.
+        let to_bits = |set: &IntervalSet<u32>| {
+            (0..12)
+                .filter(|&x| set.contains(x))
+                .fold(0u32, |bits, x| bits | 1 << x)
+        };
+        let mut sets = vec![];
+        let mut seed = 7u64;
+        for _ in 0..60 {
+            let set: IntervalSet<u32> = (0..3)
+                .map(|_| {
+                    seed = seed
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(1442695040888963407);
+                    let start = (seed >> 33) as u32 % 12;
+                    let end = (seed >> 45) as u32 % 13;
+                    start..end
+                })
+                .collect();
+            sets.push(set);
+        }
+        for a in &sets {
+            for b in &sets {
+                let (x, y) = (to_bits(a), to_bits(b));
+                assert_eq!(to_bits(&a.union(b)), x | y);
+                assert_eq!(to_bits(&a.intersection(b)), x & y);
+                assert_eq!(to_bits(&a.difference(b)), x & !y);
+                assert_eq!(a.len(), x.count_ones());
+            }
+        }
+    }
+}
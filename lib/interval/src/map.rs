@@ -0,0 +1,132 @@
+use std::ops::{Add, Range, Sub};
+
+use crate::IntervalSet;
+
+/// A mapping of integers that shifts each of a few ranges by its own offset
+/// and leaves every other value where it is, like the maps in an almanac.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RangeMap<T> {
+    /// Each range of sources, with where its start goes, sorted by source.
+    pieces: Vec<(Range<T>, T)>,
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> RangeMap<T> {
+    pub fn new() -> Self {
+        Self { pieces: vec![] }
+    }
+
+    /// Maps `source` to the range of the same length starting at
+    /// `destination`.
+    ///
+    /// Panics if `source` overlaps a range that's already mapped.
+    pub fn insert(&mut self, source: Range<T>, destination: T) {
+        if source.is_empty() {
+            return;
+        }
+        assert!(
+            !self.overlaps(&source),
+            "A range can only be mapped one way"
+        );
+        let i = self
+            .pieces
+            .partition_point(|(piece, _)| piece.end <= source.start);
+        self.pieces.insert(i, (source, destination));
+    }
+
+    /// Whether any value in `source` is already mapped.
+    pub fn overlaps(&self, source: &Range<T>) -> bool {
+        if source.is_empty() {
+            return false;
+        }
+        let i = self
+            .pieces
+            .partition_point(|(piece, _)| piece.end <= source.start);
+        self.pieces
+            .get(i)
+            .is_some_and(|(piece, _)| piece.start < source.end)
+    }
+
+    /// Where `value` goes.
+    pub fn get(&self, value: T) -> T {
+        let i = self.pieces.partition_point(|(piece, _)| piece.end <= value);
+        match self.pieces.get(i) {
+            Some((piece, destination)) if piece.start <= value => {
+                *destination + (value - piece.start)
+            }
+            _ => value,
+        }
+    }
+
+    /// Where every value in `set` goes, a range at a time rather than a
+    /// value at a time.
+    pub fn image(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut image = vec![];
+        for range in set.ranges() {
+            let first = self
+                .pieces
+                .partition_point(|(piece, _)| piece.end <= range.start);
+            let mut start = range.start;
+            for (piece, destination) in self.pieces[first..]
+                .iter()
+                .take_while(|(piece, _)| piece.start < range.end)
+            {
+                if start < piece.start {
+                    image.push(start..piece.start);
+                    start = piece.start;
+                }
+                let end = range.end.min(piece.end);
+                let shift = |value| *destination + (value - piece.start);
+                image.push(shift(start)..shift(end));
+                start = end;
+            }
+            if start < range.end {
+                image.push(start..range.end);
+            }
+        }
+        image.into_iter().collect()
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> Default for RangeMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> FromIterator<(Range<T>, T)>
+    for RangeMap<T>
+{
+    fn from_iter<I: IntoIterator<Item = (Range<T>, T)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (source, destination) in iter {
+            map.insert(source, destination);
+        }
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image() {
+        // The almanac's seed-to-soil map.
+        let map: RangeMap<u64> = [(98..100, 50), (50..98, 52)].into_iter().collect();
+        assert_eq!(
+            [79, 14, 55, 13, 99].map(|seed| map.get(seed)),
+            [81, 14, 57, 13, 51]
+        );
+        assert!(map.overlaps(&(97..99)) && map.overlaps(&(40..51)));
+        assert!(!map.overlaps(&(100..120)) && !map.overlaps(&(40..50)));
+        let seeds: IntervalSet<u64> = [79..93, 40..52, 97..105].into_iter().collect();
+        assert_eq!(map.image(&seeds).ranges(), [40..54, 81..95, 99..105]);
+        // Every value goes where `get` sends it.
+        for seed in 0..120 {
+            assert_eq!(
+                map.image(&IntervalSet::from(seed..seed + 1)).min(),
+                Some(map.get(seed))
+            );
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Number theory over `i128`, so that the products and least common
+//! multiples of `i64`-sized moduli can't overflow.
+
+/// Results of the Extended Euclidean algorithm.
+///
+/// Contains the greatest common divisor of `a` and `b`, along with Bézout
+/// coefficients such that
+///
+/// ```text
+/// a * bezout_a + b * bezout_b == gcd
+/// ```
+///
+/// <https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ExtendedEuclidean {
+    /// Never negative, and only 0 if `a` and `b` both are.
+    pub gcd: i128,
+    pub bezout_a: i128,
+    pub bezout_b: i128,
+}
+
+impl ExtendedEuclidean {
+    pub fn new(a: i128, b: i128) -> Self {
+        let (mut old_r, mut r) = (a, b);
+        let (mut old_s, mut s) = (1, 0);
+        let (mut old_t, mut t) = (0, 1);
+
+        while r != 0 {
+            let quotient = old_r / r;
+            (old_r, r) = (r, old_r - quotient * r);
+            (old_s, s) = (s, old_s - quotient * s);
+            (old_t, t) = (t, old_t - quotient * t);
+        }
+        if old_r < 0 {
+            (old_r, old_s, old_t) = (-old_r, -old_s, -old_t);
+        }
+
+        debug_assert_eq!(a * old_s + b * old_t, old_r);
+        Self {
+            gcd: old_r,
+            bezout_a: old_s,
+            bezout_b: old_t,
+        }
+    }
+}
+
+/// The greatest common divisor, never negative.
+pub fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, never negative. Divides before multiplying,
+/// so it only overflows if the answer does.
+pub fn lcm(a: i128, b: i128) -> i128 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    (a / gcd(a, b) * b).abs()
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`, or `None` if `a`
+/// and `modulus` share a factor.
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    let ee = ExtendedEuclidean::new(a.rem_euclid(modulus), modulus);
+    (ee.gcd == 1).then(|| ee.bezout_a.rem_euclid(modulus))
+}
+
+/// A set of numbers, all those `≡ residue (mod modulus)`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Congruence {
+    /// Always in `0..modulus`.
+    pub residue: i128,
+    pub modulus: i128,
+}
+
+impl Congruence {
+    /// Panics unless `modulus` is positive.
+    pub fn new(residue: i128, modulus: i128) -> Self {
+        assert!(modulus > 0, "A modulus must be positive, not {modulus}");
+        Self {
+            residue: residue.rem_euclid(modulus),
+            modulus,
+        }
+    }
+
+    /// The numbers in both sets, or `None` if there are none. The moduli
+    /// don't need to be coprime: `x ≡ 2 (mod 4)` and `x ≡ 4 (mod 6)` meet in
+    /// `x ≡ 10 (mod 12)`, while `x ≡ 1 (mod 4)` and `x ≡ 2 (mod 6)` never do.
+    ///
+    /// Safe for moduli up to `i64::MAX`.
+    pub fn meet(self, other: Self) -> Option<Self> {
+        let g = gcd(self.modulus, other.modulus);
+        let diff = other.residue - self.residue;
+        if diff % g != 0 {
+            return None;
+        }
+        // Solve self.residue + self.modulus * k ≡ other.residue
+        // (mod other.modulus) for k, after dividing through by g.
+        let step = other.modulus / g;
+        let inverse = mod_inverse(self.modulus / g, step)?;
+        let k = (diff / g).rem_euclid(step) * inverse % step;
+        Some(Self::new(self.residue + self.modulus * k, self.modulus * step))
+    }
+
+    /// The smallest member that's at least `min`.
+    pub fn first_from(&self, min: i128) -> i128 {
+        min + (self.residue - min).rem_euclid(self.modulus)
+    }
+}
+
+/// The Chinese Remainder Theorem: the numbers in every one of the sets, or
+/// `None` if there are none. With no sets at all, every number is in them.
+pub fn crt(congruences: impl IntoIterator<Item = Congruence>) -> Option<Congruence> {
+    congruences
+        .into_iter()
+        .try_fold(Congruence::new(0, 1), Congruence::meet)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, -18), 6);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        // The product would overflow an i128, but the multiple doesn't.
+        let big = i64::MAX as i128;
+        assert_eq!(lcm(big * 2, big * 3), big * 6);
+
+        let ee = ExtendedEuclidean::new(240, -46);
+        assert_eq!(ee.gcd, 2);
+        assert_eq!(240 * ee.bezout_a - 46 * ee.bezout_b, 2);
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(5, 1), Some(0));
+    }
+
+    #[test]
+    fn test_crt() {
+        let c = Congruence::new;
+        assert_eq!(crt([c(2, 3), c(3, 5), c(2, 7)]), Some(c(23, 105)));
+        assert_eq!(c(2, 4).meet(c(4, 6)), Some(c(10, 12)));
+        assert_eq!(c(1, 4).meet(c(2, 6)), None);
+        assert_eq!(crt([]), Some(c(0, 1)));
+        assert_eq!(c(10, 12).first_from(25), 34);
+        assert_eq!(c(10, 12).first_from(-5), -2);
+
+        // Moduli that are large and share a factor.
+        let p = 1_000_000_007;
+        let q = 998_244_353;
+        let solved = c(5, p * q).meet(c(5 + p, p * 7)).unwrap();
+        assert_eq!(solved.modulus, p * q * 7);
+        assert_eq!(solved.residue % (p * q), 5);
+        assert_eq!(solved.residue % (p * 7), 5 + p);
+    }
+}
@@ -0,0 +1,506 @@
+use std::{fmt, str::FromStr};
+
+/// Malformed puzzle input, and where in the input it went wrong. Lines and
+/// columns count from 1.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Error {
+    pub line: usize,
+    pub col: usize,
+    pub msg: String,
+}
+
+impl Error {
+    pub fn new(line: usize, col: usize, msg: impl Into<String>) -> Self {
+        Self {
+            line,
+            col,
+            msg: msg.into(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.col, self.msg)
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Reads one line of input from left to right. Each method either consumes
+/// what it expects or fails without moving, pointing at what it found
+/// instead.
+#[derive(Clone, Copy, Debug)]
+pub struct Cursor<'a> {
+    line: &'a str,
+    pos: usize,
+    line_no: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(line: &'a str, line_no: usize) -> Self {
+        Self {
+            line,
+            pos: 0,
+            line_no,
+        }
+    }
+
+    pub fn line_no(&self) -> usize {
+        self.line_no
+    }
+
+    /// An error pointing at the cursor's position.
+    pub fn error(&self, msg: impl Into<String>) -> Error {
+        let col = self.line[..self.pos].chars().count() + 1;
+        Error::new(self.line_no, col, msg)
+    }
+
+    /// Everything the cursor hasn't consumed yet.
+    pub fn rest(&self) -> &'a str {
+        &self.line[self.pos..]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    /// Consumes and returns the rest of the line.
+    pub fn take_rest(&mut self) -> &'a str {
+        let rest = self.rest();
+        self.pos = self.line.len();
+        rest
+    }
+
+    fn found(&self) -> String {
+        match self.rest().split_whitespace().next() {
+            Some(word) => format!("`{word}`"),
+            None => "the end of the line".to_owned(),
+        }
+    }
+
+    pub fn skip_spaces(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Consumes `lit` if it comes next.
+    pub fn eat(&mut self, lit: &str) -> bool {
+        if self.rest().starts_with(lit) {
+            self.pos += lit.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn literal(&mut self, lit: &str) -> Result<()> {
+        if self.eat(lit) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected `{lit}`, found {}", self.found())))
+        }
+    }
+
+    /// Consumes `key:` and any spaces after it, ready to read the value.
+    pub fn field(&mut self, key: &str) -> Result<()> {
+        let mut cursor = *self;
+        cursor.skip_spaces();
+        if cursor.eat(key) && cursor.eat(":") {
+            cursor.skip_spaces();
+            *self = cursor;
+            Ok(())
+        } else {
+            cursor = *self;
+            cursor.skip_spaces();
+            Err(cursor.error(format!("expected `{key}:`, found {}", cursor.found())))
+        }
+    }
+
+    /// Reads an integer with an optional sign.
+    pub fn int<T: FromStr>(&mut self) -> Result<T> {
+        let rest = self.rest();
+        let sign = usize::from(rest.starts_with(['-', '+']));
+        let digits = rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - sign);
+        if digits == 0 {
+            return Err(self.error(format!("expected a number, found {}", self.found())));
+        }
+        let value = rest[..sign + digits]
+            .parse()
+            .map_err(|_| self.error(format!("`{}` is out of range", &rest[..sign + digits])))?;
+        self.pos += sign + digits;
+        Ok(value)
+    }
+
+    /// Reads a run of letters, digits, and underscores.
+    pub fn word(&mut self) -> Result<&'a str> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error(format!("expected a word, found {}", self.found())));
+        }
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    /// Reads the next character, whatever it is.
+    pub fn char(&mut self) -> Result<char> {
+        match self.rest().chars().next() {
+            Some(c) => {
+                self.pos += c.len_utf8();
+                Ok(c)
+            }
+            None => Err(self.error("expected a character, found the end of the line")),
+        }
+    }
+
+    /// Reads a single decimal digit.
+    pub fn digit(&mut self) -> Result<u8> {
+        match self.rest().bytes().next() {
+            Some(b @ b'0'..=b'9') => {
+                self.pos += 1;
+                Ok(b - b'0')
+            }
+            _ => Err(self.error(format!("expected a digit, found {}", self.found()))),
+        }
+    }
+
+    /// Reads everything up to the next `delim`, then skips past the `delim`.
+    pub fn until(&mut self, delim: &str) -> Result<&'a str> {
+        let rest = self.rest();
+        match rest.find(delim) {
+            Some(len) => {
+                self.pos += len + delim.len();
+                Ok(&rest[..len])
+            }
+            None => Err(self.error(format!("expected `{delim}` later in the line"))),
+        }
+    }
+
+    /// Reads whichever of `options` comes next, returning its value.
+    pub fn one_of<T: Copy>(&mut self, options: &[(&str, T)]) -> Result<T> {
+        for &(lit, value) in options {
+            if self.eat(lit) {
+                return Ok(value);
+            }
+        }
+        let expected: Vec<String> = options.iter().map(|(lit, _)| format!("`{lit}`")).collect();
+        Err(self.error(format!(
+            "expected one of {}, found {}",
+            expected.join(", "),
+            self.found()
+        )))
+    }
+
+    /// Reads one or more `item`s separated by `sep`, ignoring spaces around
+    /// each separator. A separator of `" "` means any run of whitespace.
+    pub fn list<T>(
+        &mut self,
+        sep: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut cursor = *self;
+        let mut items = vec![item(&mut cursor)?];
+        loop {
+            let before = cursor;
+            cursor.skip_spaces();
+            let separated = if sep.trim().is_empty() {
+                cursor.pos > before.pos
+            } else {
+                cursor.eat(sep.trim())
+            };
+            if !separated || cursor.rest().trim().is_empty() {
+                *self = before;
+                return Ok(items);
+            }
+            cursor.skip_spaces();
+            items.push(item(&mut cursor)?);
+        }
+    }
+
+    /// Reads one or more integers separated by `sep`.
+    pub fn ints<T: FromStr>(&mut self, sep: &str) -> Result<Vec<T>> {
+        self.list(sep, Self::int)
+    }
+
+    /// Fails unless only whitespace is left.
+    pub fn end(&self) -> Result<()> {
+        let mut rest = *self;
+        rest.skip_spaces();
+        if rest.is_empty() {
+            Ok(())
+        } else {
+            Err(rest.error(format!("unexpected {}", rest.found())))
+        }
+    }
+}
+
+/// Every line of the input, numbered.
+pub fn lines(input: &str) -> impl Iterator<Item = Cursor<'_>> {
+    whole(input).lines()
+}
+
+/// Reads each line of the input with `f`, which must consume the whole line.
+pub fn parse_lines<T>(input: &str, f: impl FnMut(&mut Cursor) -> Result<T>) -> Result<Vec<T>> {
+    whole(input).parse_lines(f)
+}
+
+/// Reads each line of the input into a row of cells, calling `cell` until
+/// the line runs out. Every row must be as wide as the first.
+pub fn parse_rows<T>(
+    input: &str,
+    cell: impl FnMut(&mut Cursor) -> Result<T>,
+) -> Result<Vec<Vec<T>>> {
+    whole(input).parse_rows(cell)
+}
+
+/// The input's only line, for puzzles that fit on one.
+pub fn single_line(input: &str) -> Result<Cursor<'_>> {
+    let [section] = split_sections(input)?;
+    let [line] = section.split_lines()?;
+    Ok(line)
+}
+
+/// Treats the whole input as one section.
+fn whole(input: &str) -> Section<'_> {
+    Section {
+        text: input,
+        first_line: 1,
+    }
+}
+
+/// A block of input lines, separated from its neighbors by blank lines.
+#[derive(Clone, Copy, Debug)]
+pub struct Section<'a> {
+    text: &'a str,
+    first_line: usize,
+}
+
+impl<'a> Section<'a> {
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn first_line(&self) -> usize {
+        self.first_line
+    }
+
+    /// An error pointing at the start of the section.
+    pub fn error(&self, msg: impl Into<String>) -> Error {
+        Error::new(self.first_line, 1, msg)
+    }
+
+    /// An error pointing just past the end of the section, for when it stops
+    /// short.
+    pub fn error_at_end(&self, msg: impl Into<String>) -> Error {
+        Error::new(self.first_line + self.text.lines().count(), 1, msg)
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = Cursor<'a>> {
+        let first_line = self.first_line;
+        self.text
+            .lines()
+            .enumerate()
+            .map(move |(i, line)| Cursor::new(line, first_line + i))
+    }
+
+    /// The section's lines, failing unless there are exactly `N` of them.
+    pub fn split_lines<const N: usize>(&self) -> Result<[Cursor<'a>; N]> {
+        let lines: Vec<Cursor> = self.lines().collect();
+        if let Some(extra) = lines.get(N) {
+            return Err(extra.error(format!("expected only {N} lines")));
+        }
+        let found = lines.len();
+        lines
+            .try_into()
+            .map_err(|_| self.error_at_end(format!("expected {N} lines, found {found}")))
+    }
+
+    /// Reads each line of the section with `f`, which must consume the whole
+    /// line.
+    pub fn parse_lines<T>(&self, mut f: impl FnMut(&mut Cursor) -> Result<T>) -> Result<Vec<T>> {
+        self.lines()
+            .map(|mut cursor| {
+                let value = f(&mut cursor)?;
+                cursor.end()?;
+                Ok(value)
+            })
+            .collect()
+    }
+
+    /// Reads each line into a row of cells, calling `cell` until the line
+    /// runs out. Every row must be as wide as the first.
+    pub fn parse_rows<T>(
+        &self,
+        mut cell: impl FnMut(&mut Cursor) -> Result<T>,
+    ) -> Result<Vec<Vec<T>>> {
+        let mut rows: Vec<Vec<T>> = vec![];
+        for mut line in self.lines() {
+            let start = line;
+            let mut row = vec![];
+            while !line.is_empty() {
+                row.push(cell(&mut line)?);
+            }
+            match rows.first() {
+                None if row.is_empty() => return Err(start.error("expected a grid row")),
+                Some(first) if row.len() != first.len() => {
+                    return Err(start.error(format!(
+                        "expected a row of {} cells, found {}",
+                        first.len(),
+                        row.len()
+                    )))
+                }
+                _ => rows.push(row),
+            }
+        }
+        if rows.is_empty() {
+            return Err(self.error("expected a grid"));
+        }
+        Ok(rows)
+    }
+}
+
+/// Splits the input on blank lines.
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    let mut sections = vec![];
+    let mut start: Option<(usize, usize)> = None;
+    let mut offset = 0;
+    for (i, line) in input.split_inclusive('\n').enumerate() {
+        if line.trim().is_empty() {
+            if let Some((start, first_line)) = start.take() {
+                sections.push(Section {
+                    text: &input[start..offset],
+                    first_line,
+                });
+            }
+        } else if start.is_none() {
+            start = Some((offset, i + 1));
+        }
+        offset += line.len();
+    }
+    if let Some((start, first_line)) = start {
+        sections.push(Section {
+            text: &input[start..],
+            first_line,
+        });
+    }
+    sections
+}
+
+/// Splits the input on blank lines, failing unless there are exactly `N`
+/// sections.
+pub fn split_sections<const N: usize>(input: &str) -> Result<[Section<'_>; N]> {
+    let sections = sections(input);
+    let found = sections.len();
+    sections.try_into().map_err(|_| {
+        let line = input.lines().count().max(1);
+        Error::new(
+            line,
+            1,
+            format!("expected {N} blank-line-separated sections, found {found}"),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor() {
+        let mut cursor = Cursor::new("Valve AA has flow rate=-13; tunnels lead to DD, II", 1);
+        cursor.literal("Valve ").unwrap();
+        assert_eq!(cursor.word(), Ok("AA"));
+        cursor.literal(" has flow rate=").unwrap();
+        assert_eq!(cursor.int::<i64>(), Ok(-13));
+        assert_eq!(cursor.until(" to "), Ok("; tunnels lead"));
+        let tunnels = cursor.list(",", Cursor::word).unwrap();
+        assert_eq!(tunnels, ["DD", "II"]);
+        cursor.end().unwrap();
+
+        let mut cursor = Cursor::new("é#", 1);
+        assert_eq!(cursor.char(), Ok('é'));
+        assert_eq!(cursor.char(), Ok('#'));
+        assert_eq!(cursor.char().unwrap_err().col, 3);
+
+        let mut cursor = Cursor::new("7x", 1);
+        assert_eq!(cursor.digit(), Ok(7));
+        assert_eq!(
+            cursor.digit().unwrap_err().msg,
+            "expected a digit, found `x`"
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut cursor = Cursor::new("Register A: x", 4);
+        cursor.field("Register A").unwrap();
+        let err = cursor.int::<u64>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 13: expected a number, found `x`"
+        );
+
+        let mut cursor = Cursor::new("1,2,3 oops", 2);
+        assert_eq!(cursor.ints(","), Ok(vec![1, 2, 3]));
+        let err = cursor.end().unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 7: unexpected `oops`");
+
+        let err = Cursor::new("300", 1).int::<u8>().unwrap_err();
+        assert_eq!(err.msg, "`300` is out of range");
+    }
+
+    #[test]
+    fn test_lists() {
+        assert_eq!(
+            Cursor::new("79 14  55 13", 1).ints(" "),
+            Ok(vec![79, 14, 55, 13])
+        );
+        assert_eq!(Cursor::new("4, 5,6", 1).ints(","), Ok(vec![4, 5, 6]));
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "seeds: 1 2\n\nmap:\n1 2 3\n\n\n#.\n.#\n";
+        let sections = sections(input);
+        assert_eq!(sections.len(), 3);
+        assert_eq!(sections[1].text(), "map:\n1 2 3\n");
+        assert_eq!(sections[1].first_line(), 3);
+        assert_eq!(sections[2].first_line(), 7);
+        let [mut line] = sections[0].split_lines().unwrap();
+        line.field("seeds").unwrap();
+        assert_eq!(line.ints(" "), Ok(vec![1, 2]));
+
+        let err = sections[1].split_lines::<3>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 5, column 1: expected 3 lines, found 2"
+        );
+
+        let err = split_sections::<2>(input).unwrap_err();
+        assert_eq!(err.msg, "expected 2 blank-line-separated sections, found 3");
+
+        assert_eq!(single_line("3,4,3,1,2\n").unwrap().rest(), "3,4,3,1,2");
+        assert_eq!(single_line("3,4\n3,1\n").unwrap_err().line, 2);
+
+        assert_eq!(
+            parse_rows("12\n34\n", |cell| cell.digit()),
+            Ok(vec![vec![1, 2], vec![3, 4]])
+        );
+        let err = parse_rows("12\n345\n", |cell| cell.digit()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected a row of 2 cells, found 3"
+        );
+        assert_eq!(
+            parse_rows("", |cell| cell.digit()).unwrap_err().msg,
+            "expected a grid"
+        );
+    }
+}
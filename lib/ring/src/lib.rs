@@ -0,0 +1,299 @@
+/// Stands in for a missing child or parent.
+const NIL: usize = usize::MAX;
+
+/// A sequence that wraps around, where any element can be moved any number
+/// of places along in `O(log n)`.
+///
+/// It's an implicit treap: a binary tree kept in sequence order, balanced by
+/// a random priority on each node, where each node knows the size of its
+/// subtree instead of a key. Elements are never copied or reallocated, so
+/// the index an element was added at is a handle to it for as long as the
+/// ring lives, however it gets shuffled.
+#[derive(Clone, Debug)]
+pub struct Ring<T> {
+    values: Vec<T>,
+    left: Vec<usize>,
+    right: Vec<usize>,
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    priority: Vec<u64>,
+    root: usize,
+    seed: u64,
+}
+
+impl<T> Ring<T> {
+    pub fn new() -> Self {
+        Self {
+            values: vec![],
+            left: vec![],
+            right: vec![],
+            parent: vec![],
+            size: vec![],
+            priority: vec![],
+            root: NIL,
+            // Any nonzero seed will do, and a fixed one keeps runs repeatable.
+            seed: 0x9e37_79b9_7f4a_7c15,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Adds `value` at the back, and returns its handle.
+    pub fn push(&mut self, value: T) -> usize {
+        // xorshift64
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+        let node = self.values.len();
+        self.values.push(value);
+        self.left.push(NIL);
+        self.right.push(NIL);
+        self.parent.push(NIL);
+        self.size.push(1);
+        self.priority.push(self.seed);
+        self.root = self.merge(self.root, node);
+        self.parent[self.root] = NIL;
+        node
+    }
+
+    /// The element with handle `handle`.
+    pub fn get(&self, handle: usize) -> &T {
+        &self.values[handle]
+    }
+
+    /// How far from the front the element with handle `handle` is.
+    pub fn position(&self, handle: usize) -> usize {
+        let mut node = handle;
+        let mut position = self.size_of(self.left[node]);
+        while self.parent[node] != NIL {
+            let parent = self.parent[node];
+            if self.right[parent] == node {
+                position += self.size_of(self.left[parent]) + 1;
+            }
+            node = parent;
+        }
+        position
+    }
+
+    /// The handle of the element `position` places from the front, wrapping
+    /// around past the back.
+    ///
+    /// Panics if the ring is empty.
+    pub fn handle_at(&self, position: usize) -> usize {
+        assert!(!self.is_empty(), "An empty ring has no elements");
+        let mut position = position % self.len();
+        let mut node = self.root;
+        loop {
+            let before = self.size_of(self.left[node]);
+            match position.cmp(&before) {
+                std::cmp::Ordering::Less => node = self.left[node],
+                std::cmp::Ordering::Equal => return node,
+                std::cmp::Ordering::Greater => {
+                    position -= before + 1;
+                    node = self.right[node];
+                }
+            }
+        }
+    }
+
+    /// The element `position` places from the front, wrapping around past
+    /// the back.
+    pub fn at(&self, position: usize) -> &T {
+        self.get(self.handle_at(position))
+    }
+
+    /// Takes the element with handle `handle` out and puts it back `places`
+    /// further along, or back if `places` is negative. Going once round
+    /// passes the other `len - 1` elements, and since the ring has no ends,
+    /// which element ends up at the front doesn't matter.
+    pub fn move_by(&mut self, handle: usize, places: i64) {
+        let others = self.len() as i64 - 1;
+        if others <= 0 {
+            return;
+        }
+        let from = self.position(handle);
+        let (before, rest) = self.split(self.root, from);
+        let (node, after) = self.split(rest, 1);
+        debug_assert_eq!(node, handle);
+        let rest = self.merge(before, after);
+        let to = (from as i64 + places).rem_euclid(others) as usize;
+        let (before, after) = self.split(rest, to);
+        let before = self.merge(before, node);
+        self.root = self.merge(before, after);
+        self.parent[self.root] = NIL;
+    }
+
+    /// The handles from front to back.
+    pub fn handles(&self) -> Vec<usize> {
+        let mut handles = Vec::with_capacity(self.len());
+        let mut stack = vec![];
+        let mut node = self.root;
+        while node != NIL || !stack.is_empty() {
+            while node != NIL {
+                stack.push(node);
+                node = self.left[node];
+            }
+            node = stack.pop().unwrap();
+            handles.push(node);
+            node = self.right[node];
+        }
+        handles
+    }
+
+    /// The elements from front to back.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.handles().into_iter().map(|handle| &self.values[handle])
+    }
+
+    fn size_of(&self, node: usize) -> usize {
+        if node == NIL {
+            0
+        } else {
+            self.size[node]
+        }
+    }
+
+    fn set_left(&mut self, node: usize, child: usize) {
+        self.left[node] = child;
+        if child != NIL {
+            self.parent[child] = node;
+        }
+        self.size[node] = self.size_of(child) + self.size_of(self.right[node]) + 1;
+    }
+
+    fn set_right(&mut self, node: usize, child: usize) {
+        self.right[node] = child;
+        if child != NIL {
+            self.parent[child] = node;
+        }
+        self.size[node] = self.size_of(self.left[node]) + self.size_of(child) + 1;
+    }
+
+    /// Splits the tree under `node` into its first `count` elements and the
+    /// rest. Either half's root may still point at a stale parent.
+    fn split(&mut self, node: usize, count: usize) -> (usize, usize) {
+        if node == NIL {
+            return (NIL, NIL);
+        }
+        let before = self.size_of(self.left[node]);
+        if count <= before {
+            let (first, rest) = self.split(self.left[node], count);
+            self.set_left(node, rest);
+            (first, node)
+        } else {
+            let (first, rest) = self.split(self.right[node], count - before - 1);
+            self.set_right(node, first);
+            (node, rest)
+        }
+    }
+
+    /// Joins two trees, all of `a` coming before all of `b`. The root may
+    /// still point at a stale parent.
+    fn merge(&mut self, a: usize, b: usize) -> usize {
+        if a == NIL {
+            return b;
+        }
+        if b == NIL {
+            return a;
+        }
+        if self.priority[a] > self.priority[b] {
+            let right = self.merge(self.right[a], b);
+            self.set_right(a, right);
+            a
+        } else {
+            let left = self.merge(a, self.left[b]);
+            self.set_left(b, left);
+            b
+        }
+    }
+}
+
+impl<T> Default for Ring<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for Ring<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut ring = Self::new();
+        for value in iter {
+            ring.push(value);
+        }
+        ring
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order() {
+        let ring: Ring<char> = "abcdefg".chars().collect();
+        assert_eq!(ring.iter().collect::<String>(), "abcdefg");
+        assert_eq!(ring.handles(), [0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(ring.position(4), 4);
+        assert_eq!(*ring.at(9), 'c');
+        assert_eq!(*ring.get(6), 'g');
+    }
+
+    #[test]
+    fn test_move_by() {
+        let mut ring: Ring<char> = "abcde".chars().collect();
+        ring.move_by(0, 2);
+        assert_eq!(ring.iter().collect::<String>(), "bcade");
+        ring.move_by(0, -1);
+        assert_eq!(ring.iter().collect::<String>(), "bacde");
+        // Going round once passes the other four and lands back in place.
+        ring.move_by(3, 4);
+        assert_eq!(ring.iter().collect::<String>(), "bacde");
+        ring.move_by(4, 1);
+        assert_eq!(ring.iter().collect::<String>(), "beacd");
+        assert_eq!(ring.position(0), 2);
+        assert_eq!(*ring.get(0), 'a');
+    }
+
+    #[test]
+    fn test_against_vec() {
+        let mut seed = 12345u64;
+        let mut random = || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+        let mut ring: Ring<usize> = (0..50).collect();
+        let mut list: Vec<usize> = (0..50).collect();
+        for _ in 0..2000 {
+            let handle = random() as usize % 50;
+            let places = random() as i64 % 1000;
+            ring.move_by(handle, places);
+
+            let from = list.iter().position(|&v| v == handle).unwrap();
+            list.remove(from);
+            let to = (from as i64 + places).rem_euclid(49) as usize;
+            list.insert(to, handle);
+
+            assert_eq!(ring.position(handle), to);
+        }
+        assert_eq!(ring.iter().copied().collect::<Vec<_>>(), list);
+        assert_eq!(ring.handles(), list);
+    }
+
+    #[test]
+    fn test_small() {
+        let mut ring = Ring::new();
+        assert!(ring.is_empty());
+        let only = ring.push(7);
+        ring.move_by(only, 3);
+        assert_eq!(ring.position(only), 0);
+        assert_eq!(*ring.at(5), 7);
+    }
+}
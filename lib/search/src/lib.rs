@@ -0,0 +1,340 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Breadth-first search from any of `starts` to the first node satisfying
+/// `is_goal`. Every edge costs the same, so the returned path (which begins
+/// at a start and ends at the goal) is as short as possible.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::new();
+    let mut frontier = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = parents.entry(start.clone()) {
+            entry.insert(None);
+            frontier.push_back(start);
+        }
+    }
+
+    while let Some(node) = frontier.pop_front() {
+        if is_goal(&node) {
+            let mut path = vec![node];
+            while let Some(Some(parent)) = parents.get(path.last().unwrap()) {
+                path.push(parent.clone());
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for next in successors(&node) {
+            if let Entry::Vacant(entry) = parents.entry(next.clone()) {
+                entry.insert(Some(node.clone()));
+                frontier.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Dijkstra's algorithm from any of `starts` to the first node satisfying
+/// `is_goal`. Returns the path, including both ends, and its total cost.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Default + Ord + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// A* search. `heuristic` must never overestimate the remaining cost to a
+/// goal, or the returned path may not be the cheapest one.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Default + Ord + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (explored, goals, cost) = search(starts, successors, heuristic, is_goal, false)?;
+    let mut path = vec![goals[0]];
+    while let Some(&parent) = explored.parents[*path.last().unwrap()].first() {
+        path.push(parent);
+    }
+    let path = path
+        .into_iter()
+        .rev()
+        .map(|i| explored.nodes[i].clone())
+        .collect();
+    Some((path, cost))
+}
+
+/// Like [`dijkstra`], but keeps every path that ties for the lowest cost.
+pub fn dijkstra_all<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(AllPaths<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Default + Ord + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar_all(starts, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`astar`], but keeps every path that ties for the lowest cost. The
+/// heuristic must also be consistent, so that no node is finished before
+/// all of its cheapest parents have been seen.
+pub fn astar_all<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(AllPaths<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Default + Ord + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (explored, goals, cost) = search(starts, successors, heuristic, is_goal, true)?;
+    let paths = AllPaths {
+        nodes: explored.nodes,
+        parents: explored.parents,
+        goals,
+    };
+    Some((paths, cost))
+}
+
+/// Every cheapest path found by [`dijkstra_all`] or [`astar_all`], stored as
+/// a DAG of parent links leading back from the goals.
+pub struct AllPaths<N> {
+    nodes: Vec<N>,
+    parents: Vec<Vec<usize>>,
+    goals: Vec<usize>,
+}
+
+impl<N: Clone + Eq + Hash> AllPaths<N> {
+    /// The goals reached at the lowest cost.
+    pub fn goals(&self) -> impl Iterator<Item = &N> {
+        self.goals.iter().map(|&i| &self.nodes[i])
+    }
+
+    /// Every node that lies on at least one cheapest path.
+    pub fn nodes(&self) -> HashSet<N> {
+        let mut seen = HashSet::new();
+        let mut stack = self.goals.clone();
+        while let Some(i) = stack.pop() {
+            if seen.insert(i) {
+                stack.extend(&self.parents[i]);
+            }
+        }
+        seen.into_iter().map(|i| self.nodes[i].clone()).collect()
+    }
+
+    /// Spells out each cheapest path from start to goal. There can be
+    /// exponentially many of these, so prefer [`AllPaths::nodes`] when
+    /// that's enough.
+    pub fn paths(&self) -> Vec<Vec<N>> {
+        let mut paths = vec![];
+        let mut stack: Vec<Vec<usize>> = self.goals.iter().map(|&goal| vec![goal]).collect();
+        while let Some(path) = stack.pop() {
+            let parents = &self.parents[*path.last().unwrap()];
+            if parents.is_empty() {
+                paths.push(path.iter().rev().map(|&i| self.nodes[i].clone()).collect());
+            }
+            for &parent in parents {
+                let mut path = path.clone();
+                path.push(parent);
+                stack.push(path);
+            }
+        }
+        paths
+    }
+}
+
+/// Every node reached so far, with its cheapest known cost and the parents
+/// that reach it at that cost.
+struct Explored<N, C> {
+    indices: HashMap<N, usize>,
+    nodes: Vec<N>,
+    costs: Vec<C>,
+    parents: Vec<Vec<usize>>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy + Ord> Explored<N, C> {
+    /// Records reaching `node` at `cost`. Returns the node's index if that's
+    /// the cheapest way there yet, meaning the node needs to be expanded.
+    fn visit(&mut self, node: N, cost: C, parent: Option<usize>) -> Option<usize> {
+        match self.indices.entry(node) {
+            Entry::Vacant(entry) => {
+                let i = self.nodes.len();
+                self.nodes.push(entry.key().clone());
+                self.costs.push(cost);
+                self.parents.push(parent.into_iter().collect());
+                entry.insert(i);
+                Some(i)
+            }
+            Entry::Occupied(entry) => {
+                let i = *entry.get();
+                if cost < self.costs[i] {
+                    self.costs[i] = cost;
+                    self.parents[i] = parent.into_iter().collect();
+                    Some(i)
+                } else {
+                    if cost == self.costs[i] {
+                        self.parents[i].extend(parent);
+                    }
+                    None
+                }
+            }
+        }
+    }
+}
+
+/// Returns the explored nodes, the indices of the goals reached, and what
+/// they cost. Unless `all` is set, stops at the first goal.
+fn search<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+    all: bool,
+) -> Option<(Explored<N, C>, Vec<usize>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Default + Ord + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut explored = Explored {
+        indices: HashMap::new(),
+        nodes: vec![],
+        costs: vec![],
+        parents: vec![],
+    };
+    let mut frontier = BinaryHeap::new();
+    for start in starts {
+        if let Some(i) = explored.visit(start, C::default(), None) {
+            let estimate = heuristic(&explored.nodes[i]);
+            frontier.push(Reverse((estimate, C::default(), i)));
+        }
+    }
+
+    let mut goals = vec![];
+    let mut best = None;
+    while let Some(Reverse((estimate, cost, i))) = frontier.pop() {
+        if best.is_some_and(|best| estimate > best) {
+            break;
+        }
+        // Each improvement to a node's cost pushes a new entry, so skip the
+        // ones that have been superseded.
+        if cost > explored.costs[i] {
+            continue;
+        }
+        if is_goal(&explored.nodes[i]) {
+            goals.push(i);
+            best = Some(cost);
+            if !all {
+                break;
+            }
+            continue;
+        }
+        for (next, step) in successors(&explored.nodes[i]) {
+            let next_cost = cost + step;
+            if let Some(j) = explored.visit(next, next_cost, Some(i)) {
+                let estimate = next_cost + heuristic(&explored.nodes[j]);
+                frontier.push(Reverse((estimate, next_cost, j)));
+            }
+        }
+    }
+    best.map(|cost| (explored, goals, cost))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 4x4 room with a wall down the middle, open only at the bottom.
+    const ROOM: [&[u8]; 4] = [b"..#.", b"..#.", b"..#.", b"...."];
+
+    fn neighbors((x, y): (usize, usize)) -> Vec<(usize, usize)> {
+        let mut neighbors = vec![];
+        for (dx, dy) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+            let (Some(x), Some(y)) = (x.checked_add_signed(dx), y.checked_add_signed(dy)) else {
+                continue;
+            };
+            if y < ROOM.len() && x < ROOM[y].len() && ROOM[y][x] != b'#' {
+                neighbors.push((x, y));
+            }
+        }
+        neighbors
+    }
+
+    #[test]
+    fn test_bfs() {
+        let path = bfs([(0, 0)], |&pos| neighbors(pos), |&pos| pos == (3, 0)).unwrap();
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(3, 0)));
+        assert_eq!(path.len(), 10);
+    }
+
+    #[test]
+    fn test_multiple_starts() {
+        let path = bfs(
+            [(0, 0), (3, 3)],
+            |&pos| neighbors(pos),
+            |&pos| pos == (3, 0),
+        )
+        .unwrap();
+        assert_eq!(path, [(3, 3), (3, 2), (3, 1), (3, 0)]);
+    }
+
+    #[test]
+    fn test_astar_matches_dijkstra() {
+        let successors = |&pos: &(usize, usize)| neighbors(pos).into_iter().map(|next| (next, 1));
+        let (path, cost) = dijkstra([(0, 0)], successors, |&pos| pos == (3, 0)).unwrap();
+        assert_eq!(cost, 9);
+        assert_eq!(path.len(), 10);
+
+        let heuristic = |&(x, y): &(usize, usize)| 3 - x + y;
+        let (path, cost) = astar([(0, 0)], successors, heuristic, |&pos| pos == (3, 0)).unwrap();
+        assert_eq!(cost, 9);
+        assert_eq!(path.len(), 10);
+    }
+
+    #[test]
+    fn test_all_paths() {
+        let successors = |&pos: &(usize, usize)| neighbors(pos).into_iter().map(|next| (next, 1));
+        let (paths, cost) = dijkstra_all([(0, 0)], successors, |&pos| pos == (1, 3)).unwrap();
+        assert_eq!(cost, 4);
+        // Any staircase through the 2x4 room on the left is a shortest path.
+        assert_eq!(paths.paths().len(), 4);
+        assert_eq!(paths.nodes().len(), 8);
+        assert_eq!(paths.goals().collect::<Vec<_>>(), [&(1, 3)]);
+    }
+
+    #[test]
+    fn test_unreachable() {
+        assert_eq!(
+            bfs([(0, 0)], |&pos| neighbors(pos), |&pos| pos == (2, 0)),
+            None
+        );
+    }
+}
@@ -0,0 +1,446 @@
+use std::{collections::HashMap, fmt::Write};
+
+const HIGHLIGHT: &str = "red";
+const SWAPPED: &str = "orange";
+
+/// How a node is drawn.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Shape {
+    #[default]
+    Ellipse,
+    Box,
+    Circle,
+}
+
+/// Where Graphviz should place a group of nodes relative to the rest.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Rank {
+    Same,
+    Source,
+    Sink,
+}
+
+impl Rank {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Same => "same",
+            Self::Source => "source",
+            Self::Sink => "sink",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Node {
+    id: String,
+    label: Option<String>,
+    shape: Shape,
+    highlighted: bool,
+    swapped: bool,
+}
+
+impl Node {
+    /// Text to show instead of the node's id. May span several lines.
+    pub fn label(&mut self, label: impl Into<String>) -> &mut Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn shape(&mut self, shape: Shape) -> &mut Self {
+        self.shape = shape;
+        self
+    }
+
+    fn text(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.id)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Edge {
+    from: usize,
+    to: usize,
+    label: Option<String>,
+    color: Option<String>,
+    highlighted: bool,
+}
+
+impl Edge {
+    pub fn label(&mut self, label: impl Into<String>) -> &mut Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Any color name both Graphviz and CSS understand, like `"green"`.
+    pub fn color(&mut self, color: impl Into<String>) -> &mut Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// A graph to draw with Graphviz or Mermaid, with room to call out the
+/// parts of it that matter for a puzzle: a path, a clique or some swapped
+/// pairs.
+#[derive(Clone, Debug)]
+pub struct Graph {
+    directed: bool,
+    nodes: Vec<Node>,
+    index: HashMap<String, usize>,
+    edges: Vec<Edge>,
+    ranks: Vec<(Rank, Vec<usize>)>,
+    swaps: Vec<(usize, usize)>,
+}
+
+impl Graph {
+    pub fn directed() -> Self {
+        Self::new(true)
+    }
+
+    pub fn undirected() -> Self {
+        Self::new(false)
+    }
+
+    fn new(directed: bool) -> Self {
+        Self {
+            directed,
+            nodes: vec![],
+            index: HashMap::new(),
+            edges: vec![],
+            ranks: vec![],
+            swaps: vec![],
+        }
+    }
+
+    fn index(&mut self, id: &str) -> usize {
+        if let Some(&i) = self.index.get(id) {
+            return i;
+        }
+        let i = self.nodes.len();
+        self.nodes.push(Node {
+            id: id.to_owned(),
+            label: None,
+            shape: Shape::default(),
+            highlighted: false,
+            swapped: false,
+        });
+        self.index.insert(id.to_owned(), i);
+        i
+    }
+
+    /// The node called `id`, adding it if it's new.
+    pub fn node(&mut self, id: &str) -> &mut Node {
+        let i = self.index(id);
+        &mut self.nodes[i]
+    }
+
+    /// Adds an edge, and its ends if they're new.
+    pub fn edge(&mut self, from: &str, to: &str) -> &mut Edge {
+        let (from, to) = (self.index(from), self.index(to));
+        self.edges.push(Edge {
+            from,
+            to,
+            label: None,
+            color: None,
+            highlighted: false,
+        });
+        self.edges.last_mut().unwrap()
+    }
+
+    pub fn rank<S: AsRef<str>>(&mut self, rank: Rank, ids: impl IntoIterator<Item = S>) {
+        let nodes = ids.into_iter().map(|id| self.index(id.as_ref())).collect();
+        self.ranks.push((rank, nodes));
+    }
+
+    fn highlight_edges(&mut self, mut pick: impl FnMut(usize, usize) -> bool) {
+        let directed = self.directed;
+        for edge in &mut self.edges {
+            if pick(edge.from, edge.to) || (!directed && pick(edge.to, edge.from)) {
+                edge.highlighted = true;
+            }
+        }
+    }
+
+    /// Highlights each node along `path`, and the edges that join one to
+    /// the next.
+    pub fn highlight_path<S: AsRef<str>>(&mut self, path: &[S]) {
+        let path: Vec<usize> = path.iter().map(|id| self.index(id.as_ref())).collect();
+        for &i in &path {
+            self.nodes[i].highlighted = true;
+        }
+        self.highlight_edges(|from, to| path.windows(2).any(|step| step == [from, to]));
+    }
+
+    /// Highlights each node in `clique`, and every edge between two of them.
+    pub fn highlight_clique<S: AsRef<str>>(&mut self, clique: &[S]) {
+        let clique: Vec<usize> = clique.iter().map(|id| self.index(id.as_ref())).collect();
+        for &i in &clique {
+            self.nodes[i].highlighted = true;
+        }
+        self.highlight_edges(|from, to| clique.contains(&from) && clique.contains(&to));
+    }
+
+    /// Marks each pair of nodes as swapped with each other, and links them
+    /// with a dashed line.
+    pub fn highlight_swaps<S: AsRef<str>>(&mut self, pairs: &[(S, S)]) {
+        for (a, b) in pairs {
+            let (a, b) = (self.index(a.as_ref()), self.index(b.as_ref()));
+            self.nodes[a].swapped = true;
+            self.nodes[b].swapped = true;
+            self.swaps.push((a, b));
+        }
+    }
+
+    /// The graph in Graphviz's DOT language.
+    pub fn to_dot(&self) -> String {
+        let (keyword, arrow) = if self.directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+        let mut out = format!("{keyword} {{\n");
+        for node in &self.nodes {
+            let mut attrs = vec![];
+            if node.label.is_some() {
+                attrs.push(format!("label={}", dot_string(node.text())));
+            }
+            match node.shape {
+                Shape::Ellipse => {}
+                Shape::Box => attrs.push("shape=box".to_owned()),
+                Shape::Circle => attrs.push("shape=circle".to_owned()),
+            }
+            if node.highlighted {
+                attrs.push(format!("color={HIGHLIGHT} penwidth=2"));
+            }
+            if node.swapped {
+                attrs.push(format!("style=filled fillcolor={SWAPPED}"));
+            }
+            write!(out, "  {}", dot_string(&node.id)).unwrap();
+            if !attrs.is_empty() {
+                write!(out, " [{}]", attrs.join(" ")).unwrap();
+            }
+            out.push_str(";\n");
+        }
+        for (rank, nodes) in &self.ranks {
+            write!(out, "  {{ rank={};", rank.name()).unwrap();
+            for &i in nodes {
+                write!(out, " {};", dot_string(&self.nodes[i].id)).unwrap();
+            }
+            out.push_str(" }\n");
+        }
+        for edge in &self.edges {
+            let mut attrs = vec![];
+            if let Some(label) = &edge.label {
+                attrs.push(format!("label={}", dot_string(label)));
+            }
+            if edge.highlighted {
+                attrs.push(format!("color={HIGHLIGHT} penwidth=2"));
+            } else if let Some(color) = &edge.color {
+                attrs.push(format!("color={color}"));
+            }
+            write!(
+                out,
+                "  {} {arrow} {}",
+                dot_string(&self.nodes[edge.from].id),
+                dot_string(&self.nodes[edge.to].id)
+            )
+            .unwrap();
+            if !attrs.is_empty() {
+                write!(out, " [{}]", attrs.join(" ")).unwrap();
+            }
+            out.push_str(";\n");
+        }
+        for &(a, b) in &self.swaps {
+            writeln!(
+                out,
+                "  {} {arrow} {} [style=dashed color={SWAPPED} dir=none constraint=false];",
+                dot_string(&self.nodes[a].id),
+                dot_string(&self.nodes[b].id)
+            )
+            .unwrap();
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// The graph as a Mermaid flowchart. Mermaid has no ranks, so those are
+    /// left out.
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::from("flowchart TD\n");
+        for (i, node) in self.nodes.iter().enumerate() {
+            let text = mermaid_string(node.text());
+            let shape = match node.shape {
+                Shape::Ellipse => format!("({text})"),
+                Shape::Box => format!("[{text}]"),
+                Shape::Circle => format!("(({text}))"),
+            };
+            writeln!(out, "  n{i}{shape}").unwrap();
+        }
+
+        let arrow = if self.directed { "-->" } else { "---" };
+        let mut link_styles = vec![];
+        for (i, edge) in self.edges.iter().enumerate() {
+            let label = match &edge.label {
+                Some(label) => format!("|{}|", mermaid_string(label)),
+                None => String::new(),
+            };
+            writeln!(out, "  n{} {arrow}{label} n{}", edge.from, edge.to).unwrap();
+            if edge.highlighted {
+                link_styles.push(format!("{i} stroke:{HIGHLIGHT},stroke-width:3px"));
+            } else if let Some(color) = &edge.color {
+                link_styles.push(format!("{i} stroke:{color}"));
+            }
+        }
+        for (i, &(a, b)) in self.swaps.iter().enumerate() {
+            writeln!(out, "  n{a} -.- n{b}").unwrap();
+            link_styles.push(format!("{} stroke:{SWAPPED}", self.edges.len() + i));
+        }
+        for style in link_styles {
+            writeln!(out, "  linkStyle {style}").unwrap();
+        }
+
+        for (class, style, pick) in [
+            (
+                "highlighted",
+                format!("stroke:{HIGHLIGHT},stroke-width:3px"),
+                (|node: &Node| node.highlighted) as fn(&Node) -> bool,
+            ),
+            ("swapped", format!("fill:{SWAPPED}"), |node| node.swapped),
+        ] {
+            let members: Vec<String> = (0..self.nodes.len())
+                .filter(|&i| pick(&self.nodes[i]))
+                .map(|i| format!("n{i}"))
+                .collect();
+            if !members.is_empty() {
+                writeln!(out, "  classDef {class} {style}").unwrap();
+                writeln!(out, "  class {} {class}", members.join(",")).unwrap();
+            }
+        }
+        out
+    }
+}
+
+fn dot_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn mermaid_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "#quot;").replace('\n', "<br>"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle() -> Graph {
+        let mut graph = Graph::undirected();
+        graph.edge("a", "b");
+        graph.edge("b", "c");
+        graph.edge("c", "a");
+        graph.edge("c", "d");
+        graph
+    }
+
+    #[test]
+    fn test_path() {
+        let mut graph = Graph::directed();
+        graph.node("start").shape(Shape::Box);
+        graph.edge("start", "A").label("1");
+        graph.edge("A", "end").color("green");
+        graph.edge("start", "end");
+        graph.highlight_path(&["start", "A", "end"]);
+        assert_eq!(
+            graph.to_dot(),
+            r#"digraph {
+  "start" [shape=box color=red penwidth=2];
+  "A" [color=red penwidth=2];
+  "end" [color=red penwidth=2];
+  "start" -> "A" [label="1" color=red penwidth=2];
+  "A" -> "end" [color=red penwidth=2];
+  "start" -> "end";
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_clique() {
+        let mut graph = triangle();
+        // Undirected edges match in either direction.
+        graph.highlight_clique(&["c", "b", "a"]);
+        let highlighted: Vec<bool> = graph.edges.iter().map(|edge| edge.highlighted).collect();
+        assert_eq!(highlighted, [true, true, true, false]);
+        assert!(graph.to_dot().contains("\"c\" -- \"d\";\n"));
+    }
+
+    #[test]
+    fn test_mermaid() {
+        let mut graph = triangle();
+        graph
+            .node("d")
+            .label("say \"d\"\ntwice")
+            .shape(Shape::Circle);
+        graph.highlight_path(&["a", "b"]);
+        assert_eq!(
+            graph.to_mermaid(),
+            r#"flowchart TD
+  n0("a")
+  n1("b")
+  n2("c")
+  n3(("say #quot;d#quot;<br>twice"))
+  n0 --- n1
+  n1 --- n2
+  n2 --- n0
+  n2 --- n3
+  linkStyle 0 stroke:red,stroke-width:3px
+  classDef highlighted stroke:red,stroke-width:3px
+  class n0,n1 highlighted
+"#
+        );
+    }
+
+    #[test]
+    fn test_swaps() {
+        let mut graph = Graph::directed();
+        graph.edge("x00", "z00").color("green");
+        graph.edge("y00", "z01").color("red");
+        graph.rank(Rank::Source, ["x00", "y00"]);
+        graph.highlight_swaps(&[("z00", "z01")]);
+        assert_eq!(
+            graph.to_dot(),
+            r#"digraph {
+  "x00";
+  "z00" [style=filled fillcolor=orange];
+  "y00";
+  "z01" [style=filled fillcolor=orange];
+  { rank=source; "x00"; "y00"; }
+  "x00" -> "z00" [color=green];
+  "y00" -> "z01" [color=red];
+  "z00" -> "z01" [style=dashed color=orange dir=none constraint=false];
+}
+"#
+        );
+        let mermaid = graph.to_mermaid();
+        assert!(mermaid.contains("  n1 -.- n3\n  linkStyle 0 stroke:green\n"));
+        assert!(mermaid.ends_with("  classDef swapped fill:orange\n  class n1,n3 swapped\n"));
+    }
+
+    #[test]
+    fn test_escaping() {
+        let mut graph = Graph::directed();
+        graph.node("a\"b").label("x\\y\nz");
+        assert_eq!(
+            graph.to_dot(),
+            "digraph {\n  \"a\\\"b\" [label=\"x\\\\y\\nz\"];\n}\n"
+        );
+    }
+}
@@ -0,0 +1,396 @@
+use std::fmt;
+
+mod reverse;
+
+pub use reverse::{find_a, find_quine, Unsupported};
+
+/// One of the machine's three registers.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Register {
+    A,
+    B,
+    C,
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::A => write!(f, "A"),
+            Self::B => write!(f, "B"),
+            Self::C => write!(f, "C"),
+        }
+    }
+}
+
+/// A combo operand: 0 through 3 stand for themselves, 4 through 6 for the
+/// registers, and 7 is reserved.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Combo {
+    Literal(u8),
+    Register(Register),
+    Reserved,
+}
+
+impl Combo {
+    pub fn decode(operand: u8) -> Self {
+        match operand {
+            0..=3 => Self::Literal(operand),
+            4 => Self::Register(Register::A),
+            5 => Self::Register(Register::B),
+            6 => Self::Register(Register::C),
+            _ => Self::Reserved,
+        }
+    }
+}
+
+impl fmt::Display for Combo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(n) => write!(f, "{n}"),
+            Self::Register(register) => write!(f, "{register}"),
+            Self::Reserved => write!(f, "?"),
+        }
+    }
+}
+
+/// A decoded instruction. The three `dv` instructions all divide A by a
+/// power of two, and differ only in where they store the result.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Instruction {
+    Adv(Combo),
+    Bxl(u8),
+    Bst(Combo),
+    Jnz(u8),
+    Bxc,
+    Out(Combo),
+    Bdv(Combo),
+    Cdv(Combo),
+}
+
+impl Instruction {
+    pub fn decode(opcode: u8, operand: u8) -> Option<Self> {
+        let combo = Combo::decode(operand);
+        Some(match opcode {
+            0 => Self::Adv(combo),
+            1 => Self::Bxl(operand),
+            2 => Self::Bst(combo),
+            3 => Self::Jnz(operand),
+            4 => Self::Bxc,
+            5 => Self::Out(combo),
+            6 => Self::Bdv(combo),
+            7 => Self::Cdv(combo),
+            _ => return None,
+        })
+    }
+
+    /// What the instruction does, written out as an assignment.
+    pub fn effect(&self) -> String {
+        match self {
+            Self::Adv(x) => format!("A = A >> {x}"),
+            Self::Bxl(n) => format!("B = B ^ {n}"),
+            Self::Bst(x) => format!("B = {x} & 7"),
+            Self::Jnz(n) => format!("if A != 0 goto {n}"),
+            Self::Bxc => "B = B ^ C".to_owned(),
+            Self::Out(x) => format!("output {x} & 7"),
+            Self::Bdv(x) => format!("B = A >> {x}"),
+            Self::Cdv(x) => format!("C = A >> {x}"),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Adv(x) => write!(f, "adv A, {x}"),
+            Self::Bxl(n) => write!(f, "bxl B, {n}"),
+            Self::Bst(x) => write!(f, "bst B, {x}"),
+            Self::Jnz(n) => write!(f, "jnz {n}"),
+            Self::Bxc => write!(f, "bxc B, C"),
+            Self::Out(x) => write!(f, "out {x}"),
+            Self::Bdv(x) => write!(f, "bdv B, {x}"),
+            Self::Cdv(x) => write!(f, "cdv C, {x}"),
+        }
+    }
+}
+
+/// Lists the program one instruction per line, with its address and what it
+/// does:
+///
+/// ```text
+///  0: bst B, A   ; B = A & 7
+///  2: bxl B, 1   ; B = B ^ 1
+/// ```
+pub fn disassemble(program: &[u8]) -> String {
+    let mut out = String::new();
+    for (ip, pair) in program.chunks(2).enumerate() {
+        let ip = 2 * ip;
+        let line = match *pair {
+            [opcode, operand] => match Instruction::decode(opcode, operand) {
+                Some(instruction) => {
+                    format!(
+                        "{ip:>2}: {:<10} ; {}",
+                        instruction.to_string(),
+                        instruction.effect()
+                    )
+                }
+                None => format!("{ip:>2}: ??? {opcode}, {operand}"),
+            },
+            _ => format!("{ip:>2}: ??? {}", pair[0]),
+        };
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out
+}
+
+/// Why the machine stopped before halting.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Fault {
+    BadOpcode { ip: usize, opcode: u8 },
+    ReservedOperand { ip: usize },
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BadOpcode { ip, opcode } => write!(f, "bad opcode {opcode} at {ip}"),
+            Self::ReservedOperand { ip } => write!(f, "reserved combo operand 7 at {ip}"),
+        }
+    }
+}
+
+impl std::error::Error for Fault {}
+
+/// What a single [`Machine::step`] did.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Step {
+    /// Ran an instruction that didn't produce any output.
+    Ran,
+    /// Ran an `out` instruction, which produced this value.
+    Output(u8),
+    /// The instruction pointer ran off the end of the program.
+    Halted,
+}
+
+/// The 3-bit computer: three registers, an instruction pointer, and a
+/// program of 3-bit numbers.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Machine {
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+    pub ip: usize,
+    program: Vec<u8>,
+}
+
+impl Machine {
+    pub fn new([a, b, c]: [u64; 3], program: Vec<u8>) -> Self {
+        Self {
+            a,
+            b,
+            c,
+            ip: 0,
+            program,
+        }
+    }
+
+    pub fn program(&self) -> &[u8] {
+        &self.program
+    }
+
+    /// The instruction the machine will run next, or `None` if it's halted.
+    pub fn current(&self) -> Option<Result<Instruction, Fault>> {
+        let opcode = *self.program.get(self.ip)?;
+        let operand = *self.program.get(self.ip + 1)?;
+        Some(
+            Instruction::decode(opcode, operand).ok_or(Fault::BadOpcode {
+                ip: self.ip,
+                opcode,
+            }),
+        )
+    }
+
+    fn register(&self, register: Register) -> u64 {
+        match register {
+            Register::A => self.a,
+            Register::B => self.b,
+            Register::C => self.c,
+        }
+    }
+
+    fn combo(&self, combo: Combo) -> Result<u64, Fault> {
+        match combo {
+            Combo::Literal(n) => Ok(n.into()),
+            Combo::Register(register) => Ok(self.register(register)),
+            Combo::Reserved => Err(Fault::ReservedOperand { ip: self.ip }),
+        }
+    }
+
+    /// A shifted right by a combo operand. Shifting all the bits out leaves
+    /// zero, as the puzzle's division would.
+    fn divide(&self, combo: Combo) -> Result<u64, Fault> {
+        let shift = self.combo(combo)?;
+        Ok(u32::try_from(shift)
+            .ok()
+            .and_then(|shift| self.a.checked_shr(shift))
+            .unwrap_or(0))
+    }
+
+    /// Runs one instruction.
+    pub fn step(&mut self) -> Result<Step, Fault> {
+        let Some(instruction) = self.current() else {
+            return Ok(Step::Halted);
+        };
+        let mut step = Step::Ran;
+        let mut next = self.ip + 2;
+        match instruction? {
+            Instruction::Adv(x) => self.a = self.divide(x)?,
+            Instruction::Bxl(n) => self.b ^= u64::from(n),
+            Instruction::Bst(x) => self.b = self.combo(x)? & 7,
+            Instruction::Jnz(n) => {
+                if self.a != 0 {
+                    next = n.into();
+                }
+            }
+            Instruction::Bxc => self.b ^= self.c,
+            Instruction::Out(x) => step = Step::Output((self.combo(x)? & 7) as u8),
+            Instruction::Bdv(x) => self.b = self.divide(x)?,
+            Instruction::Cdv(x) => self.c = self.divide(x)?,
+        }
+        self.ip = next;
+        Ok(step)
+    }
+
+    /// Runs until the machine halts, passing each output to `sink`.
+    pub fn run(&mut self, sink: impl FnMut(u8)) -> Result<(), Fault> {
+        self.run_traced(sink, |_, _| {})
+    }
+
+    /// Like [`Machine::run`], but calls `trace` with the machine's state
+    /// before each instruction runs.
+    pub fn run_traced(
+        &mut self,
+        mut sink: impl FnMut(u8),
+        mut trace: impl FnMut(&Self, Instruction),
+    ) -> Result<(), Fault> {
+        while let Some(instruction) = self.current() {
+            trace(self, instruction?);
+            if let Step::Output(value) = self.step()? {
+                sink(value);
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs until the machine halts and collects everything it output.
+    pub fn outputs(&mut self) -> Result<Vec<u8>, Fault> {
+        let mut outputs = vec![];
+        self.run(|value| outputs.push(value))?;
+        Ok(outputs)
+    }
+}
+
+/// Reads the puzzle's register block and program.
+pub fn parse(input: &str) -> aoc_parse::Result<Machine> {
+    let [registers, program] = aoc_parse::split_sections(input)?;
+    let [a, b, c] = registers.split_lines()?;
+    let register = |mut line: aoc_parse::Cursor, name: &str| -> aoc_parse::Result<u64> {
+        line.field(&format!("Register {name}"))?;
+        let value = line.int()?;
+        line.end()?;
+        Ok(value)
+    };
+    let registers = [register(a, "A")?, register(b, "B")?, register(c, "C")?];
+
+    let [mut line] = program.split_lines()?;
+    line.field("Program")?;
+    let program = line.list(",", |cursor| {
+        let start = *cursor;
+        let value: u8 = cursor.int()?;
+        if value < 8 {
+            Ok(value)
+        } else {
+            Err(start.error(format!("{value} isn't a 3-bit number")))
+        }
+    })?;
+    line.end()?;
+    Ok(Machine::new(registers, program))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(registers: [u64; 3], program: &[u8]) -> (Machine, Vec<u8>) {
+        let mut machine = Machine::new(registers, program.to_vec());
+        let outputs = machine.outputs().unwrap();
+        (machine, outputs)
+    }
+
+    #[test]
+    fn test_small_examples() {
+        assert_eq!(run([0, 0, 9], &[2, 6]).0.b, 1);
+        assert_eq!(run([10, 0, 0], &[5, 0, 5, 1, 5, 4]).1, [0, 1, 2]);
+        let (machine, outputs) = run([2024, 0, 0], &[0, 1, 5, 4, 3, 0]);
+        assert_eq!(outputs, [4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]);
+        assert_eq!(machine.a, 0);
+        assert_eq!(run([0, 29, 0], &[1, 7]).0.b, 26);
+        assert_eq!(run([0, 2024, 43690], &[4, 0]).0.b, 44354);
+    }
+
+    #[test]
+    fn test_parse_and_run() {
+        let input = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0\n";
+        let mut machine = parse(input).unwrap();
+        assert_eq!(machine.outputs().unwrap(), [4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+
+        let err = parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,9\n");
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "line 5, column 12: 9 isn't a 3-bit number"
+        );
+    }
+
+    #[test]
+    fn test_quine() {
+        let program = [0, 3, 5, 4, 3, 0];
+        assert_eq!(run([117440, 0, 0], &program).1, program);
+    }
+
+    #[test]
+    fn test_step_and_trace() {
+        let mut machine = Machine::new([10, 0, 0], vec![5, 0, 5, 4]);
+        assert_eq!(machine.step(), Ok(Step::Output(0)));
+        assert_eq!(machine.step(), Ok(Step::Output(2)));
+        assert_eq!(machine.step(), Ok(Step::Halted));
+
+        let mut machine = Machine::new([2, 0, 0], vec![0, 1, 3, 0]);
+        let mut trace = vec![];
+        machine
+            .run_traced(
+                |_| {},
+                |machine, instruction| trace.push((machine.a, instruction)),
+            )
+            .unwrap();
+        let (adv, jnz) = (Instruction::Adv(Combo::Literal(1)), Instruction::Jnz(0));
+        assert_eq!(trace, [(2, adv), (1, jnz), (1, adv), (0, jnz)]);
+
+        let mut machine = Machine::new([0, 0, 0], vec![5, 7]);
+        assert_eq!(machine.step(), Err(Fault::ReservedOperand { ip: 0 }));
+    }
+
+    #[test]
+    fn test_disassemble() {
+        assert_eq!(
+            disassemble(&[2, 4, 1, 1, 7, 5, 0, 3, 4, 7, 5, 5, 3, 0]),
+            " 0: bst B, A   ; B = A & 7
+ 2: bxl B, 1   ; B = B ^ 1
+ 4: cdv C, B   ; C = A >> B
+ 6: adv A, 3   ; A = A >> 3
+ 8: bxc B, C   ; B = B ^ C
+10: out B      ; output B & 7
+12: jnz 0      ; if A != 0 goto 0
+"
+        );
+    }
+}
@@ -0,0 +1,255 @@
+use std::fmt;
+
+use crate::{Combo, Instruction, Machine, Register, Step};
+
+/// Why [`find_a`] can't reason about a program.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Unsupported {
+    OddLength,
+    BadOpcode {
+        ip: usize,
+        opcode: u8,
+    },
+    ReservedOperand {
+        ip: usize,
+    },
+    /// The program doesn't end with the `jnz 0` that loops back to the start.
+    NoLoop,
+    /// There's a jump other than the one that closes the loop.
+    ExtraJump {
+        ip: usize,
+    },
+    /// Each iteration must shift A right by exactly three bits, with a single
+    /// `adv A, 3`.
+    Shift {
+        advs: usize,
+        by_three: usize,
+    },
+    /// Each iteration must output exactly one value.
+    Outputs {
+        outs: usize,
+    },
+    /// The register is read before it's written, so it carries state from
+    /// one iteration into the next.
+    Carried {
+        ip: usize,
+        register: Register,
+    },
+}
+
+impl fmt::Display for Unsupported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OddLength => write!(f, "the program has an opcode without an operand"),
+            Self::BadOpcode { ip, opcode } => write!(f, "bad opcode {opcode} at {ip}"),
+            Self::ReservedOperand { ip } => write!(f, "reserved combo operand 7 at {ip}"),
+            Self::NoLoop => write!(f, "the program doesn't end by jumping back to 0"),
+            Self::ExtraJump { ip } => write!(f, "jump at {ip} isn't the one closing the loop"),
+            Self::Shift { advs, by_three } => write!(
+                f,
+                "expected a single `adv A, 3` per iteration, found {advs} `adv` ({by_three} by 3)"
+            ),
+            Self::Outputs { outs } => {
+                write!(f, "expected one `out` per iteration, found {outs}")
+            }
+            Self::Carried { ip, register } => write!(
+                f,
+                "{register} is read at {ip} before it's written, so it carries over between iterations"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Unsupported {}
+
+/// Checks that `program` is a single loop which outputs one value per
+/// iteration, shifts A right by three bits, and derives B and C afresh from
+/// A each time. Returns the address of the `jnz` that closes the loop.
+fn check_shape(program: &[u8]) -> Result<usize, Unsupported> {
+    if !program.len().is_multiple_of(2) {
+        return Err(Unsupported::OddLength);
+    }
+    let mut body = vec![];
+    for (i, pair) in program.chunks(2).enumerate() {
+        let ip = 2 * i;
+        let instruction = Instruction::decode(pair[0], pair[1]).ok_or(Unsupported::BadOpcode {
+            ip,
+            opcode: pair[0],
+        })?;
+        body.push((ip, instruction));
+    }
+    let Some((jnz, Instruction::Jnz(0))) = body.pop() else {
+        return Err(Unsupported::NoLoop);
+    };
+
+    let (mut advs, mut by_three, mut outs) = (0, 0, 0);
+    let mut written = vec![Register::A];
+    for &(ip, instruction) in &body {
+        let mut reads = vec![];
+        let mut read_combo = |combo| match combo {
+            Combo::Literal(_) => Ok(()),
+            Combo::Register(register) => {
+                reads.push(register);
+                Ok(())
+            }
+            Combo::Reserved => Err(Unsupported::ReservedOperand { ip }),
+        };
+        let writes = match instruction {
+            Instruction::Adv(x) => {
+                advs += 1;
+                if x == Combo::Literal(3) {
+                    by_three += 1;
+                }
+                read_combo(x)?;
+                Register::A
+            }
+            Instruction::Bxl(_) => {
+                reads.push(Register::B);
+                Register::B
+            }
+            Instruction::Bst(x) | Instruction::Bdv(x) => {
+                read_combo(x)?;
+                Register::B
+            }
+            Instruction::Cdv(x) => {
+                read_combo(x)?;
+                Register::C
+            }
+            Instruction::Bxc => {
+                reads.extend([Register::B, Register::C]);
+                Register::B
+            }
+            Instruction::Out(x) => {
+                outs += 1;
+                read_combo(x)?;
+                Register::A
+            }
+            Instruction::Jnz(_) => return Err(Unsupported::ExtraJump { ip }),
+        };
+        if let Some(&register) = reads.iter().find(|register| !written.contains(register)) {
+            return Err(Unsupported::Carried { ip, register });
+        }
+        written.push(writes);
+    }
+
+    if advs != 1 || by_three != 1 {
+        return Err(Unsupported::Shift { advs, by_three });
+    }
+    if outs != 1 {
+        return Err(Unsupported::Outputs { outs });
+    }
+    Ok(jnz)
+}
+
+/// Runs a single pass through the loop body with A set to `a`, returning
+/// what it outputs.
+fn iteration(program: &[u8], jnz: usize, a: u64) -> u8 {
+    let mut machine = Machine::new([a, 0, 0], program.to_vec());
+    while machine.ip != jnz {
+        match machine.step() {
+            Ok(Step::Output(value)) => return value,
+            Ok(Step::Ran) => {}
+            // The shape check rules out faults and halting mid-loop.
+            Ok(Step::Halted) | Err(_) => unreachable!(),
+        }
+    }
+    unreachable!("the shape check guarantees one output per iteration")
+}
+
+/// Finds the smallest starting value of register A that makes `program`
+/// output exactly `target`, with B and C starting at zero.
+///
+/// Each pass through the loop consumes A's lowest three bits, so the last
+/// output depends only on A's top three bits, the one before it on the top
+/// six, and so on. That lets A be rebuilt three bits at a time from the last
+/// output backwards, backtracking whenever no choice of bits fits.
+///
+/// Returns `Ok(None)` if no value works, and an error if the program doesn't
+/// have the loop shape this relies on.
+pub fn find_a(program: &[u8], target: &[u8]) -> Result<Option<u64>, Unsupported> {
+    let jnz = check_shape(program)?;
+    if target.is_empty() {
+        // The loop body always runs at least once.
+        return Ok(None);
+    }
+
+    // Each frame holds the bits recovered so far and the next 3-bit digit to
+    // try below them.
+    let mut stack = vec![(0, 0)];
+    while let Some((prefix, digit)) = stack.pop() {
+        if digit == 8 {
+            continue;
+        }
+        stack.push((prefix, digit + 1));
+        if prefix >> 61 != 0 {
+            // Another three bits wouldn't fit in a register.
+            continue;
+        }
+
+        let a = prefix << 3 | digit;
+        let k = target.len() - stack.len();
+        // A must still be nonzero after the last shift but one, or the loop
+        // would stop early.
+        let stops_early = a == 0 && target.len() > 1;
+        if stops_early || iteration(program, jnz, a) != target[k] {
+            continue;
+        }
+        if k > 0 {
+            stack.push((a, 0));
+        } else if Machine::new([a, 0, 0], program.to_vec())
+            .outputs()
+            .as_deref()
+            == Ok(target)
+        {
+            return Ok(Some(a));
+        }
+    }
+    Ok(None)
+}
+
+/// Finds the smallest starting value of register A that makes the program
+/// output a copy of itself.
+pub fn find_quine(program: &[u8]) -> Result<Option<u64>, Unsupported> {
+    find_a(program, program)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_quine() {
+        assert_eq!(find_quine(&[0, 3, 5, 4, 3, 0]), Ok(Some(117440)));
+
+        let program = [2, 4, 1, 1, 7, 5, 0, 3, 4, 7, 1, 6, 5, 5, 3, 0];
+        let a = find_quine(&program).unwrap().unwrap();
+        let outputs = Machine::new([a, 0, 0], program.to_vec()).outputs();
+        assert_eq!(outputs.as_deref(), Ok(&program[..]));
+        let a = find_a(&program, &[3, 7, 2]).unwrap().unwrap();
+        let outputs = Machine::new([a, 0, 0], program.to_vec()).outputs();
+        assert_eq!(outputs, Ok(vec![3, 7, 2]));
+    }
+
+    #[test]
+    fn test_unsupported() {
+        assert_eq!(
+            find_quine(&[0, 1, 5, 4, 3, 0]),
+            Err(Unsupported::Shift {
+                advs: 1,
+                by_three: 0
+            })
+        );
+        assert_eq!(find_quine(&[0, 3, 5, 4]), Err(Unsupported::NoLoop));
+        assert_eq!(
+            find_quine(&[4, 0, 0, 3, 5, 5, 3, 0]),
+            Err(Unsupported::Carried {
+                ip: 0,
+                register: Register::B
+            })
+        );
+        assert_eq!(
+            find_quine(&[0, 3, 3, 4, 5, 4, 3, 0]),
+            Err(Unsupported::ExtraJump { ip: 2 })
+        );
+    }
+}